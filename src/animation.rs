@@ -66,6 +66,21 @@ impl Direction {
         }
     }
 
+    /// Unit vector pointing this way (diagonals are normalized)
+    pub fn unit_vector(self) -> (f32, f32) {
+        let diagonal = std::f32::consts::FRAC_1_SQRT_2;
+        match self {
+            Direction::South => (0.0, 1.0),
+            Direction::SouthEast => (diagonal, diagonal),
+            Direction::East => (1.0, 0.0),
+            Direction::NorthEast => (diagonal, -diagonal),
+            Direction::North => (0.0, -1.0),
+            Direction::NorthWest => (-diagonal, -diagonal),
+            Direction::West => (-1.0, 0.0),
+            Direction::SouthWest => (-diagonal, diagonal),
+        }
+    }
+
     pub fn to_row(&self) -> i32 {
         *self as i32
     }
@@ -3,12 +3,18 @@ use sdl2::rect::Rect;
 use sdl2::render::Canvas;
 use sdl2::video::Window;
 
+/// Unscaled pixels each later combo step's effect is drawn bigger by
+const COMBO_STEP_GROWTH: u32 = 6;
+
 /// AttackEffect represents a visual effect that shows the range/hitbox of an attack.
 ///
 /// Game Dev Pattern: Visual Effects (VFX)
 /// This is separate from the player's character animation. When the player attacks,
 /// we spawn one of these effects positioned in front of them to show the attack's range.
 /// Once the animation finishes playing, the effect is removed.
+///
+/// Later steps of a combo chain draw bigger (around the same center), so a
+/// finisher reads as heavier than the opening jab.
 pub struct AttackEffect<'a> {
     pub x: i32,
     pub y: i32,
@@ -16,6 +22,8 @@ pub struct AttackEffect<'a> {
     pub height: u32,
     #[allow(dead_code)] // Keeping for future directional effect support
     pub direction: Direction,
+    /// Which step of the attacker's combo chain spawned this effect (0 = first)
+    pub combo_step: usize,
     animation_controller: AnimationController<'a>,
 }
 
@@ -24,27 +32,28 @@ impl<'a> AttackEffect<'a> {
     ///
     /// # Parameters
     /// - `x`, `y`: Position to render the effect
-    /// - `width`, `height`: Size of each frame (32x32 for your punch sprite)
+    /// - `width`, `height`: Size of each frame (32x32 for the punch sprite)
     /// - `direction`: Which direction the attack is facing (for future directional effects)
-    /// - `animation_controller`: Controller loaded with the punch animation
+    /// - `combo_step`: The attack's step in its combo chain
+    /// - `animation_controller`: Controller already set to the weapon's effect animation
+    ///   (see `WeaponRegistry::create_effect_controller`); AnimationController starts
+    ///   with an empty state, which would draw nothing
     pub fn new(
         x: i32,
         y: i32,
         width: u32,
         height: u32,
         direction: Direction,
-        mut animation_controller: AnimationController<'a>,
+        combo_step: usize,
+        animation_controller: AnimationController<'a>,
     ) -> Self {
-        // IMPORTANT: Set the initial state to "punch" so the animation plays!
-        // AnimationController starts with an empty state by default.
-        animation_controller.set_state("punch".to_string());
-
         AttackEffect {
             x,
             y,
             width,
             height,
             direction,
+            combo_step,
             animation_controller,
         }
     }
@@ -62,9 +71,15 @@ impl<'a> AttackEffect<'a> {
     /// - `canvas`: The SDL2 canvas to draw on
     /// - `scale`: Rendering scale (usually 3 to match player scale)
     pub fn render(&self, canvas: &mut Canvas<Window>, scale: u32) -> Result<(), String> {
-        let scaled_width = self.width * scale;
-        let scaled_height = self.height * scale;
-        let dest_rect = Rect::new(self.x, self.y, scaled_width, scaled_height);
+        let growth = COMBO_STEP_GROWTH * self.combo_step as u32 * scale;
+        let scaled_width = self.width * scale + growth;
+        let scaled_height = self.height * scale + growth;
+        let dest_rect = Rect::new(
+            self.x - growth as i32 / 2,
+            self.y - growth as i32 / 2,
+            scaled_width,
+            scaled_height,
+        );
 
         if let Some(sprite_sheet) = self.animation_controller.get_current_sprite_sheet() {
             // Rotate sprite to match direction
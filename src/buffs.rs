@@ -0,0 +1,316 @@
+//! Active effects - the stat buffs and debuffs currently on the player
+//!
+//! Every effect is a bundle of `ModifierEffect`s under a key. There are three
+//! lifetimes:
+//! - timed: counts down and is removed when it runs out (a modifier's
+//!   `duration` becomes the effect's duration)
+//! - permanent: stays until removed (a modifier with no `duration`)
+//! - aura: present while its source says so. Awake pyramids are auras keyed
+//!   by pyramid; `set_auras` replaces the whole set each frame without
+//!   touching timed effects.
+//!
+//! Re-applying a key that is already active follows the effect's
+//! `StackRule`, as status effects do: `Refresh` restarts the duration
+//! (keeping the longer one), `StackIntensity` adds a stack (Flat and
+//! Percentage values scale with stacks) and `Ignore` keeps the current one.
+//!
+//! Stat modifiers from status effects (e.g. slow) stay in `StatusEffects`;
+//! `Player::modifiers` combines both.
+//!
+//! # Example
+//!
+//! ```rust
+//! let buff = ModifierEffect {
+//!     stat_type: StatType::AttackDamage,
+//!     modifier: StatModifier::Flat(2.0),
+//!     duration: Some(Duration::from_secs(30)),
+//!     source: "Strength Potion".to_string(),
+//! };
+//! player.active_effects.apply(ActiveEffect::from_modifier(buff));
+//!
+//! player.active_effects.update(delta_time);
+//! let damage = stats.effective_stat(StatType::AttackDamage, &player.active_effects.modifiers());
+//! ```
+
+use crate::stats::{ModifierEffect, StatModifier};
+use crate::status::StackRule;
+use serde::{Deserialize, Serialize};
+
+/// A bundle of stat modifiers on the player
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveEffect {
+    /// Identity for stacking and removal (re-applying the same key stacks)
+    pub key: String,
+    /// One stack's modifiers
+    pub modifiers: Vec<ModifierEffect>,
+    /// Seconds the effect lasts when (re)applied; None lasts until removed
+    pub duration: Option<f32>,
+    /// Seconds left (None for permanent effects and auras)
+    pub remaining: Option<f32>,
+    /// Replaced by `set_auras` rather than counting down
+    pub aura: bool,
+    pub stacks: u32,
+    pub max_stacks: u32,
+    pub stack_rule: StackRule,
+}
+
+impl ActiveEffect {
+    /// A single-stack effect lasting `duration` seconds that refreshes when re-applied
+    pub fn timed(key: impl Into<String>, modifiers: Vec<ModifierEffect>, duration: f32) -> Self {
+        ActiveEffect {
+            key: key.into(),
+            modifiers,
+            duration: Some(duration),
+            remaining: Some(duration),
+            aura: false,
+            stacks: 1,
+            max_stacks: 1,
+            stack_rule: StackRule::Refresh,
+        }
+    }
+
+    /// An effect from one modifier, keyed by its source
+    ///
+    /// Lasts the modifier's `duration`, or until removed if it has none.
+    pub fn from_modifier(modifier: ModifierEffect) -> Self {
+        let duration = modifier.duration.map(|duration| duration.as_secs_f32());
+        ActiveEffect {
+            key: modifier.source.clone(),
+            modifiers: vec![modifier],
+            duration,
+            remaining: duration,
+            aura: false,
+            stacks: 1,
+            max_stacks: 1,
+            stack_rule: StackRule::Refresh,
+        }
+    }
+
+    /// An effect that lasts while its source keeps passing it to `set_auras`
+    pub fn aura(key: impl Into<String>, modifiers: Vec<ModifierEffect>) -> Self {
+        ActiveEffect {
+            key: key.into(),
+            modifiers,
+            duration: None,
+            remaining: None,
+            aura: true,
+            stacks: 1,
+            max_stacks: 1,
+            stack_rule: StackRule::Ignore,
+        }
+    }
+
+    /// Sets what re-applying this effect does
+    pub fn with_stacking(mut self, stack_rule: StackRule, max_stacks: u32) -> Self {
+        self.stack_rule = stack_rule;
+        self.max_stacks = max_stacks.max(1);
+        self
+    }
+
+    /// The modifiers with Flat and Percentage values scaled by the stack count
+    pub fn stacked_modifiers(&self) -> impl Iterator<Item = ModifierEffect> + '_ {
+        let stacks = self.stacks as f32;
+        self.modifiers.iter().map(move |modifier| {
+            let mut modifier = modifier.clone();
+            modifier.modifier = match modifier.modifier {
+                StatModifier::Flat(value) => StatModifier::Flat(value * stacks),
+                StatModifier::Percentage(value) => StatModifier::Percentage(value * stacks),
+                StatModifier::Override(value) => StatModifier::Override(value),
+            };
+            modifier
+        })
+    }
+
+    /// Name to show for the effect (its first modifier's source, or the key)
+    #[allow(dead_code)] // Reserved for buff tooltips
+    pub fn name(&self) -> &str {
+        self.modifiers.first()
+            .map(|modifier| modifier.source.as_str())
+            .filter(|source| !source.is_empty())
+            .unwrap_or(&self.key)
+    }
+
+    /// 1.0 when just applied, falling to 0.0 as it wears off; None if it doesn't wear off
+    pub fn fraction_remaining(&self) -> Option<f32> {
+        match (self.remaining, self.duration) {
+            (Some(remaining), Some(duration)) if duration > 0.0 => Some((remaining / duration).clamp(0.0, 1.0)),
+            (Some(_), Some(_)) => Some(0.0),
+            _ => None,
+        }
+    }
+}
+
+/// Every active effect on the player (at most one entry per key)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ActiveEffects {
+    effects: Vec<ActiveEffect>,
+}
+
+impl ActiveEffects {
+    /// Applies an effect, following the stack rule of one already active with the same key
+    ///
+    /// Returns false if it was ignored.
+    pub fn apply(&mut self, effect: ActiveEffect) -> bool {
+        let Some(current) = self.effects.iter_mut().find(|current| current.key == effect.key) else {
+            self.effects.push(effect);
+            return true;
+        };
+
+        match current.stack_rule {
+            StackRule::Ignore => return false,
+            StackRule::Refresh => {
+                current.modifiers = effect.modifiers;
+            }
+            StackRule::StackIntensity => {
+                current.stacks = (current.stacks + effect.stacks).min(current.max_stacks.max(1));
+            }
+        }
+        current.duration = match (current.duration, effect.duration) {
+            (Some(current), Some(new)) => Some(current.max(new)),
+            _ => None,
+        };
+        current.remaining = current.duration;
+        true
+    }
+
+    /// Replaces every aura with `auras`, leaving timed and permanent effects alone
+    ///
+    /// Auras are listed first, in the order given.
+    pub fn set_auras(&mut self, auras: Vec<ActiveEffect>) {
+        self.effects.retain(|effect| !effect.aura);
+        self.effects.splice(0..0, auras.into_iter().map(|aura| ActiveEffect { aura: true, ..aura }));
+    }
+
+    /// Counts timed effects down and removes the ones that ran out
+    pub fn update(&mut self, delta_time: f32) {
+        for effect in &mut self.effects {
+            if let Some(remaining) = &mut effect.remaining {
+                *remaining -= delta_time;
+            }
+        }
+        self.effects.retain(|effect| effect.remaining.is_none_or(|remaining| remaining > 0.0));
+    }
+
+    /// Removes the effect with `key`, returning whether there was one
+    #[allow(dead_code)] // Reserved for cleansing items
+    pub fn remove(&mut self, key: &str) -> bool {
+        let before = self.effects.len();
+        self.effects.retain(|effect| effect.key != key);
+        self.effects.len() != before
+    }
+
+    /// Stat modifiers from every active effect, scaled by stacks
+    pub fn modifiers(&self) -> Vec<ModifierEffect> {
+        self.effects.iter().flat_map(ActiveEffect::stacked_modifiers).collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ActiveEffect> {
+        self.effects.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::{StatType, Stats};
+    use std::time::Duration;
+
+    fn flat(stat_type: StatType, value: f32, source: &str) -> ModifierEffect {
+        ModifierEffect {
+            stat_type,
+            modifier: StatModifier::Flat(value),
+            duration: None,
+            source: source.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_modifier_duration_is_honored() {
+        let mut effects = ActiveEffects::default();
+        let mut buff = flat(StatType::AttackDamage, 2.0, "Strength Potion");
+        buff.duration = Some(Duration::from_secs(3));
+        effects.apply(ActiveEffect::from_modifier(buff));
+        effects.apply(ActiveEffect::from_modifier(flat(StatType::Defense, 0.1, "Blessing")));
+
+        let stats = Stats::new();
+        assert_eq!(stats.effective_stat(StatType::AttackDamage, &effects.modifiers()), 5.0);
+
+        effects.update(2.0);
+        let potion = effects.iter().find(|effect| effect.key == "Strength Potion").unwrap();
+        assert!((potion.fraction_remaining().unwrap() - 1.0 / 3.0).abs() < 0.001);
+
+        // The timed buff runs out; the permanent one stays
+        effects.update(1.0);
+        assert_eq!(stats.effective_stat(StatType::AttackDamage, &effects.modifiers()), 3.0);
+        assert_eq!(effects.iter().count(), 1);
+        assert_eq!(effects.iter().next().unwrap().fraction_remaining(), None);
+    }
+
+    #[test]
+    fn test_stack_rules() {
+        let mut effects = ActiveEffects::default();
+        let rage = || {
+            ActiveEffect::timed("rage", vec![flat(StatType::AttackDamage, 1.0, "Rage")], 4.0)
+                .with_stacking(StackRule::StackIntensity, 3)
+        };
+        effects.apply(rage());
+        effects.update(3.0);
+        for _ in 0..5 {
+            effects.apply(rage());
+        }
+        let stacked = effects.iter().next().unwrap();
+        assert_eq!((stacked.stacks, stacked.remaining), (3, Some(4.0)));
+        assert_eq!(Stats::new().effective_stat(StatType::AttackDamage, &effects.modifiers()), 6.0);
+
+        // Refresh restarts the clock without stacking
+        effects.apply(ActiveEffect::timed("haste", vec![flat(StatType::MovementSpeed, 1.0, "Haste")], 2.0));
+        effects.update(1.5);
+        assert!(effects.apply(ActiveEffect::timed("haste", vec![flat(StatType::MovementSpeed, 1.0, "Haste")], 2.0)));
+        let haste = effects.iter().find(|effect| effect.key == "haste").unwrap();
+        assert_eq!((haste.stacks, haste.remaining), (1, Some(2.0)));
+
+        // Ignore keeps the current effect
+        let ward = || ActiveEffect::timed("ward", vec![], 1.0).with_stacking(StackRule::Ignore, 1);
+        effects.apply(ward());
+        effects.update(0.5);
+        assert!(!effects.apply(ward()));
+        assert!(effects.remove("ward"));
+    }
+
+    #[test]
+    fn test_auras_are_replaced_by_source() {
+        let mut effects = ActiveEffects::default();
+        effects.apply(ActiveEffect::timed("potion", vec![flat(StatType::AttackDamage, 2.0, "Potion")], 10.0));
+
+        effects.set_auras(vec![
+            ActiveEffect::aura("pyramid_1", vec![flat(StatType::AttackDamage, 1.0, "Pyramid of Attack")]),
+            ActiveEffect::aura("pyramid_2", vec![flat(StatType::AttackDamage, 1.0, "Pyramid of Attack")]),
+        ]);
+        assert_eq!(Stats::new().effective_stat(StatType::AttackDamage, &effects.modifiers()), 7.0);
+        assert_eq!(effects.iter().next().unwrap().key, "pyramid_1");
+
+        // Auras never count down, and drop out once their source stops listing them
+        effects.update(100.0);
+        effects.set_auras(vec![
+            ActiveEffect::aura("pyramid_2", vec![flat(StatType::AttackDamage, 1.0, "Pyramid of Attack")]),
+        ]);
+        assert_eq!(effects.iter().count(), 1);
+        assert_eq!(effects.iter().next().unwrap().name(), "Pyramid of Attack");
+    }
+
+    #[test]
+    fn test_saves_round_trip() {
+        let mut effects = ActiveEffects::default();
+        effects.apply(ActiveEffect::timed("potion", vec![flat(StatType::Defense, 0.2, "Potion")], 10.0));
+        effects.update(4.0);
+
+        let json = serde_json::to_string(&effects).unwrap();
+        let restored: ActiveEffects = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, effects);
+    }
+}
@@ -0,0 +1,233 @@
+//! In-game clock and day/night cycle
+//!
+//! `GameClock` tracks the time of day in in-game minutes (0-1440) plus a day counter.
+//! It advances with simulated time (the real frame delta, capped at
+//! `physics::MAX_FRAME_DELTA`), so it stops whenever the game stops updating,
+//! and it can additionally be paused or sped up on its own.
+//!
+//! # Time Scale
+//!
+//! At a time scale of 1.0, one in-game day lasts `REAL_SECONDS_PER_DAY` seconds.
+//! The debug menu can raise the scale to fast-forward through days while testing.
+//!
+//! # Queries
+//!
+//! Other systems only ever read from the clock:
+//! - `is_night()` - slime spawning gets more aggressive at night
+//! - `update()` returns how many days rolled over - crops grow once per day
+//! - `tint()` - the renderer darkens the world layer
+//! - `time_text()` - the HUD clock
+
+use sdl2::pixels::Color;
+use serde::{Deserialize, Serialize};
+
+/// In-game minutes in one day
+pub const MINUTES_PER_DAY: f32 = 1440.0;
+
+/// Real seconds for one in-game day at time scale 1.0
+pub const REAL_SECONDS_PER_DAY: f32 = 600.0;
+
+/// Hour the first day starts at
+const START_HOUR: f32 = 8.0;
+
+/// Night starts at this hour (inclusive)
+const NIGHT_START_HOUR: u32 = 20;
+
+/// Night ends at this hour (exclusive)
+const NIGHT_END_HOUR: u32 = 6;
+
+/// Strongest night tint (applied at midnight)
+const NIGHT_TINT: (u8, u8, u8, u8) = (10, 10, 50, 150);
+
+/// Warm tint applied around sunrise and sunset
+const DUSK_TINT: (u8, u8, u8, u8) = (120, 50, 20, 60);
+
+/// Tracks time of day, day count, pause state and time scale
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameClock {
+    /// Current day (starts at 1)
+    pub day: u32,
+    /// Minutes since midnight (0.0 - 1440.0)
+    pub minutes: f32,
+    /// Multiplier applied to simulated time (0.0 = frozen)
+    pub time_scale: f32,
+    /// When paused, the clock doesn't advance at all
+    pub paused: bool,
+}
+
+impl GameClock {
+    /// Creates a clock at the morning of day 1
+    pub fn new() -> Self {
+        GameClock {
+            day: 1,
+            minutes: START_HOUR * 60.0,
+            time_scale: 1.0,
+            paused: false,
+        }
+    }
+
+    /// Advances the clock by `delta_time` seconds of simulated time
+    ///
+    /// # Returns
+    /// Number of midnights crossed during this update (usually 0)
+    pub fn update(&mut self, delta_time: f32) -> u32 {
+        if self.paused {
+            return 0;
+        }
+
+        let minutes_per_second = MINUTES_PER_DAY / REAL_SECONDS_PER_DAY;
+        self.minutes += delta_time * self.time_scale * minutes_per_second;
+
+        let mut days_passed = 0;
+        while self.minutes >= MINUTES_PER_DAY {
+            self.minutes -= MINUTES_PER_DAY;
+            self.day += 1;
+            days_passed += 1;
+        }
+
+        days_passed
+    }
+
+    /// Toggles the pause state, returning the new state
+    pub fn toggle_pause(&mut self) -> bool {
+        self.paused = !self.paused;
+        self.paused
+    }
+
+    /// Sets the time scale (negative values are clamped to 0)
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = time_scale.max(0.0);
+    }
+
+    /// Jumps to a time of day without advancing the day counter
+    pub fn set_time_of_day(&mut self, hour: u32, minute: u32) {
+        self.minutes = ((hour % 24) * 60 + minute % 60) as f32;
+    }
+
+    /// Current hour (0-23)
+    pub fn hour(&self) -> u32 {
+        (self.minutes / 60.0) as u32 % 24
+    }
+
+    /// Current minute within the hour (0-59)
+    pub fn minute(&self) -> u32 {
+        self.minutes as u32 % 60
+    }
+
+    /// Returns true between NIGHT_START_HOUR and NIGHT_END_HOUR
+    pub fn is_night(&self) -> bool {
+        let hour = self.hour();
+        !(NIGHT_END_HOUR..NIGHT_START_HOUR).contains(&hour)
+    }
+
+    /// How dark it is, from 0.0 (full daylight) to 1.0 (midnight)
+    ///
+    /// Full daylight from 8:00 to 18:00, fading to full dark by 22:00
+    /// and back to light between 4:00 and 8:00.
+    pub fn darkness(&self) -> f32 {
+        let hour = self.minutes / 60.0;
+        match hour {
+            h if (8.0..18.0).contains(&h) => 0.0,
+            h if (18.0..22.0).contains(&h) => (h - 18.0) / 4.0,
+            h if (4.0..8.0).contains(&h) => 1.0 - (h - 4.0) / 4.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Overlay color for the world layer (alpha 0 during full daylight)
+    ///
+    /// Blends a warm dusk tint into a deep blue night tint as darkness increases.
+    pub fn tint(&self) -> Color {
+        let darkness = self.darkness();
+        if darkness <= 0.0 {
+            return Color::RGBA(0, 0, 0, 0);
+        }
+
+        // Dusk color peaks halfway into the transition, night takes over after
+        let night_weight = (darkness * 2.0 - 1.0).clamp(0.0, 1.0);
+        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * night_weight) as u8;
+
+        let alpha = (DUSK_TINT.3 as f32 + (NIGHT_TINT.3 as f32 - DUSK_TINT.3 as f32) * night_weight)
+            * darkness.min(0.5) * 2.0;
+
+        Color::RGBA(
+            lerp(DUSK_TINT.0, NIGHT_TINT.0),
+            lerp(DUSK_TINT.1, NIGHT_TINT.1),
+            lerp(DUSK_TINT.2, NIGHT_TINT.2),
+            alpha as u8,
+        )
+    }
+
+    /// HUD text, e.g. "DAY 3  14:05"
+    pub fn time_text(&self) -> String {
+        format!("DAY {}  {:02}:{:02}", self.day, self.hour(), self.minute())
+    }
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_clock_starts_in_morning() {
+        let clock = GameClock::new();
+        assert_eq!(clock.day, 1);
+        assert_eq!(clock.hour(), 8);
+        assert!(!clock.is_night());
+    }
+
+    #[test]
+    fn test_full_day_rolls_over() {
+        let mut clock = GameClock::new();
+        let days = clock.update(REAL_SECONDS_PER_DAY);
+        assert_eq!(days, 1);
+        assert_eq!(clock.day, 2);
+        assert_eq!(clock.hour(), 8);
+    }
+
+    #[test]
+    fn test_time_scale_speeds_up_clock() {
+        let mut clock = GameClock::new();
+        clock.set_time_scale(2.0);
+        clock.update(REAL_SECONDS_PER_DAY / 4.0); // Half a day at 2x
+        assert_eq!(clock.hour(), 20);
+        assert!(clock.is_night());
+    }
+
+    #[test]
+    fn test_paused_clock_does_not_advance() {
+        let mut clock = GameClock::new();
+        clock.toggle_pause();
+        assert_eq!(clock.update(REAL_SECONDS_PER_DAY * 3.0), 0);
+        assert_eq!(clock.day, 1);
+        assert_eq!(clock.hour(), 8);
+    }
+
+    #[test]
+    fn test_darkness_and_tint() {
+        let mut clock = GameClock::new();
+        clock.set_time_of_day(12, 0);
+        assert_eq!(clock.darkness(), 0.0);
+        assert_eq!(clock.tint().a, 0);
+
+        clock.set_time_of_day(0, 0);
+        assert_eq!(clock.darkness(), 1.0);
+        assert_eq!(clock.tint().a, NIGHT_TINT.3);
+
+        clock.set_time_of_day(20, 0);
+        assert!(clock.darkness() > 0.0 && clock.darkness() < 1.0);
+    }
+
+    #[test]
+    fn test_time_text() {
+        let mut clock = GameClock::new();
+        clock.set_time_of_day(7, 5);
+        assert_eq!(clock.time_text(), "DAY 1  07:05");
+    }
+}
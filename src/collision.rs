@@ -7,8 +7,14 @@
 ///
 /// - `Collidable` trait: Implemented by dynamic entities that can collide with each other
 /// - `CollisionLayer`: Enum to categorize entities for collision filtering
+/// - `CollisionMatrix`: Which layers collide (push apart), only trigger, or ignore each other
+/// - `detect_collisions()`: Generic pass turning a list of `Collider`s into `CollisionEvent`s
 /// - AABB functions: Pure functions for rectangle intersection detection
 ///
+/// The game builds one collider list per frame (player, enemies, projectiles,
+/// walls) and reacts to the events by layer pair, so a new entity type only
+/// needs a layer, matrix entries and a match arm - not its own loop.
+///
 /// # Rust Learning Notes
 ///
 /// This module demonstrates:
@@ -16,22 +22,24 @@
 /// - **Enums for categorization**: Type-safe collision layer system
 /// - **Pure functions**: Stateless collision detection logic
 use crate::render::DepthSortable;
+use crate::spatial::SpatialHash;
+use std::collections::HashMap;
+use sdl2::pixels::Color;
 use sdl2::rect::Rect;
 use sdl2::render::Canvas;
 use sdl2::video::Window;
 
 /// Represents different categories of collidable objects.
 ///
-/// Used for collision filtering - e.g., player attacks should only hit enemies,
-/// not other players or friendly NPCs.
+/// Used for collision filtering through the `CollisionMatrix`. Projectiles
+/// also use their owner's layer to decide what they hit (see projectile.rs).
 ///
-/// Note: Currently unused but included for future collision filtering features.
-#[allow(dead_code)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Variants are ordered: `CollisionEvent`s always list the lower layer first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum CollisionLayer {
     /// Player character
     Player,
-    /// Enemy entities (slimes, goblins, etc.)
+    /// Enemy entities (slimes, spitters, etc.)
     Enemy,
     /// Projectiles (arrows, fireballs, etc.)
     Projectile,
@@ -75,9 +83,6 @@ pub trait Collidable {
     /// Returns the collision layer this entity belongs to.
     ///
     /// Used for filtering which entities can collide with each other.
-    ///
-    /// Note: Currently unused but included for future collision filtering features.
-    #[allow(dead_code)]
     fn get_collision_layer(&self) -> CollisionLayer;
 }
 
@@ -141,10 +146,10 @@ pub fn aabb_intersect(a: &Rect, b: &Rect) -> bool {
 /// // Use the smaller overlap to determine push direction
 /// if overlap_x.abs() < overlap_y.abs() {
 ///     // Push apart on X axis
-///     player.x += overlap_x;
+///     player.apply_push(-overlap_x as f32, 0.0);
 /// } else {
 ///     // Push apart on Y axis
-///     player.y += overlap_y;
+///     player.apply_push(0.0, -overlap_y as f32);
 /// }
 /// ```
 pub fn calculate_overlap(a: &Rect, b: &Rect) -> (i32, i32) {
@@ -175,45 +180,6 @@ pub fn calculate_overlap(a: &Rect, b: &Rect) -> (i32, i32) {
     (overlap_x, overlap_y)
 }
 
-/// Checks collision between a single collidable entity and a collection of other entities.
-///
-/// This is a generic helper function that works with any type implementing `Collidable`.
-/// It returns a vector of indices where collisions occurred.
-///
-/// # Type Parameters
-///
-/// - `T`: The type of entities in the collection (must implement `Collidable`)
-///
-/// # Returns
-///
-/// A vector of indices into `entities` where collisions with `entity` were detected.
-///
-/// # Example
-///
-/// ```rust
-/// let colliding_indices = check_collisions_with_collection(&player, &slimes);
-/// for index in colliding_indices {
-///     println!("Player collided with slime {}", index);
-/// }
-/// ```
-pub fn check_collisions_with_collection<T: Collidable>(
-    entity: &impl Collidable,
-    entities: &[T],
-) -> Vec<usize> {
-    let entity_bounds = entity.get_bounds();
-    let mut collisions = Vec::new();
-
-    for (index, other) in entities.iter().enumerate() {
-        let other_bounds = other.get_bounds();
-
-        if aabb_intersect(&entity_bounds, &other_bounds) {
-            collisions.push(index);
-        }
-    }
-
-    collisions
-}
-
 /// Trait for static (non-moving) world objects that participate in collision.
 ///
 /// This is separate from `Collidable` because static objects have different behavior:
@@ -246,21 +212,34 @@ pub trait StaticCollidable {
 ///
 /// This is a simple struct that implements `StaticCollidable` and can be
 /// used for any immovable obstacle in the game world.
+#[derive(Debug, Clone)]
 pub struct StaticObject {
     pub x: i32,
     pub y: i32,
     pub width: u32,
     pub height: u32,
+    /// Visible objects (placed by maps/the editor) render as stone blocks;
+    /// invisible ones (boundary walls) only exist for collision
+    pub visible: bool,
 }
 
 impl StaticObject {
-    /// Creates a new static object at the given position with the given size.
+    /// Creates a new invisible static object at the given position with the given size.
     pub fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
         StaticObject {
             x,
             y,
             width,
             height,
+            visible: false,
+        }
+    }
+
+    /// Creates a visible, solid block (rocks and walls placed on a map).
+    pub fn solid(x: i32, y: i32, width: u32, height: u32) -> Self {
+        StaticObject {
+            visible: true,
+            ..StaticObject::new(x, y, width, height)
         }
     }
 }
@@ -281,55 +260,171 @@ impl StaticCollidable for StaticObject {
 /// render methods. See docs/systems/depth-sorting-render-system.md for details.
 impl DepthSortable for StaticObject {
     fn get_depth_y(&self) -> i32 {
-        // For simple static objects, the anchor is at the Y position
-        // More complex objects would calculate based on sprite_height
-        self.y
+        // Visible blocks sort by their base so the player can walk behind them.
+        // Invisible objects keep the simple Y anchor (they never draw anyway)
+        if self.visible {
+            self.y + self.height as i32
+        } else {
+            self.y
+        }
     }
 
-    fn render(&self, _canvas: &mut Canvas<Window>) -> Result<(), String> {
-        // Simple static objects (like boundary walls) don't render visually
-        // They only exist for collision detection
-        // More complex static objects (trees, rocks) would have sprite rendering here
+    fn render(&self, canvas: &mut Canvas<Window>) -> Result<(), String> {
+        // Invisible objects (like boundary walls) only exist for collision detection
+        if !self.visible {
+            return Ok(());
+        }
 
-        // For now, return Ok without rendering anything
-        // This prevents invisible boundary walls from appearing in the depth-sorted render
+        // Visible blocks are drawn as a simple stone block with a darker outline
+        // (no sprite yet)
+        let bounds = self.get_bounds();
+        canvas.set_draw_color(Color::RGB(112, 108, 100));
+        canvas.fill_rect(bounds)?;
+        canvas.set_draw_color(Color::RGB(68, 64, 60));
+        canvas.draw_rect(bounds)?;
         Ok(())
     }
 }
 
-/// Checks for collisions between a moving entity and static world objects.
-///
-/// Returns indices of all static objects the entity is colliding with.
+/// How a pair of collision layers interacts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayerInteraction {
+    /// Never tested against each other
+    Ignore,
+    /// Overlaps are reported but nothing is pushed (hits, pickups)
+    Trigger,
+    /// Overlaps are reported so the pair can be pushed apart
+    Collide,
+}
+
+/// Symmetric table of how every pair of layers interacts
 ///
 /// # Example
 ///
 /// ```rust
-/// let static_objects = vec![
-///     StaticObject::new(100, 100, 64, 64),
-///     StaticObject::new(300, 200, 96, 96),
-/// ];
-///
-/// let colliding_indices = check_static_collisions(&player, &static_objects);
-/// for index in colliding_indices {
-///     // Resolve collision with static_objects[index]
-/// }
+/// let mut matrix = CollisionMatrix::standard();
+/// // Enemies shove each other apart
+/// matrix.set(CollisionLayer::Enemy, CollisionLayer::Enemy, LayerInteraction::Collide);
 /// ```
-pub fn check_static_collisions(
-    entity: &impl Collidable,
-    static_objects: &[&dyn StaticCollidable],
-) -> Vec<usize> {
-    let entity_bounds = entity.get_bounds();
-    let mut collisions = Vec::new();
-
-    for (index, static_obj) in static_objects.iter().enumerate() {
-        let obj_bounds = static_obj.get_bounds();
-
-        if aabb_intersect(&entity_bounds, &obj_bounds) {
-            collisions.push(index);
+#[derive(Debug, Clone)]
+pub struct CollisionMatrix {
+    interactions: HashMap<(CollisionLayer, CollisionLayer), LayerInteraction>,
+}
+
+impl CollisionMatrix {
+    /// A matrix where every pair is ignored
+    pub fn new() -> Self {
+        CollisionMatrix { interactions: HashMap::new() }
+    }
+
+    /// The game's rules:
+    /// - the player, enemies and walls push each other apart (enemies don't push enemies)
+    /// - projectiles trigger on the player, enemies and walls
+    /// - dropped items are picked up by their own proximity check
+    pub fn standard() -> Self {
+        use CollisionLayer::*;
+        use LayerInteraction::*;
+
+        let mut matrix = Self::new();
+        matrix.set(Player, Enemy, Collide);
+        matrix.set(Player, Static, Collide);
+        matrix.set(Enemy, Static, Collide);
+        matrix.set(Projectile, Player, Trigger);
+        matrix.set(Projectile, Enemy, Trigger);
+        matrix.set(Projectile, Static, Trigger);
+        matrix
+    }
+
+    /// Sets how two layers interact (order doesn't matter)
+    pub fn set(&mut self, a: CollisionLayer, b: CollisionLayer, interaction: LayerInteraction) {
+        self.interactions.insert(Self::key(a, b), interaction);
+    }
+
+    pub fn interaction(&self, a: CollisionLayer, b: CollisionLayer) -> LayerInteraction {
+        self.interactions.get(&Self::key(a, b)).copied().unwrap_or(LayerInteraction::Ignore)
+    }
+
+    fn key(a: CollisionLayer, b: CollisionLayer) -> (CollisionLayer, CollisionLayer) {
+        if a <= b { (a, b) } else { (b, a) }
+    }
+}
+
+impl Default for CollisionMatrix {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Grid cell size used by `detect_collisions` (about two enemy hitboxes)
+const BROADPHASE_CELL_SIZE: i32 = 64;
+
+/// One object taking part in a collision pass
+///
+/// `index` points into whatever collection the caller built the collider from
+/// (e.g. `enemies[index]`), so events can be mapped back to objects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Collider {
+    pub layer: CollisionLayer,
+    pub index: usize,
+    pub bounds: Rect,
+}
+
+impl Collider {
+    /// A dynamic entity on its own layer
+    pub fn of(object: &impl Collidable, index: usize) -> Self {
+        Collider { layer: object.get_collision_layer(), index, bounds: object.get_bounds() }
+    }
+
+    /// A static object (always on the Static layer)
+    pub fn fixed(object: &dyn StaticCollidable, index: usize) -> Self {
+        Collider { layer: CollisionLayer::Static, index, bounds: object.get_bounds() }
+    }
+}
+
+/// An overlap found by `detect_collisions`; `.0` is always on the lower layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollisionEvent {
+    /// A `Collide` pair overlaps and should be pushed apart
+    Collide(Collider, Collider),
+    /// A `Trigger` pair overlaps
+    Trigger(Collider, Collider),
+}
+
+/// Tests every pair of colliders whose layers interact
+///
+/// Candidate pairs come from a `SpatialHash`, so only colliders sharing a grid
+/// cell are compared; the events are the same (and in the same order) as
+/// testing every pair. Events involving the Static layer come last, so callers that push objects
+/// apart in order settle dynamic pairs before walls get the final say.
+/// Overlaps are measured before anything moves; callers that push should
+/// re-measure with `calculate_overlap` when resolving.
+pub fn detect_collisions(colliders: &[Collider], matrix: &CollisionMatrix) -> Vec<CollisionEvent> {
+    let mut hash = SpatialHash::new(BROADPHASE_CELL_SIZE);
+    hash.rebuild(colliders.iter().enumerate().map(|(i, collider)| (i, collider.bounds)));
+
+    let mut events = Vec::new();
+    for (i, a) in colliders.iter().enumerate() {
+        for j in hash.query_rect(&a.bounds).into_iter().filter(|&j| j > i) {
+            let b = &colliders[j];
+            let interaction = matrix.interaction(a.layer, b.layer);
+            if interaction == LayerInteraction::Ignore {
+                continue;
+            }
+
+            let (first, second) = if a.layer <= b.layer { (*a, *b) } else { (*b, *a) };
+            events.push(match interaction {
+                LayerInteraction::Collide => CollisionEvent::Collide(first, second),
+                _ => CollisionEvent::Trigger(first, second),
+            });
         }
     }
 
-    collisions
+    // Stable sort keeps the original order within each group
+    events.sort_by_key(|event| {
+        let (CollisionEvent::Collide(a, b) | CollisionEvent::Trigger(a, b)) = event;
+        a.layer == CollisionLayer::Static || b.layer == CollisionLayer::Static
+    });
+    events
 }
 
 #[cfg(test)]
@@ -401,6 +496,99 @@ mod tests {
         assert_eq!(overlap_y, 12);
     }
 
+    fn collider(layer: CollisionLayer, index: usize, x: i32, y: i32) -> Collider {
+        Collider { layer, index, bounds: Rect::new(x, y, 32, 32) }
+    }
+
+    #[test]
+    fn test_matrix_is_symmetric_and_defaults_to_ignore() {
+        let mut matrix = CollisionMatrix::new();
+        assert_eq!(matrix.interaction(CollisionLayer::Player, CollisionLayer::Enemy), LayerInteraction::Ignore);
+
+        matrix.set(CollisionLayer::Enemy, CollisionLayer::Player, LayerInteraction::Trigger);
+        assert_eq!(matrix.interaction(CollisionLayer::Player, CollisionLayer::Enemy), LayerInteraction::Trigger);
+
+        let standard = CollisionMatrix::standard();
+        assert_eq!(standard.interaction(CollisionLayer::Static, CollisionLayer::Player), LayerInteraction::Collide);
+        assert_eq!(standard.interaction(CollisionLayer::Enemy, CollisionLayer::Enemy), LayerInteraction::Ignore);
+        assert_eq!(standard.interaction(CollisionLayer::Static, CollisionLayer::Projectile), LayerInteraction::Trigger);
+    }
+
+    #[test]
+    fn test_detect_collisions_filters_by_matrix() {
+        let colliders = [
+            collider(CollisionLayer::Static, 0, 20, 0),
+            collider(CollisionLayer::Player, 0, 0, 0),
+            collider(CollisionLayer::Enemy, 0, 10, 0),
+            collider(CollisionLayer::Enemy, 1, 12, 0),
+            collider(CollisionLayer::Projectile, 0, 200, 200),
+        ];
+        let events = detect_collisions(&colliders, &CollisionMatrix::standard());
+
+        // Player-enemy pairs first, then everything touching the wall; lower layer first;
+        // enemy-enemy is ignored and the far projectile touches nothing
+        assert_eq!(events, vec![
+            CollisionEvent::Collide(colliders[1], colliders[2]),
+            CollisionEvent::Collide(colliders[1], colliders[3]),
+            CollisionEvent::Collide(colliders[1], colliders[0]),
+            CollisionEvent::Collide(colliders[2], colliders[0]),
+            CollisionEvent::Collide(colliders[3], colliders[0]),
+        ]);
+    }
+
+    #[test]
+    fn test_trigger_pairs_report_triggers() {
+        let colliders = [
+            collider(CollisionLayer::Projectile, 3, 0, 0),
+            collider(CollisionLayer::Enemy, 7, 16, 16),
+        ];
+        let events = detect_collisions(&colliders, &CollisionMatrix::standard());
+        assert_eq!(events, vec![CollisionEvent::Trigger(colliders[1], colliders[0])]);
+    }
+
+    #[test]
+    fn test_detect_collisions_matches_brute_force() {
+        let mut rng = crate::rng::SimpleRng::new(42);
+        let layers = [CollisionLayer::Player, CollisionLayer::Enemy, CollisionLayer::Projectile, CollisionLayer::Static];
+        let colliders: Vec<Collider> = (0..2000)
+            .map(|index| Collider {
+                layer: layers[rng.range_u32(0, 3) as usize],
+                index,
+                bounds: Rect::new(
+                    rng.range_u32(0, 2000) as i32 - 200,
+                    rng.range_u32(0, 1200) as i32 - 200,
+                    rng.range_u32(4, 96),
+                    rng.range_u32(4, 96),
+                ),
+            })
+            .collect();
+        let mut matrix = CollisionMatrix::standard();
+        matrix.set(CollisionLayer::Enemy, CollisionLayer::Enemy, LayerInteraction::Trigger);
+
+        // Every pair, in order, the way detection worked before the broadphase
+        let mut expected = Vec::new();
+        for (i, a) in colliders.iter().enumerate() {
+            for b in &colliders[i + 1..] {
+                let interaction = matrix.interaction(a.layer, b.layer);
+                if interaction == LayerInteraction::Ignore || !aabb_intersect(&a.bounds, &b.bounds) {
+                    continue;
+                }
+                let (first, second) = if a.layer <= b.layer { (*a, *b) } else { (*b, *a) };
+                expected.push(match interaction {
+                    LayerInteraction::Collide => CollisionEvent::Collide(first, second),
+                    _ => CollisionEvent::Trigger(first, second),
+                });
+            }
+        }
+        expected.sort_by_key(|event| {
+            let (CollisionEvent::Collide(a, b) | CollisionEvent::Trigger(a, b)) = event;
+            a.layer == CollisionLayer::Static || b.layer == CollisionLayer::Static
+        });
+
+        assert!(!expected.is_empty());
+        assert_eq!(detect_collisions(&colliders, &matrix), expected);
+    }
+
     #[test]
     fn test_calculate_overlap_diagonal() {
         // Diagonal overlap
@@ -4,7 +4,9 @@
 //! - Damage types (Physical, Magical, True)
 //! - Damage sources (Enemy, Environment, etc.)
 //! - Player state management (Alive/Dead)
-//! - Defense calculations
+//! - Resistance calculations (defense against Physical, magic resistance against Magical)
+//! - Damage rolls (±variance and critical hits, from a seeded `SimpleRng`)
+//! - Attack hitbox shapes (square, thrust/swing strip, arc)
 //!
 //! # Rust Learning Notes
 //!
@@ -13,17 +15,20 @@
 //! - **Pattern matching**: Using `matches!()` for state checks
 //! - **Type safety**: Different damage types are handled differently
 
+use crate::rng::SimpleRng;
+use crate::status::StatusEffect;
+use serde::{Deserialize, Serialize};
+use sdl2::rect::Rect;
 use std::time::Instant;
 
 /// Types of damage that can be dealt
 ///
-/// Different damage types may be affected by different resistances/defenses
+/// Each damage type is reduced by its own resistance (see `Resistances`)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[allow(dead_code)] // Magical and True damage reserved for future features
 pub enum DamageType {
     /// Physical damage (affected by defense stat)
     Physical,
-    /// Magical damage (could have separate resistance in future)
+    /// Magical damage (affected by magic resistance stat)
     Magical,
     /// True damage (ignores all defenses)
     True,
@@ -41,6 +46,8 @@ pub enum DamageSource {
     /// Damage from an enemy entity
     /// In the future, this could store an entity ID
     Enemy,
+    /// Damage from the player (attacks, thrown items)
+    Player,
     /// Environmental damage (spikes, lava, etc.)
     Environment,
     /// Self-inflicted damage (fall damage, self-destruct, etc.)
@@ -58,6 +65,9 @@ pub enum DamageSource {
 ///     amount: 25.0,
 ///     damage_type: DamageType::Physical,
 ///     source: DamageSource::Enemy,
+///     knockback: Some((180.0, 0.0)), // Shoved east
+///     status_effect: Some(StatusEffect::new(StatusKind::Poison, 0.5, 4.0)),
+///     is_crit: false,
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -69,6 +79,12 @@ pub struct DamageEvent {
     /// What caused this damage
     #[allow(dead_code)] // Reserved for future damage tracking features
     pub source: DamageSource,
+    /// Knockback impulse in pixels per second (before the target's resistance)
+    pub knockback: Option<(f32, f32)>,
+    /// Applied to the target if the hit lands
+    pub status_effect: Option<StatusEffect>,
+    /// The amount was boosted by a critical hit (see `DamageRoll`)
+    pub is_crit: bool,
 }
 
 impl DamageEvent {
@@ -78,28 +94,47 @@ impl DamageEvent {
             amount,
             damage_type: DamageType::Physical,
             source,
+            knockback: None,
+            status_effect: None,
+            is_crit: false,
         }
     }
 
     /// Creates a new magical damage event
-    #[allow(dead_code)] // Reserved for future magical damage features
     pub fn magical(amount: f32, source: DamageSource) -> Self {
         DamageEvent {
             amount,
             damage_type: DamageType::Magical,
             source,
+            knockback: None,
+            status_effect: None,
+            is_crit: false,
         }
     }
 
     /// Creates a new true damage event (ignores defenses)
-    #[allow(dead_code)] // Reserved for future true damage features
     pub fn true_damage(amount: f32, source: DamageSource) -> Self {
         DamageEvent {
             amount,
             damage_type: DamageType::True,
             source,
+            knockback: None,
+            status_effect: None,
+            is_crit: false,
         }
     }
+
+    /// Adds a knockback impulse (pixels per second)
+    pub fn with_knockback(mut self, impulse: (f32, f32)) -> Self {
+        self.knockback = Some(impulse);
+        self
+    }
+
+    /// Leaves a status effect on the target if the hit lands
+    pub fn with_status_effect(mut self, effect: StatusEffect) -> Self {
+        self.status_effect = Some(effect);
+        self
+    }
 }
 
 /// Player state for life/death management
@@ -193,6 +228,16 @@ pub struct AttackEvent {
     pub direction: crate::animation::Direction,
     /// Range of the attack in pixels
     pub range: i32,
+    /// Shape of the area in front of the attacker that `range` sizes
+    pub shape: HitboxShape,
+    /// Knockback speed (pixels per second) in the attack's direction; 0 for none
+    pub knockback: f32,
+    /// Left on whatever the attack hits
+    pub status_effect: Option<StatusEffect>,
+    /// Variance and crits applied per target by `roll_damage_event`
+    pub roll: DamageRoll,
+    /// Which attack of the attacker's combo chain this is (0 = first)
+    pub combo_step: usize,
 }
 
 impl AttackEvent {
@@ -203,26 +248,153 @@ impl AttackEvent {
             position,
             direction,
             range,
+            shape: HitboxShape::Square,
+            knockback: 0.0,
+            status_effect: None,
+            roll: DamageRoll::default(),
+            combo_step: 0,
         }
     }
 
+    /// Changes the hitbox from the default square (see `HitboxShape`)
+    pub fn with_shape(mut self, shape: HitboxShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Makes the attack knock targets back along its direction
+    pub fn with_knockback(mut self, strength: f32) -> Self {
+        self.knockback = strength;
+        self
+    }
+
+    /// Leaves a status effect on every target the attack lands on
+    pub fn with_status_effect(mut self, effect: StatusEffect) -> Self {
+        self.status_effect = Some(effect);
+        self
+    }
+
+    /// Rolls variance and crits for this attack's hits (see `roll_damage_event`)
+    pub fn with_roll(mut self, roll: DamageRoll) -> Self {
+        self.roll = roll;
+        self
+    }
+
+    /// Marks the attack as step `step` of a combo chain
+    pub fn with_combo_step(mut self, step: usize) -> Self {
+        self.combo_step = step;
+        self
+    }
+
     /// Converts this attack to a damage event
-    #[allow(dead_code)] // Reserved for future damage type features
+    ///
+    /// The knockback points the way the attack faces (`direction`), scaled by its strength.
     pub fn to_damage_event(&self, damage_type: DamageType, source: DamageSource) -> DamageEvent {
+        let (direction_x, direction_y) = self.direction.unit_vector();
         DamageEvent {
             amount: self.damage,
             damage_type,
             source,
+            knockback: (self.knockback > 0.0)
+                .then_some((direction_x * self.knockback, direction_y * self.knockback)),
+            status_effect: self.status_effect,
+            is_crit: false,
         }
     }
 
+    /// Converts this attack to a damage event with a fresh damage roll
+    ///
+    /// Call once per target, so each one hit can crit on its own.
+    pub fn roll_damage_event(&self, damage_type: DamageType, source: DamageSource, rng: &mut SimpleRng) -> DamageEvent {
+        let mut event = self.to_damage_event(damage_type, source);
+        (event.amount, event.is_crit) = self.roll.roll(self.damage, rng);
+        event
+    }
+
     /// Gets the attack hitbox as a Rect based on position, direction, and range
     ///
-    /// This creates a rectangular hitbox in front of the attacker based on their facing direction
-    pub fn get_hitbox(&self) -> sdl2::rect::Rect {
+    /// For the square shape this is the exact hitbox in front of the attacker;
+    /// for the other shapes it is their bounding box, good for broadphase
+    /// queries and debug drawing. Use `hits` for the real test.
+    pub fn get_hitbox(&self) -> Rect {
+        match self.shape {
+            HitboxShape::Square => self.square_hitbox(),
+            HitboxShape::Strip { width } => {
+                let (forward, side) = self.axes();
+                let half_width = width as f32 / 2.0;
+                let range = self.range as f32;
+                bounding_rect(self.position, &[
+                    (side.0 * half_width, side.1 * half_width),
+                    (-side.0 * half_width, -side.1 * half_width),
+                    (forward.0 * range + side.0 * half_width, forward.1 * range + side.1 * half_width),
+                    (forward.0 * range - side.0 * half_width, forward.1 * range - side.1 * half_width),
+                ])
+            }
+            HitboxShape::Arc { degrees } => {
+                // The origin plus points along the rim (close enough for a bounding box)
+                let (forward_x, forward_y) = self.direction.unit_vector();
+                let facing = forward_y.atan2(forward_x);
+                let half_angle = degrees.to_radians() / 2.0;
+                let range = self.range as f32;
+                let mut points = vec![(0.0, 0.0)];
+                for step in 0..=ARC_BOUND_STEPS {
+                    let angle = facing - half_angle + 2.0 * half_angle * step as f32 / ARC_BOUND_STEPS as f32;
+                    points.push((angle.cos() * range, angle.sin() * range));
+                }
+                bounding_rect(self.position, &points)
+            }
+        }
+    }
+
+    /// Checks whether the attack's shape reaches a target's bounds
+    ///
+    /// Non-square shapes test the target's point nearest the attacker and its
+    /// center, which is exact for anything smaller than the hitbox.
+    pub fn hits(&self, target: Rect) -> bool {
+        if !self.get_hitbox().has_intersection(target) {
+            return false;
+        }
+
+        let (origin_x, origin_y) = (self.position.0 as f32, self.position.1 as f32);
+        let nearest = (
+            origin_x.clamp(target.left() as f32, target.right() as f32),
+            origin_y.clamp(target.top() as f32, target.bottom() as f32),
+        );
+        let center = (target.center().x() as f32, target.center().y() as f32);
+
+        [nearest, center].into_iter().any(|(x, y)| self.contains_offset((x - origin_x, y - origin_y)))
+    }
+
+    /// Whether a point, relative to the attacker, is inside the shape
+    fn contains_offset(&self, (offset_x, offset_y): (f32, f32)) -> bool {
+        let (forward, side) = self.axes();
+        let along = offset_x * forward.0 + offset_y * forward.1;
+        let range = self.range as f32;
+        match self.shape {
+            // Already settled by the bounding box test
+            HitboxShape::Square => true,
+            HitboxShape::Strip { width } => {
+                let across = offset_x * side.0 + offset_y * side.1;
+                (0.0..=range).contains(&along) && across.abs() <= width as f32 / 2.0
+            }
+            HitboxShape::Arc { degrees } => {
+                let distance = (offset_x * offset_x + offset_y * offset_y).sqrt();
+                distance <= range
+                    && (distance <= f32::EPSILON || along >= distance * (degrees.to_radians() / 2.0).cos())
+            }
+        }
+    }
+
+    /// Unit vectors pointing along the attack and across it
+    fn axes(&self) -> ((f32, f32), (f32, f32)) {
+        let (forward_x, forward_y) = self.direction.unit_vector();
+        ((forward_x, forward_y), (-forward_y, forward_x))
+    }
+
+    /// A `range`-sized square in front of the attacker based on their facing direction
+    fn square_hitbox(&self) -> Rect {
         use crate::animation::Direction;
 
-        // Attack hitbox is a rectangle in front of the player
         let hitbox_size = self.range;
 
         // Calculate offset based on direction (all calculations in i32)
@@ -237,7 +409,7 @@ impl AttackEvent {
             Direction::NorthWest => (-hitbox_size, -hitbox_size),
         };
 
-        sdl2::rect::Rect::new(
+        Rect::new(
             self.position.0 + offset_x,
             self.position.1 + offset_y,
             hitbox_size as u32,
@@ -246,45 +418,133 @@ impl AttackEvent {
     }
 }
 
-/// Calculates final damage after applying defense
+/// Points sampled along an arc's rim for its bounding box
+const ARC_BOUND_STEPS: u32 = 16;
+
+/// The smallest rect around `origin` plus each offset
+fn bounding_rect(origin: (i32, i32), offsets: &[(f32, f32)]) -> Rect {
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (f32::MAX, f32::MAX, f32::MIN, f32::MIN);
+    for &(x, y) in offsets {
+        min_x = min_x.min(x);
+        min_y = min_y.min(y);
+        max_x = max_x.max(x);
+        max_y = max_y.max(y);
+    }
+    let (left, top) = (min_x.floor() as i32, min_y.floor() as i32);
+    let (right, bottom) = (max_x.ceil() as i32, max_y.ceil() as i32);
+    Rect::new(
+        origin.0 + left,
+        origin.1 + top,
+        (right - left).max(1) as u32,
+        (bottom - top).max(1) as u32,
+    )
+}
+
+/// The area an attack covers in front of the attacker
 ///
-/// # Formula
+/// # Example (weapon JSON)
+///
+/// ```json
+/// "shape": { "type": "strip", "width": 12 }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HitboxShape {
+    /// A `range`-sized square in front (the punch)
+    Square,
+    /// A `range`-long rectangle straight out along the facing, `width` pixels
+    /// across: a long thrust when narrow, a wide swing when wide
+    Strip { width: i32 },
+    /// A pie slice of radius `range` centered on the facing, `degrees` wide
+    Arc { degrees: f32 },
+}
+
+/// How much an attack's damage can vary from hit to hit
+///
+/// The default is a fixed roll: no variance and no crits.
+///
+/// # Example
+///
+/// ```rust
+/// let roll = DamageRoll { variance: 0.1, crit_chance: 0.05, crit_multiplier: 2.0 };
+/// let (amount, is_crit) = roll.roll(10.0, &mut rng);
+/// // 9.0..=11.0, or 18.0..=22.0 on a crit (5% of hits)
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DamageRoll {
+    /// Damage is scaled by a random factor in 1.0 ± variance (0.1 = ±10%)
+    pub variance: f32,
+    /// Chance of a critical hit (0.0 - 1.0)
+    pub crit_chance: f32,
+    /// Damage multiplier on a critical hit
+    pub crit_multiplier: f32,
+}
+
+impl Default for DamageRoll {
+    fn default() -> Self {
+        DamageRoll { variance: 0.0, crit_chance: 0.0, crit_multiplier: 1.0 }
+    }
+}
+
+impl DamageRoll {
+    /// Rolls the damage for one hit: returns (amount, is_crit)
+    ///
+    /// Always draws two numbers from `rng`, so a seeded generator gives the
+    /// same sequence of hits whatever the stats are.
+    pub fn roll(&self, base_damage: f32, rng: &mut SimpleRng) -> (f32, bool) {
+        let variance = self.variance.clamp(0.0, 1.0);
+        let scale = 1.0 + (rng.next_f32() * 2.0 - 1.0) * variance;
+        let is_crit = rng.chance(self.crit_chance);
+        let multiplier = if is_crit { self.crit_multiplier.max(1.0) } else { 1.0 };
+        (base_damage * scale * multiplier, is_crit)
+    }
+}
+
+/// Damage reduction against each resistible damage type
+///
+/// Each value is 0.0 (no reduction) to 1.0 (immune); values outside that range
+/// are clamped when damage is calculated. Build one from a target's stats with
+/// `Stats::resistances`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Resistances {
+    /// Against Physical damage (the Defense stat)
+    pub physical: f32,
+    /// Against Magical damage (the MagicResistance stat)
+    pub magical: f32,
+}
+
+impl Resistances {
+    /// The resistance that applies to a damage type (True damage has none)
+    pub fn against(&self, damage_type: DamageType) -> f32 {
+        match damage_type {
+            DamageType::Physical => self.physical,
+            DamageType::Magical => self.magical,
+            DamageType::True => 0.0,
+        }
+    }
+}
+
+/// Calculates final damage after applying the target's resistance to its type
 ///
-/// - **Physical damage**: `damage * (1.0 - defense)`
-///   - defense is clamped to 0.0-1.0 (0% to 100% reduction)
-///   - Example: 100 damage with 0.25 defense = 75 damage
+/// # Formula
 ///
-/// - **Magical damage**: Currently reduced by 20% flat (could add magic resistance later)
+/// - **Physical damage**: `damage * (1.0 - physical)`
+/// - **Magical damage**: `damage * (1.0 - magical)`
+/// - **True damage**: Ignores all resistances
 ///
-/// - **True damage**: Ignores all defenses
+/// Resistances are clamped to 0.0-1.0 (0% to 100% reduction).
 ///
 /// # Example
 ///
 /// ```rust
 /// let event = DamageEvent::physical(100.0, DamageSource::Enemy);
-/// let defense = 0.25; // 25% damage reduction
-/// let final_damage = calculate_damage_with_defense(&event, defense);
+/// let resistances = Resistances { physical: 0.25, magical: 0.0 }; // 25% physical reduction
+/// let final_damage = calculate_damage_with_resistances(&event, &resistances);
 /// assert_eq!(final_damage, 75.0);
 /// ```
-pub fn calculate_damage_with_defense(event: &DamageEvent, defense: f32) -> f32 {
-    // Clamp defense to valid range (0.0 = no reduction, 1.0 = full immunity)
-    let clamped_defense = defense.clamp(0.0, 1.0);
-
-    match event.damage_type {
-        DamageType::Physical => {
-            // Physical damage reduced by defense percentage
-            event.amount * (1.0 - clamped_defense)
-        }
-        DamageType::Magical => {
-            // Magical damage: 20% flat reduction for now
-            // In future, could add separate magic_resistance stat
-            event.amount * 0.8
-        }
-        DamageType::True => {
-            // True damage ignores all defenses
-            event.amount
-        }
-    }
+pub fn calculate_damage_with_resistances(event: &DamageEvent, resistances: &Resistances) -> f32 {
+    let resistance = resistances.against(event.damage_type).clamp(0.0, 1.0);
+    event.amount * (1.0 - resistance)
 }
 
 #[cfg(test)]
@@ -322,52 +582,141 @@ mod tests {
         assert_eq!(state.death_time(), Some(death_time));
     }
 
+    #[test]
+    fn test_each_type_uses_its_own_resistance() {
+        let events = [
+            DamageEvent::physical(100.0, DamageSource::Enemy),
+            DamageEvent::magical(100.0, DamageSource::Environment),
+            DamageEvent::true_damage(100.0, DamageSource::SelfInflicted),
+        ];
+        let values = [0.0, 0.25, 0.5, 1.0];
+
+        // Every damage type against every (physical, magical) pair
+        for event in &events {
+            for physical in values {
+                for magical in values {
+                    let resistances = Resistances { physical, magical };
+                    let expected = match event.damage_type {
+                        DamageType::Physical => 100.0 * (1.0 - physical),
+                        DamageType::Magical => 100.0 * (1.0 - magical),
+                        DamageType::True => 100.0,
+                    };
+                    assert_eq!(
+                        calculate_damage_with_resistances(event, &resistances),
+                        expected,
+                        "{:?} vs {:?}",
+                        event.damage_type,
+                        resistances
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_physical_damage_with_defense() {
         let event = DamageEvent::physical(100.0, DamageSource::Enemy);
+        let defense = |physical| Resistances { physical, magical: 0.0 };
 
         // 25% defense = 25% damage reduction
-        let damage = calculate_damage_with_defense(&event, 0.25);
+        let damage = calculate_damage_with_resistances(&event, &defense(0.25));
         assert_eq!(damage, 75.0);
 
         // 50% defense = 50% damage reduction
-        let damage = calculate_damage_with_defense(&event, 0.5);
+        let damage = calculate_damage_with_resistances(&event, &defense(0.5));
         assert_eq!(damage, 50.0);
 
         // 100% defense = 100% damage reduction (immunity)
-        let damage = calculate_damage_with_defense(&event, 1.0);
+        let damage = calculate_damage_with_resistances(&event, &defense(1.0));
         assert_eq!(damage, 0.0);
     }
 
     #[test]
     fn test_defense_clamping() {
-        let event = DamageEvent::physical(100.0, DamageSource::Enemy);
+        let physical = DamageEvent::physical(100.0, DamageSource::Enemy);
+        let magical = DamageEvent::magical(100.0, DamageSource::Environment);
 
-        // Defense > 1.0 should be clamped to 1.0
-        let damage = calculate_damage_with_defense(&event, 2.0);
-        assert_eq!(damage, 0.0);
+        // Resistance > 1.0 should be clamped to 1.0, negative resistance to 0.0
+        let resistances = Resistances { physical: 2.0, magical: -0.5 };
+        assert_eq!(calculate_damage_with_resistances(&physical, &resistances), 0.0);
+        assert_eq!(calculate_damage_with_resistances(&magical, &resistances), 100.0);
 
-        // Negative defense should be clamped to 0.0
-        let damage = calculate_damage_with_defense(&event, -0.5);
-        assert_eq!(damage, 100.0);
+        let resistances = Resistances { physical: -0.5, magical: 2.0 };
+        assert_eq!(calculate_damage_with_resistances(&physical, &resistances), 100.0);
+        assert_eq!(calculate_damage_with_resistances(&magical, &resistances), 0.0);
     }
 
     #[test]
     fn test_magical_damage() {
         let event = DamageEvent::magical(100.0, DamageSource::Environment);
-        let defense = 0.5; // Defense doesn't affect magical damage currently
 
-        let damage = calculate_damage_with_defense(&event, defense);
-        assert_eq!(damage, 80.0); // 20% flat reduction
+        // Defense doesn't affect magical damage; magic resistance does
+        let damage = calculate_damage_with_resistances(&event, &Resistances { physical: 0.5, magical: 0.0 });
+        assert_eq!(damage, 100.0);
+        let damage = calculate_damage_with_resistances(&event, &Resistances { physical: 0.0, magical: 0.3 });
+        assert!((damage - 70.0).abs() < 0.001);
     }
 
     #[test]
     fn test_true_damage_ignores_defense() {
         let event = DamageEvent::true_damage(100.0, DamageSource::SelfInflicted);
 
-        // True damage ignores defense completely
-        let damage = calculate_damage_with_defense(&event, 1.0);
-        assert_eq!(damage, 100.0);
+        // True damage ignores every resistance completely
+        let immune = Resistances { physical: 1.0, magical: 1.0 };
+        assert_eq!(immune.against(DamageType::True), 0.0);
+        assert_eq!(calculate_damage_with_resistances(&event, &immune), 100.0);
+    }
+
+    #[test]
+    fn test_fixed_roll_never_varies() {
+        let mut rng = SimpleRng::new(1);
+        for _ in 0..100 {
+            assert_eq!(DamageRoll::default().roll(3.0, &mut rng), (3.0, false));
+        }
+    }
+
+    #[test]
+    fn test_variance_stays_in_range() {
+        let roll = DamageRoll { variance: 0.2, ..DamageRoll::default() };
+        let mut rng = SimpleRng::new(5);
+        let amounts: Vec<f32> = (0..1000).map(|_| roll.roll(10.0, &mut rng).0).collect();
+
+        assert!(amounts.iter().all(|&amount| (8.0..=12.0).contains(&amount)));
+        // Both ends of the range actually come up
+        assert!(amounts.iter().any(|&amount| amount < 8.5));
+        assert!(amounts.iter().any(|&amount| amount > 11.5));
+    }
+
+    #[test]
+    fn test_crits_multiply_and_follow_chance() {
+        let always = DamageRoll { variance: 0.0, crit_chance: 1.0, crit_multiplier: 2.5 };
+        assert_eq!(always.roll(4.0, &mut SimpleRng::new(9)), (10.0, true));
+
+        let roll = DamageRoll { variance: 0.0, crit_chance: 0.25, crit_multiplier: 2.0 };
+        let mut rng = SimpleRng::new(9);
+        let hits: Vec<(f32, bool)> = (0..2000).map(|_| roll.roll(4.0, &mut rng)).collect();
+        let crits = hits.iter().filter(|(_, is_crit)| *is_crit).count();
+        assert!((400..600).contains(&crits), "{} crits", crits);
+        assert!(hits.iter().all(|&(amount, is_crit)| amount == if is_crit { 8.0 } else { 4.0 }));
+    }
+
+    #[test]
+    fn test_seeded_rolls_repeat() {
+        use crate::animation::Direction;
+
+        let roll = DamageRoll { variance: 0.1, crit_chance: 0.3, crit_multiplier: 2.0 };
+        let attack = AttackEvent::new(5.0, (0, 0), Direction::South, 32).with_roll(roll);
+        let hits = |seed| {
+            let mut rng = SimpleRng::new(seed);
+            (0..20)
+                .map(|_| {
+                    let event = attack.roll_damage_event(DamageType::Physical, DamageSource::Player, &mut rng);
+                    (event.amount, event.is_crit)
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(hits(77), hits(77));
+        assert_ne!(hits(77), hits(78));
     }
 
     #[test]
@@ -380,5 +729,62 @@ mod tests {
         assert_eq!(damage_event.amount, 50.0);
         assert_eq!(damage_event.damage_type, DamageType::Physical);
         assert_eq!(damage_event.source, DamageSource::Enemy);
+        assert_eq!(damage_event.knockback, None);
+    }
+
+    #[test]
+    fn test_attack_knockback_follows_direction() {
+        use crate::animation::Direction;
+
+        let attack = AttackEvent::new(1.0, (0, 0), Direction::West, 32).with_knockback(200.0);
+        let damage_event = attack.to_damage_event(DamageType::Physical, DamageSource::Player);
+        assert_eq!(damage_event.knockback, Some((-200.0, 0.0)));
+
+        let diagonal = AttackEvent::new(1.0, (0, 0), Direction::SouthEast, 32).with_knockback(100.0);
+        let (x, y) = diagonal.to_damage_event(DamageType::Physical, DamageSource::Player).knockback.unwrap();
+        assert!((x - 70.71).abs() < 0.01 && (y - 70.71).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_strip_reaches_far_but_not_wide() {
+        use crate::animation::Direction;
+
+        let thrust = AttackEvent::new(1.0, (0, 0), Direction::East, 64).with_shape(HitboxShape::Strip { width: 12 });
+        assert_eq!(thrust.get_hitbox(), Rect::new(0, -6, 64, 12));
+        assert!(thrust.hits(Rect::new(50, -4, 8, 8)));
+        assert!(!thrust.hits(Rect::new(20, 20, 8, 8)));
+        assert!(!thrust.hits(Rect::new(-20, -4, 8, 8)));
+
+        // Diagonal thrusts follow the diagonal instead of filling the square
+        let diagonal = AttackEvent::new(1.0, (0, 0), Direction::SouthEast, 64).with_shape(HitboxShape::Strip { width: 12 });
+        assert!(diagonal.hits(Rect::new(38, 38, 6, 6)));
+        assert!(!diagonal.hits(Rect::new(38, 0, 6, 6)));
+    }
+
+    #[test]
+    fn test_arc_covers_its_angle_and_radius() {
+        use crate::animation::Direction;
+
+        let swing = AttackEvent::new(1.0, (0, 0), Direction::North, 40).with_shape(HitboxShape::Arc { degrees: 120.0 });
+        assert!(swing.hits(Rect::new(-4, -36, 8, 8)));   // straight ahead
+        assert!(swing.hits(Rect::new(24, -24, 6, 6)));   // 45° off the facing
+        assert!(!swing.hits(Rect::new(30, -4, 6, 6)));   // off to the side
+        assert!(!swing.hits(Rect::new(-4, -60, 8, 8)));  // out of reach
+        assert!(!swing.hits(Rect::new(-4, 10, 8, 8)));   // behind
+
+        let bounds = swing.get_hitbox();
+        assert!(bounds.contains_point((0, -40)) && bounds.contains_point((34, -19)));
+        assert!(bounds.bottom() <= 1);
+    }
+
+    #[test]
+    fn test_square_hits_match_the_hitbox() {
+        use crate::animation::Direction;
+
+        let punch = AttackEvent::new(1.0, (100, 100), Direction::West, 32);
+        assert_eq!(punch.shape, HitboxShape::Square);
+        assert_eq!(punch.get_hitbox(), Rect::new(68, 84, 32, 32));
+        assert!(punch.hits(Rect::new(60, 110, 10, 10)));
+        assert!(!punch.hits(Rect::new(101, 90, 10, 10)));
     }
 }
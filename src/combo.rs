@@ -0,0 +1,109 @@
+//! Combo chains - pressing attack again in time continues the chain
+//!
+//! Each weapon has up to `MAX_COMBO_STEPS` attacks (see `ComboStep` in the
+//! weapon profile). After a swing the attacker must wait out that step's
+//! recovery; pressing attack within the weapon's combo window after that
+//! plays the next step. Waiting longer, finishing the chain, or being hit
+//! starts over from the first step.
+//!
+//! ```text
+//! swing 1 |-- recovery --|== window ==|
+//!                          ^ press: swing 2
+//!                                       ^ press: swing 1 again (timed out)
+//! ```
+//!
+//! The tracker only stores which step was last played and how long its
+//! recovery is; the caller passes the seconds since that swing.
+//!
+//! # Example
+//!
+//! ```rust
+//! let elapsed = last_attack_time.elapsed().as_secs_f32();
+//! if combo.is_recovered(elapsed) {
+//!     let step = combo.next_step(elapsed, weapon.combo_window, weapon.combo_len());
+//!     combo.advance(step, weapon.step_recovery(step, attack_speed));
+//! }
+//! ```
+
+/// Longest chain a weapon can define
+pub const MAX_COMBO_STEPS: usize = 3;
+
+/// Where the attacker is in their combo chain
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ComboTracker {
+    /// Index of the last step played, or None when the chain was reset
+    last_step: Option<usize>,
+    /// Seconds after the last swing before another attack is allowed
+    recovery: f32,
+}
+
+impl ComboTracker {
+    /// Whether the last swing's recovery is over
+    pub fn is_recovered(&self, elapsed: f32) -> bool {
+        elapsed >= self.recovery
+    }
+
+    /// The step an attack now would play (0 = first)
+    ///
+    /// Continues the chain if the last step wasn't the final one and `elapsed`
+    /// is still within `window` seconds after its recovery.
+    pub fn next_step(&self, elapsed: f32, window: f32, chain_length: usize) -> usize {
+        match self.last_step {
+            Some(step) if step + 1 < chain_length && elapsed <= self.recovery + window => step + 1,
+            _ => 0,
+        }
+    }
+
+    /// Records a swing of `step`, which needs `recovery` seconds before the next
+    pub fn advance(&mut self, step: usize, recovery: f32) {
+        self.last_step = Some(step);
+        self.recovery = recovery;
+    }
+
+    /// Drops the chain so the next attack is the first step (the recovery still applies)
+    pub fn reset(&mut self) {
+        self.last_step = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presses_in_the_window_advance_the_chain() {
+        let mut combo = ComboTracker::default();
+        assert_eq!(combo.next_step(0.0, 0.4, 3), 0);
+
+        combo.advance(0, 0.3);
+        assert!(!combo.is_recovered(0.2));
+        assert!(combo.is_recovered(0.3));
+        assert_eq!(combo.next_step(0.5, 0.4, 3), 1);
+
+        combo.advance(1, 0.3);
+        assert_eq!(combo.next_step(0.7, 0.4, 3), 2);
+
+        // The chain ends after its last step
+        combo.advance(2, 0.6);
+        assert_eq!(combo.next_step(0.7, 0.4, 3), 0);
+    }
+
+    #[test]
+    fn test_chain_resets_on_timeout_or_hit() {
+        let mut combo = ComboTracker::default();
+        combo.advance(0, 0.3);
+        assert_eq!(combo.next_step(0.71, 0.4, 3), 0);
+
+        combo.advance(1, 0.3);
+        combo.reset();
+        assert_eq!(combo.next_step(0.4, 0.4, 3), 0);
+        assert!(!combo.is_recovered(0.1));
+    }
+
+    #[test]
+    fn test_single_attack_weapons_never_chain() {
+        let mut combo = ComboTracker::default();
+        combo.advance(0, 0.5);
+        assert_eq!(combo.next_step(0.6, 1.0, 1), 0);
+    }
+}
@@ -79,7 +79,10 @@ impl<'a> DroppedItem<'a> {
     }
 
     pub fn render(&self, canvas: &mut Canvas<Window>) -> Result<(), String> {
-        let sprite_scale: u32 = if self.item_id == "slime_ball" || self.item_id == "stone" {
+        let sprite_scale: u32 = if matches!(
+            self.item_id.as_str(),
+            "slime_ball" | "stone" | "wheat_seeds" | "wheat" | "carrot_seeds" | "carrot" | "watering_can"
+        ) {
             1
         } else {
             2
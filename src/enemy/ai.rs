@@ -0,0 +1,317 @@
+// Enemy AI - chase/flee/return-home state machine
+//
+// Enemies move in hops: they rest on the ground, then hop toward the player,
+// away from them or back home depending on their AI mode. The state machine
+// and hop maths are pure functions so they can be tested without SDL.
+
+use crate::pathfinding::{self, NavGrid};
+use serde::{Deserialize, Serialize};
+
+/// Tunable AI parameters for an enemy type (the `ai` section of a definition)
+///
+/// Distances are in world pixels (measured between anchor points), times in seconds.
+/// Fields missing from a definition fall back to the regular slime's values.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnemyAiConfig {
+    /// Player within this distance starts a chase
+    pub aggro_radius: f32,
+    /// Chasing stops once the enemy is this far from home, or the player this far from the enemy
+    pub leash_distance: f32,
+    /// Flee when health drops to this fraction of max health (0.0 = never flee)
+    pub flee_health_fraction: f32,
+    /// Fleeing stops once the player is at least this far away
+    pub safe_distance: f32,
+    /// Distance covered by a single hop
+    pub hop_distance: f32,
+    /// Rest between hops while idle at home
+    pub idle_time: f32,
+    /// Rest between hops while chasing, fleeing or returning home
+    pub rest_time: f32,
+    /// A returning enemy counts as home within this distance
+    pub home_radius: f32,
+    /// Ranged enemies chase only until the player is this close, and back off
+    /// when the player gets nearer than 60% of it (0.0 = melee, close the gap)
+    pub preferred_distance: f32,
+}
+
+impl EnemyAiConfig {
+    /// The regular green slime
+    pub fn standard() -> Self {
+        EnemyAiConfig {
+            aggro_radius: 120.0,
+            leash_distance: 220.0,
+            flee_health_fraction: 0.25,
+            safe_distance: 160.0,
+            hop_distance: 28.0,
+            idle_time: 2.0,
+            rest_time: 0.6,
+            home_radius: 8.0,
+            preferred_distance: 0.0,
+        }
+    }
+}
+
+impl Default for EnemyAiConfig {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// What an enemy is trying to do between hops
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnemyAiMode {
+    /// Bouncing in place at home
+    Idle,
+    /// Hopping toward the player (or holding range, for ranged enemies)
+    Chase,
+    /// Hopping away from the player
+    Flee,
+    /// Hopping back to the home position
+    ReturnHome,
+}
+
+/// Decides the next AI mode from the current situation
+///
+/// - Idle -> Chase when the player enters the aggro radius (Flee instead when already hurt)
+/// - Chase -> Flee at low health, -> ReturnHome past the leash distance or without a player
+/// - Flee -> ReturnHome once the player is at the safe distance
+/// - ReturnHome -> Idle at home (the player is ignored on the way back)
+pub fn next_ai_mode(
+    mode: EnemyAiMode,
+    config: &EnemyAiConfig,
+    position: (f32, f32),
+    home: (f32, f32),
+    player: Option<(f32, f32)>,
+    health_fraction: f32,
+) -> EnemyAiMode {
+    let distance_to_home = distance(position, home);
+    let distance_to_player = player.map_or(f32::INFINITY, |p| distance(position, p));
+    let is_hurt = health_fraction <= config.flee_health_fraction;
+
+    match mode {
+        EnemyAiMode::Idle => {
+            if distance_to_player <= config.aggro_radius {
+                if is_hurt { EnemyAiMode::Flee } else { EnemyAiMode::Chase }
+            } else if distance_to_home > config.home_radius {
+                EnemyAiMode::ReturnHome
+            } else {
+                EnemyAiMode::Idle
+            }
+        }
+        EnemyAiMode::Chase => {
+            if player.is_none()
+                || distance_to_home > config.leash_distance
+                || distance_to_player > config.leash_distance
+            {
+                EnemyAiMode::ReturnHome
+            } else if is_hurt {
+                EnemyAiMode::Flee
+            } else {
+                EnemyAiMode::Chase
+            }
+        }
+        EnemyAiMode::Flee => {
+            if distance_to_player >= config.safe_distance {
+                EnemyAiMode::ReturnHome
+            } else {
+                EnemyAiMode::Flee
+            }
+        }
+        EnemyAiMode::ReturnHome => {
+            if distance_to_home <= config.home_radius {
+                EnemyAiMode::Idle
+            } else {
+                EnemyAiMode::ReturnHome
+            }
+        }
+    }
+}
+
+/// How a chasing enemy should close in on the player
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChaseIntent {
+    /// Hop toward the player
+    Approach,
+    /// In range: hop in place
+    Hold,
+    /// Too close for a ranged enemy: hop away
+    BackOff,
+}
+
+/// Melee enemies always approach; ranged enemies keep to their preferred distance
+pub fn chase_intent(config: &EnemyAiConfig, distance_to_player: f32) -> ChaseIntent {
+    if config.preferred_distance <= 0.0 || distance_to_player > config.preferred_distance {
+        ChaseIntent::Approach
+    } else if distance_to_player < config.preferred_distance * 0.6 {
+        ChaseIntent::BackOff
+    } else {
+        ChaseIntent::Hold
+    }
+}
+
+/// Offset covered by the next hop in the given mode, ignoring obstacles
+///
+/// Chase and ReturnHome never overshoot their target; Idle hops in place.
+pub fn hop_offset(
+    mode: EnemyAiMode,
+    config: &EnemyAiConfig,
+    position: (f32, f32),
+    home: (f32, f32),
+    player: Option<(f32, f32)>,
+) -> (f32, f32) {
+    let (target, away) = match (mode, player) {
+        (EnemyAiMode::Chase, Some(player)) => (player, false),
+        (EnemyAiMode::Flee, Some(player)) => (player, true),
+        (EnemyAiMode::ReturnHome, _) => (home, false),
+        _ => return (0.0, 0.0),
+    };
+
+    if away {
+        away_from(position, target, config.hop_distance)
+    } else {
+        hop_toward(position, target, config.hop_distance)
+    }
+}
+
+/// Offset moving from `position` toward `target`, at most `max_step` long
+pub fn hop_toward(position: (f32, f32), target: (f32, f32), max_step: f32) -> (f32, f32) {
+    let (dx, dy) = (target.0 - position.0, target.1 - position.1);
+    let length = (dx * dx + dy * dy).sqrt();
+    if length < f32::EPSILON {
+        return (0.0, 0.0);
+    }
+    let step = length.min(max_step);
+    (dx / length * step, dy / length * step)
+}
+
+/// Offset of exactly `step` pointing from `threat` through `position`
+pub fn away_from(position: (f32, f32), threat: (f32, f32), step: f32) -> (f32, f32) {
+    let (dx, dy) = hop_toward(threat, position, f32::INFINITY);
+    let length = (dx * dx + dy * dy).sqrt();
+    if length < f32::EPSILON {
+        return (0.0, 0.0);
+    }
+    (dx / length * step, dy / length * step)
+}
+
+/// Picks a hop away from danger that doesn't land in a blocked cell
+///
+/// Tries straight away first, then veers off at growing angles (45 and 90
+/// degrees either side). Stays put when cornered.
+pub fn flee_offset(away: (f32, f32), nav_position: (f32, f32), grid: &NavGrid) -> (f32, f32) {
+    if grid.width == 0 {
+        return away; // No navigation data: run blind
+    }
+
+    for degrees in [0.0f32, 45.0, -45.0, 90.0, -90.0] {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let offset = (away.0 * cos - away.1 * sin, away.0 * sin + away.1 * cos);
+        let landing = pathfinding::world_to_cell(nav_position.0 + offset.0, nav_position.1 + offset.1);
+        if grid.is_walkable(landing) {
+            return offset;
+        }
+    }
+    (0.0, 0.0)
+}
+
+pub fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    (dx * dx + dy * dy).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tile::{TileId, WorldGrid};
+    use sdl2::rect::Rect;
+
+    const HOME: (f32, f32) = (100.0, 100.0);
+
+    fn config() -> EnemyAiConfig {
+        EnemyAiConfig::standard()
+    }
+
+    #[test]
+    fn test_idle_enemy_aggros_only_inside_radius() {
+        let far = Some((100.0 + config().aggro_radius + 1.0, 100.0));
+        let near = Some((100.0 + config().aggro_radius - 1.0, 100.0));
+
+        assert_eq!(next_ai_mode(EnemyAiMode::Idle, &config(), HOME, HOME, far, 1.0), EnemyAiMode::Idle);
+        assert_eq!(next_ai_mode(EnemyAiMode::Idle, &config(), HOME, HOME, None, 1.0), EnemyAiMode::Idle);
+        assert_eq!(next_ai_mode(EnemyAiMode::Idle, &config(), HOME, HOME, near, 1.0), EnemyAiMode::Chase);
+    }
+
+    #[test]
+    fn test_chase_gives_up_past_leash() {
+        let leash = config().leash_distance;
+        let position = (100.0 + leash + 1.0, 100.0);
+        let player = Some((position.0 + 10.0, 100.0));
+        assert_eq!(next_ai_mode(EnemyAiMode::Chase, &config(), position, HOME, player, 1.0), EnemyAiMode::ReturnHome);
+
+        // Player ran away from an enemy still near home
+        let player = Some((100.0 + leash + 50.0, 100.0));
+        assert_eq!(next_ai_mode(EnemyAiMode::Chase, &config(), HOME, HOME, player, 1.0), EnemyAiMode::ReturnHome);
+
+        assert_eq!(next_ai_mode(EnemyAiMode::Chase, &config(), HOME, HOME, None, 1.0), EnemyAiMode::ReturnHome);
+    }
+
+    #[test]
+    fn test_low_health_flees_until_safe() {
+        let player = Some((120.0, 100.0));
+        assert_eq!(next_ai_mode(EnemyAiMode::Chase, &config(), HOME, HOME, player, 0.25), EnemyAiMode::Flee);
+        assert_eq!(next_ai_mode(EnemyAiMode::Idle, &config(), HOME, HOME, player, 0.1), EnemyAiMode::Flee);
+        assert_eq!(next_ai_mode(EnemyAiMode::Flee, &config(), HOME, HOME, player, 0.1), EnemyAiMode::Flee);
+
+        let safe = Some((100.0 + config().safe_distance, 100.0));
+        assert_eq!(next_ai_mode(EnemyAiMode::Flee, &config(), HOME, HOME, safe, 0.1), EnemyAiMode::ReturnHome);
+
+        // Enemies that never flee keep chasing
+        let fearless = EnemyAiConfig { flee_health_fraction: 0.0, ..config() };
+        assert_eq!(next_ai_mode(EnemyAiMode::Chase, &fearless, HOME, HOME, player, 0.1), EnemyAiMode::Chase);
+    }
+
+    #[test]
+    fn test_return_home_ignores_player_until_home() {
+        let away = (160.0, 100.0);
+        let player = Some((170.0, 100.0));
+        assert_eq!(next_ai_mode(EnemyAiMode::ReturnHome, &config(), away, HOME, player, 1.0), EnemyAiMode::ReturnHome);
+        assert_eq!(next_ai_mode(EnemyAiMode::ReturnHome, &config(), (104.0, 100.0), HOME, None, 1.0), EnemyAiMode::Idle);
+    }
+
+    #[test]
+    fn test_hop_offsets() {
+        let hop = config().hop_distance;
+
+        // Chase hops toward the player, flee hops away
+        assert_eq!(hop_offset(EnemyAiMode::Chase, &config(), HOME, HOME, Some((200.0, 100.0))), (hop, 0.0));
+        assert_eq!(hop_offset(EnemyAiMode::Flee, &config(), HOME, HOME, Some((200.0, 100.0))), (-hop, 0.0));
+
+        // Returning never overshoots home; idle hops in place
+        assert_eq!(hop_offset(EnemyAiMode::ReturnHome, &config(), (100.0, 110.0), HOME, None), (0.0, -10.0));
+        assert_eq!(hop_offset(EnemyAiMode::Idle, &config(), HOME, HOME, Some((120.0, 100.0))), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_ranged_enemies_keep_their_distance() {
+        let ranged = EnemyAiConfig { preferred_distance: 100.0, ..config() };
+        assert_eq!(chase_intent(&ranged, 150.0), ChaseIntent::Approach);
+        assert_eq!(chase_intent(&ranged, 80.0), ChaseIntent::Hold);
+        assert_eq!(chase_intent(&ranged, 40.0), ChaseIntent::BackOff);
+
+        // Melee enemies always close in
+        assert_eq!(chase_intent(&config(), 5.0), ChaseIntent::Approach);
+    }
+
+    #[test]
+    fn test_flee_veers_away_from_walls() {
+        let grid = NavGrid::from_world(&WorldGrid::new(4, 4, TileId::Grass), &[Rect::new(96, 0, 32, 128)]);
+
+        // Straight away is blocked (column 3), so the hop turns 45 degrees
+        let offset = flee_offset((28.0, 0.0), (70.0, 48.0), &grid);
+        assert!((offset.0 - 28.0 * 0.70710677).abs() < 0.01 && offset.1.abs() > 1.0, "{:?}", offset);
+
+        // Open grid: straight away
+        assert_eq!(flee_offset((28.0, 0.0), (16.0, 48.0), &grid), (28.0, 0.0));
+    }
+}
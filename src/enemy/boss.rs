@@ -0,0 +1,240 @@
+// Boss phases - health-threshold behavior changes for boss enemies
+//
+// A definition with a "boss" section is a boss. Its phases are listed from
+// full health down: each starts once the boss's health fraction falls to the
+// phase's `health_fraction` and swaps in that phase's attacks, hop tuning and
+// minion summons. Phases only ever advance, and a big hit can skip straight
+// past one.
+//
+// Example (inside assets/enemies/slime_king.json):
+//
+// "boss": {
+//   "title": "SLIME KING",
+//   "arena_width": 320, "arena_height": 240,
+//   "phases": [
+//     { "health_fraction": 1.0, "melee_attack": { ... } },
+//     { "health_fraction": 0.5, "melee_attack": { ... }, "ranged_attack": { ... },
+//       "rest_time": 0.4,
+//       "summon": { "enemy_id": "slime", "count": 2, "interval": 12.0, "max_alive": 3 } }
+//   ]
+// }
+
+use serde::{Deserialize, Serialize};
+
+use super::definition::{MeleeAttack, RangedAttack};
+
+/// What makes an enemy a boss: its title, arena and phases
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BossConfig {
+    /// Shown over the boss health bar
+    pub title: String,
+    /// Size of the area locked around the fight, in pixels
+    pub arena_width: u32,
+    pub arena_height: u32,
+    /// From full health down; the first must start at 1.0
+    pub phases: Vec<BossPhase>,
+}
+
+/// One stage of a boss fight
+///
+/// The attacks replace the boss's current ones (a missing attack means the
+/// boss doesn't use it in this phase); missing hop tuning keeps the previous
+/// phase's values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BossPhase {
+    /// Starts once health falls to this fraction of max health
+    pub health_fraction: f32,
+    #[serde(default)]
+    pub melee_attack: Option<MeleeAttack>,
+    #[serde(default)]
+    pub ranged_attack: Option<RangedAttack>,
+    #[serde(default)]
+    pub hop_distance: Option<f32>,
+    #[serde(default)]
+    pub rest_time: Option<f32>,
+    #[serde(default)]
+    pub summon: Option<SummonSpec>,
+}
+
+/// Minions called in when a phase starts and then every `interval` seconds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SummonSpec {
+    pub enemy_id: String,
+    /// Minions per summon
+    pub count: u32,
+    pub interval: f32,
+    /// A summon only tops the arena up to this many living minions of the type
+    pub max_alive: u32,
+}
+
+impl BossConfig {
+    /// Checks the values a JSON file can get wrong (errors name the boss)
+    pub fn validate(&self, id: &str) -> Result<(), String> {
+        if self.arena_width == 0 || self.arena_height == 0 {
+            return Err(format!("'{}': boss arena must not be empty", id));
+        }
+        if self.phases.first().is_none_or(|phase| phase.health_fraction != 1.0) {
+            return Err(format!("'{}': the first boss phase must start at health_fraction 1.0", id));
+        }
+        if self.phases.windows(2).any(|pair| pair[1].health_fraction >= pair[0].health_fraction)
+            || self.phases.iter().any(|phase| phase.health_fraction <= 0.0)
+        {
+            return Err(format!("'{}': boss phase health fractions must fall from 1.0 and stay above 0", id));
+        }
+        for phase in &self.phases {
+            if let Some(melee) = &phase.melee_attack {
+                melee.validate().map_err(|e| format!("'{}': boss phase {}", id, e))?;
+            }
+            if let Some(ranged) = &phase.ranged_attack {
+                ranged.validate().map_err(|e| format!("'{}': boss phase {}", id, e))?;
+            }
+            if phase.hop_distance.is_some_and(|distance| distance < 0.0) || phase.rest_time.is_some_and(|time| time < 0.0) {
+                return Err(format!("'{}': boss phase hop_distance and rest_time must not be negative", id));
+            }
+            if let Some(summon) = &phase.summon
+                && (summon.enemy_id.is_empty() || summon.enemy_id == id || summon.count == 0 || summon.max_alive == 0 || summon.interval <= 0.0)
+            {
+                return Err(format!(
+                    "'{}': boss summons need another enemy_id and a positive count, max_alive and interval",
+                    id
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Enemy types the boss summons (checked against the registry once everything is loaded)
+    pub fn summoned_ids(&self) -> impl Iterator<Item = &str> {
+        self.phases.iter().filter_map(|phase| phase.summon.as_ref()).map(|summon| summon.enemy_id.as_str())
+    }
+}
+
+/// Where a boss is in its fight (not saved: the phase follows from health)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BossState {
+    phase: Option<usize>,
+    summon_timer: f32,
+    summon_due: bool,
+}
+
+impl BossState {
+    /// Index of the current phase (None before the first update)
+    pub fn phase(&self) -> Option<usize> {
+        self.phase
+    }
+
+    /// Advances to the phase the health fraction has reached and ticks the summon timer
+    ///
+    /// Returns the index of a newly entered phase, whose settings the boss
+    /// should take on. Entering a phase with a summon makes one due at once.
+    pub fn update(&mut self, config: &BossConfig, health_fraction: f32, delta_time: f32) -> Option<usize> {
+        let reached = config.phases.iter().rposition(|phase| health_fraction <= phase.health_fraction).unwrap_or(0);
+        let entered = self.phase.is_none_or(|phase| reached > phase).then_some(reached);
+
+        if let Some(phase) = entered {
+            self.phase = Some(phase);
+            if let Some(summon) = &config.phases[phase].summon {
+                self.summon_due = true;
+                self.summon_timer = summon.interval;
+            }
+        } else if let Some(summon) = self.phase.and_then(|phase| config.phases[phase].summon.as_ref()) {
+            self.summon_timer -= delta_time;
+            if self.summon_timer <= 0.0 {
+                self.summon_due = true;
+                self.summon_timer += summon.interval;
+            }
+        }
+        entered
+    }
+
+    /// Takes the current phase's summon if one is due
+    pub fn take_summon<'c>(&mut self, config: &'c BossConfig) -> Option<&'c SummonSpec> {
+        if !std::mem::take(&mut self.summon_due) {
+            return None;
+        }
+        config.phases.get(self.phase?)?.summon.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase(health_fraction: f32, summon: Option<SummonSpec>) -> BossPhase {
+        BossPhase {
+            health_fraction,
+            melee_attack: None,
+            ranged_attack: None,
+            hop_distance: None,
+            rest_time: None,
+            summon,
+        }
+    }
+
+    fn config() -> BossConfig {
+        let summon = SummonSpec { enemy_id: "slime".to_string(), count: 2, interval: 5.0, max_alive: 3 };
+        BossConfig {
+            title: "KING".to_string(),
+            arena_width: 300,
+            arena_height: 200,
+            phases: vec![phase(1.0, None), phase(0.6, Some(summon)), phase(0.3, None)],
+        }
+    }
+
+    #[test]
+    fn test_phases_advance_with_health_and_never_go_back() {
+        let config = config();
+        let mut state = BossState::default();
+        assert_eq!(state.update(&config, 1.0, 0.0), Some(0));
+        assert_eq!(state.update(&config, 0.8, 0.0), None);
+        assert_eq!(state.update(&config, 0.6, 0.0), Some(1));
+
+        // Healing doesn't undo a phase; a big hit can skip straight to the last one
+        assert_eq!(state.update(&config, 0.9, 0.0), None);
+        assert_eq!(state.phase(), Some(1));
+        assert_eq!(state.update(&config, 0.1, 0.0), Some(2));
+
+        // A boss restored mid-fight starts in the phase its health is in
+        let mut restored = BossState::default();
+        assert_eq!(restored.update(&config, 0.5, 0.0), Some(1));
+    }
+
+    #[test]
+    fn test_summons_on_phase_entry_then_every_interval() {
+        let config = config();
+        let mut state = BossState::default();
+        state.update(&config, 1.0, 0.0);
+        assert!(state.take_summon(&config).is_none());
+
+        state.update(&config, 0.5, 0.0);
+        assert_eq!(state.take_summon(&config).map(|summon| summon.count), Some(2));
+        assert!(state.take_summon(&config).is_none());
+
+        state.update(&config, 0.5, 4.0);
+        assert!(state.take_summon(&config).is_none());
+        state.update(&config, 0.5, 1.0);
+        assert!(state.take_summon(&config).is_some());
+
+        // The last phase has no summon, so nothing more is called in
+        state.update(&config, 0.2, 0.0);
+        state.update(&config, 0.2, 10.0);
+        assert!(state.take_summon(&config).is_none());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_phases() {
+        assert!(config().validate("king").is_ok());
+
+        let mut unordered = config();
+        unordered.phases[2].health_fraction = 0.7;
+        assert!(unordered.validate("king").is_err());
+
+        let mut late_start = config();
+        late_start.phases[0].health_fraction = 0.9;
+        assert!(late_start.validate("king").is_err());
+
+        let mut summons_itself = config();
+        summons_itself.phases[1].summon.as_mut().unwrap().enemy_id = "king".to_string();
+        assert!(summons_itself.validate("king").is_err());
+    }
+}
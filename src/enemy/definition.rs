@@ -0,0 +1,403 @@
+// Enemy definitions - the data describing an enemy type
+//
+// Definitions live in assets/enemies/<id>.json and are loaded into the
+// EnemyRegistry at startup. Like ItemDefinition vs ItemStack, a definition is
+// the "class"; each Enemy in the world is an instance built from it.
+//
+// Example (assets/enemies/slime.json):
+//
+// {
+//   "id": "slime",
+//   "name": "Slime",
+//   "sprite_path": "assets/sprites/slime/Slime.png",
+//   "animation_config": "assets/config/slime_animations.json",
+//   "animations": { "idle": "slime_idle", "moving": "jump", "hurt": "slime_damage", "death": "slime_death" },
+//   "stats": { "max_health": 8, "knockback_resistance": 0.0 },
+//   "hitbox": { "offset_x": -7, "offset_y": -24, "width": 16, "height": 12 },
+//   "movement": { "jump_height": 20, "jump_duration": 0.5 },
+//   "ai": { "aggro_radius": 120.0 },
+//   "melee_attack": { "range": 40.0, "reach": 36, "windup": 0.5, "active_time": 0.15, "cooldown": 1.2, "damage": 1.0, "knockback": 160.0 },
+//   "xp": 5,
+//   "loot": { "entries": [{ "item_id": "slime_ball", "min": 1, "max": 1, "chance": 1.0 }] },
+//   "ambient_spawn": { "day_weight": 3, "night_weight": 2 }
+// }
+//
+// Bosses add "scale" (sprite size multiplier), "tint" (an [r, g, b] color
+// multiplied into the sprite) and a "boss" section with their phases (see
+// boss.rs).
+
+use crate::combat::Resistances;
+use crate::loot::LootTable;
+use crate::status::StatusEffectSpec;
+use serde::{Deserialize, Serialize};
+
+use super::ai::EnemyAiConfig;
+use super::boss::BossConfig;
+
+/// The blueprint for an enemy type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyDefinition {
+    /// Unique identifier (used for lookups and saves)
+    pub id: String,
+
+    /// Display name
+    pub name: String,
+
+    /// Sprite sheet texture
+    pub sprite_path: String,
+
+    /// AnimationConfig JSON describing the sprite sheet's frames
+    pub animation_config: String,
+
+    /// Which animation in the config plays for each behavior
+    pub animations: EnemyAnimationNames,
+
+    /// Sprite frame size in unscaled pixels
+    #[serde(default = "default_frame_size")]
+    pub width: u32,
+    #[serde(default = "default_frame_size")]
+    pub height: u32,
+
+    /// How many screen pixels each sprite pixel covers (hitbox included)
+    #[serde(default = "default_scale")]
+    pub scale: u32,
+
+    /// Color multiplied into the sprite sheet (None draws it as is)
+    #[serde(default)]
+    pub tint: Option<(u8, u8, u8)>,
+
+    pub stats: EnemyStats,
+    pub hitbox: EnemyHitbox,
+
+    #[serde(default)]
+    pub movement: EnemyMovement,
+
+    #[serde(default)]
+    pub ai: EnemyAiConfig,
+
+    /// Present for enemies that attack up close (touching the player is harmless)
+    #[serde(default)]
+    pub melee_attack: Option<MeleeAttack>,
+
+    /// Present for enemies that shoot at the player
+    #[serde(default)]
+    pub ranged_attack: Option<RangedAttack>,
+
+    /// Experience awarded to the player for the kill
+    #[serde(default)]
+    pub xp: u32,
+
+    /// What the enemy drops when it dies
+    #[serde(default)]
+    pub loot: LootTable,
+
+    /// How often ambient spawning picks this enemy (0 = never)
+    #[serde(default)]
+    pub ambient_spawn: AmbientSpawn,
+
+    /// Present for bosses: their title, arena and health-threshold phases
+    #[serde(default)]
+    pub boss: Option<BossConfig>,
+}
+
+fn default_frame_size() -> u32 {
+    32
+}
+
+fn default_scale() -> u32 {
+    2
+}
+
+/// Animation state names for each enemy behavior
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnemyAnimationNames {
+    pub idle: String,
+    pub moving: String,
+    pub hurt: String,
+    pub death: String,
+}
+
+impl EnemyAnimationNames {
+    pub fn all(&self) -> [&str; 4] {
+        [&self.idle, &self.moving, &self.hurt, &self.death]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EnemyStats {
+    pub max_health: i32,
+    /// 0.0 = full knockback, 1.0 = can't be knocked back
+    #[serde(default)]
+    pub knockback_resistance: f32,
+    /// Physical damage reduction (0.0 - 1.0)
+    #[serde(default)]
+    pub defense: f32,
+    /// Magical damage reduction (0.0 - 1.0)
+    #[serde(default)]
+    pub magic_resistance: f32,
+}
+
+impl EnemyStats {
+    pub fn resistances(&self) -> Resistances {
+        Resistances { physical: self.defense, magical: self.magic_resistance }
+    }
+}
+
+/// Collision box relative to the anchor (bottom-center), in unscaled sprite pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnemyHitbox {
+    pub offset_x: i32,
+    pub offset_y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl EnemyHitbox {
+    /// Anchor position that centers this hitbox on a point
+    ///
+    /// Used when spawning so a click (or spawn point) becomes the collision center.
+    pub fn anchor_for_center(&self, x: i32, y: i32, scale: u32) -> (i32, i32) {
+        let scale = scale as i32;
+        (
+            x - self.offset_x * scale - (self.width as i32 * scale) / 2,
+            y - self.offset_y * scale - (self.height as i32 * scale) / 2,
+        )
+    }
+}
+
+/// Hop movement
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnemyMovement {
+    /// Peak height of a hop in pixels (0 = slide along the ground)
+    pub jump_height: i32,
+    /// Duration of a hop in seconds
+    pub jump_duration: f32,
+}
+
+impl Default for EnemyMovement {
+    fn default() -> Self {
+        EnemyMovement { jump_height: 20, jump_duration: 0.5 }
+    }
+}
+
+/// A melee attack: a telegraphed windup, a short active hitbox, then a cooldown
+///
+/// The attack's direction is locked when the windup starts, so the player can
+/// dodge by stepping out of the flashing area before it hits.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MeleeAttack {
+    /// Starts the windup when the player's collision center is this close to the enemy's
+    pub range: f32,
+    /// Size of the square hitbox in front of the enemy (AttackEvent range, in pixels)
+    pub reach: i32,
+    /// Seconds of telegraph before the hitbox becomes active
+    pub windup: f32,
+    /// Seconds the hitbox can hit (at most once per attack)
+    pub active_time: f32,
+    /// Seconds after an attack before the next windup
+    pub cooldown: f32,
+    pub damage: f32,
+    /// Knockback speed (pixels per second) in the attack's direction
+    #[serde(default)]
+    pub knockback: f32,
+    /// Left on the player when the hit lands (e.g. a stun)
+    #[serde(default)]
+    pub status_effect: Option<StatusEffectSpec>,
+}
+
+/// A ranged attack: the enemy spits a shot at the player
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RangedAttack {
+    /// Only fires when the player is within this distance
+    pub range: f32,
+    /// Seconds between shots
+    pub cooldown: f32,
+    pub damage: f32,
+    /// Shot speed in pixels per second
+    pub speed: f32,
+    /// Left on the player when a shot hits (e.g. poison)
+    #[serde(default)]
+    pub status_effect: Option<StatusEffectSpec>,
+    /// Shots per volley, fanned out around the aim
+    #[serde(default = "default_shot_count")]
+    pub count: u32,
+    /// Degrees between neighbouring shots of a volley
+    #[serde(default)]
+    pub spread: f32,
+}
+
+fn default_shot_count() -> u32 {
+    1
+}
+
+impl MeleeAttack {
+    /// Checks the attack's sizes and timings (and its on-hit effect)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.range <= 0.0 || self.reach <= 0 || self.windup < 0.0 || self.active_time <= 0.0 || self.cooldown < 0.0 || self.knockback < 0.0 {
+            return Err(
+                "melee_attack range, reach and active_time must be positive (windup, cooldown and knockback non-negative)"
+                    .to_string(),
+            );
+        }
+        self.status_effect.map_or(Ok(()), |effect| effect.validate())
+    }
+}
+
+impl RangedAttack {
+    /// Checks the attack's range, timing and volley (and its on-hit effect)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.cooldown <= 0.0 || self.speed <= 0.0 || self.range <= 0.0 {
+            return Err("ranged_attack range, cooldown and speed must be positive".to_string());
+        }
+        if self.count == 0 || self.spread < 0.0 {
+            return Err("ranged_attack count must be positive (spread non-negative)".to_string());
+        }
+        self.status_effect.map_or(Ok(()), |effect| effect.validate())
+    }
+}
+
+/// Ambient spawn weights (relative to the other enemy types)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AmbientSpawn {
+    pub day_weight: u32,
+    /// Used at night and in underground zones
+    pub night_weight: u32,
+}
+
+impl EnemyDefinition {
+    /// Checks the values a JSON file can get wrong
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() {
+            return Err("enemy id must not be empty".to_string());
+        }
+        if self.stats.max_health <= 0 {
+            return Err(format!("'{}': max_health must be positive", self.id));
+        }
+        if self.hitbox.width == 0 || self.hitbox.height == 0 {
+            return Err(format!("'{}': hitbox must not be empty", self.id));
+        }
+        if !(0.0..=1.0).contains(&self.stats.knockback_resistance) {
+            return Err(format!("'{}': knockback_resistance must be between 0 and 1", self.id));
+        }
+        if !(0.0..=1.0).contains(&self.stats.defense) || !(0.0..=1.0).contains(&self.stats.magic_resistance) {
+            return Err(format!("'{}': defense and magic_resistance must be between 0 and 1", self.id));
+        }
+        if self.movement.jump_duration <= 0.0 {
+            return Err(format!("'{}': jump_duration must be positive", self.id));
+        }
+        if self.ai.leash_distance < self.ai.aggro_radius {
+            return Err(format!("'{}': leash_distance must be at least aggro_radius", self.id));
+        }
+        if self.scale == 0 {
+            return Err(format!("'{}': scale must be positive", self.id));
+        }
+        if let Some(melee) = &self.melee_attack {
+            melee.validate().map_err(|e| format!("'{}': {}", self.id, e))?;
+        }
+        if let Some(ranged) = &self.ranged_attack {
+            ranged.validate().map_err(|e| format!("'{}': {}", self.id, e))?;
+        }
+        if let Some(boss) = &self.boss {
+            boss.validate(&self.id)?;
+        }
+        if let Some(entry) = self.loot.entries.iter().find(|entry| !(0.0..=1.0).contains(&entry.chance)) {
+            return Err(format!("'{}': loot chance for '{}' must be between 0 and 1", self.id, entry.item_id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> EnemyDefinition {
+        let definition: EnemyDefinition = serde_json::from_str(json).unwrap();
+        definition.validate().unwrap();
+        definition
+    }
+
+    #[test]
+    fn test_bundled_definitions_are_valid() {
+        let slime = parse(include_str!("../../assets/enemies/slime.json"));
+        assert_eq!(slime.stats.max_health, 8);
+        assert_eq!(slime.animations.moving, "jump");
+        assert!(slime.melee_attack.is_some());
+        assert!(slime.ranged_attack.is_none());
+
+        let spitter = parse(include_str!("../../assets/enemies/spitter.json"));
+        assert!(spitter.ranged_attack.is_some_and(|ranged| ranged.status_effect.is_some()));
+        assert!(spitter.ai.preferred_distance > 0.0);
+
+        let rock_slime = parse(include_str!("../../assets/enemies/rock_slime.json"));
+        assert!(rock_slime.loot.entries.iter().any(|entry| entry.item_id == "stone"));
+        assert!(rock_slime.stats.resistances().physical > slime.stats.resistances().physical);
+
+        let slime_king = parse(include_str!("../../assets/enemies/slime_king.json"));
+        assert!(slime_king.boss.as_ref().is_some_and(|boss| boss.phases.len() > 1));
+        assert!(slime_king.scale > slime.scale);
+        assert_eq!(slime_king.ambient_spawn, AmbientSpawn::default());
+        assert!(slime_king.loot.entries.iter().any(|entry| entry.item_id == "sword" && entry.chance == 1.0));
+    }
+
+    #[test]
+    fn test_optional_sections_use_defaults() {
+        let definition = parse(r#"{
+            "id": "blob", "name": "Blob",
+            "sprite_path": "blob.png", "animation_config": "blob.json",
+            "animations": { "idle": "a", "moving": "b", "hurt": "c", "death": "d" },
+            "stats": { "max_health": 3 },
+            "hitbox": { "offset_x": -4, "offset_y": -8, "width": 8, "height": 8 },
+            "ai": { "aggro_radius": 50.0 }
+        }"#);
+
+        assert_eq!(definition.width, 32);
+        assert_eq!(definition.movement, EnemyMovement::default());
+        assert_eq!(definition.ai.aggro_radius, 50.0);
+        assert_eq!(definition.ai.hop_distance, EnemyAiConfig::standard().hop_distance);
+        assert!(definition.melee_attack.is_none());
+        assert!(definition.loot.entries.is_empty());
+        assert_eq!(definition.ambient_spawn, AmbientSpawn::default());
+        assert_eq!((definition.scale, definition.tint), (2, None));
+        assert!(definition.boss.is_none());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_values() {
+        let mut definition = parse(include_str!("../../assets/enemies/slime.json"));
+        definition.stats.max_health = 0;
+        assert!(definition.validate().is_err());
+
+        let mut definition = parse(include_str!("../../assets/enemies/slime.json"));
+        definition.stats.magic_resistance = 1.5;
+        assert!(definition.validate().is_err());
+
+        let mut definition = parse(include_str!("../../assets/enemies/slime.json"));
+        definition.ai.leash_distance = definition.ai.aggro_radius - 1.0;
+        assert!(definition.validate().is_err());
+
+        let mut definition = parse(include_str!("../../assets/enemies/slime.json"));
+        definition.melee_attack.as_mut().unwrap().reach = 0;
+        assert!(definition.validate().is_err());
+
+        let mut definition = parse(include_str!("../../assets/enemies/rock_slime.json"));
+        definition.melee_attack.as_mut().unwrap().status_effect.as_mut().unwrap().duration = 0.0;
+        assert!(definition.validate().is_err());
+    }
+
+    #[test]
+    fn test_anchor_for_center() {
+        let hitbox = EnemyHitbox { offset_x: -7, offset_y: -24, width: 16, height: 12 };
+        let (anchor_x, anchor_y) = hitbox.anchor_for_center(100, 100, 2);
+        // Hitbox spans anchor + offset*2 .. + size*2, so its center is back at the click
+        assert_eq!(anchor_x + hitbox.offset_x * 2 + 16, 100);
+        assert_eq!(anchor_y + hitbox.offset_y * 2 + 12, 100);
+
+        // Bigger enemies scale the hitbox up around the same center
+        let (anchor_x, anchor_y) = hitbox.anchor_for_center(100, 100, 4);
+        assert_eq!(anchor_x + hitbox.offset_x * 4 + 32, 100);
+        assert_eq!(anchor_y + hitbox.offset_y * 4 + 24, 100);
+    }
+}
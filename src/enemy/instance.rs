@@ -0,0 +1,1305 @@
+// Enemy instances - a live enemy in the world
+//
+// Every enemy type shares this struct. Hitbox, stats, hop movement, AI tuning,
+// animation names and the melee/ranged attacks come from the EnemyDefinition;
+// only per-instance state (position, health, home) is saved.
+//
+// Enemies hurt the player only through attacks, never by touch. A melee
+// attack winds up first (the target area flashes on the ground), then its
+// hitbox is active for a moment; Game::handle_collisions checks it against
+// the player with `try_melee_hit`.
+//
+// Status effects tick in `update`: poison and burn chip health away without
+// the hurt animation, slow stretches hops out, and stun freezes the AI.
+//
+// Bosses also move through their definition's phases in `update`, taking on
+// each phase's attacks and queueing its minion summons for the game to spawn.
+
+use crate::animation::{AnimationController, Direction};
+use crate::collision::{Collidable, CollisionLayer};
+use crate::combat::{AttackEvent, DamageEvent, Resistances, calculate_damage_with_resistances};
+use crate::pathfinding::{self, GridPos, Pathfinder};
+use crate::physics::Knockback;
+use crate::projectile::Projectile;
+use crate::render::DepthSortable;
+use crate::save::{Saveable, SaveData, SaveError};
+use crate::stats::DamageResult;
+use crate::status::StatusEffects;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{BlendMode, Canvas};
+use sdl2::video::Window;
+use serde::{Serialize, Deserialize};
+
+use super::ai::{self, ChaseIntent, EnemyAiConfig, EnemyAiMode};
+use super::boss::{BossConfig, BossState, SummonSpec};
+use super::definition::{EnemyAnimationNames, EnemyDefinition, MeleeAttack, RangedAttack};
+
+#[derive(Debug, Clone, PartialEq)]
+enum EnemyBehavior {
+    Idle,
+    Jumping,
+    Attacking,     // Melee windup, then the active hit window
+    TakingDamage,  // Playing damage animation
+    Dying,         // Playing death animation
+}
+
+pub struct Enemy<'a> {
+    /// Definition this enemy was built from (e.g. "slime")
+    pub enemy_type: String,
+    /// Spawner that created this enemy (None for ambient and debug spawns)
+    pub spawner_id: Option<String>,
+    /// Anchor (bottom-center) position; sub-pixel, rounded only when drawing
+    pub x: f32,
+    pub y: f32,
+    pub base_y: f32, // Original Y position for jumping reference
+    pub width: u32,
+    pub height: u32,
+    /// Screen pixels per sprite pixel (sprite and hitbox)
+    pub scale: u32,
+    animation_controller: AnimationController<'a>,
+    animations: EnemyAnimationNames,
+    behavior: EnemyBehavior,
+    previous_behavior: EnemyBehavior,  // Track behavior before damage/death
+    behavior_timer: f32, // Seconds spent in the current behavior (paused while taking damage)
+    jump_height: i32,
+    jump_duration: f32, // Duration of jump animation in seconds
+
+    // AI (chase/flee/return home)
+    pub ai: EnemyAiConfig,
+    ai_mode: EnemyAiMode,
+    pub home_x: i32,
+    pub home_y: i32,
+    hop_start: (f32, f32),
+    hop_offset: (f32, f32),
+    knockback: Knockback,
+    knockback_resistance: f32,
+    resistances: Resistances,
+    pub status_effects: StatusEffects,
+    nav_path: Vec<GridPos>, // Cells the current hop is following (debug overlay)
+
+    // Health system
+    pub health: i32,
+    pub max_health: i32,
+    pub is_alive: bool,
+    pub has_dropped_loot: bool,
+
+    // Attacks
+    melee_attack: Option<MeleeAttack>,
+    melee_cooldown: f32,
+    attack_direction: Direction, // Locked when the windup starts
+    attack_landed: bool,         // The current melee attack already hit
+    ranged_attack: Option<RangedAttack>,
+    ranged_cooldown: f32,
+    pending_shot: Option<(f32, f32)>, // Target of a volley waiting to be spawned
+
+    // Boss phases (None for ordinary enemies)
+    boss: Option<BossConfig>,
+    boss_state: BossState,
+
+    // Collision hitbox configuration
+    pub hitbox_offset_x: i32,
+    pub hitbox_offset_y: i32,
+    pub hitbox_width: u32,
+    pub hitbox_height: u32,
+}
+
+impl<'a> Enemy<'a> {
+    /// Creates a full-health enemy of the definition's type, anchored (bottom-center) at (x, y)
+    pub fn new(definition: &EnemyDefinition, x: i32, y: i32, animation_controller: AnimationController<'a>) -> Self {
+        let mut enemy = Enemy::blank(&definition.id, x, y, animation_controller);
+        enemy.apply_definition(definition);
+        enemy.set_max_health(definition.stats.max_health);
+        enemy
+    }
+
+    /// Instance state only; configuration is filled in by `apply_definition`
+    fn blank(enemy_type: &str, x: i32, y: i32, animation_controller: AnimationController<'a>) -> Self {
+        Enemy {
+            enemy_type: enemy_type.to_string(),
+            spawner_id: None,
+            x: x as f32,
+            y: y as f32,
+            base_y: y as f32,
+            width: 32,
+            height: 32,
+            scale: 2,
+            animation_controller,
+            animations: EnemyAnimationNames {
+                idle: String::new(),
+                moving: String::new(),
+                hurt: String::new(),
+                death: String::new(),
+            },
+            behavior: EnemyBehavior::Idle,
+            previous_behavior: EnemyBehavior::Idle,  // Start as idle
+            behavior_timer: 0.0,
+            jump_height: 0,
+            jump_duration: 0.5,
+            ai: EnemyAiConfig::standard(),
+            ai_mode: EnemyAiMode::Idle,
+            home_x: x,
+            home_y: y,
+            hop_start: (x as f32, y as f32),
+            hop_offset: (0.0, 0.0),
+            knockback: Knockback::default(),
+            knockback_resistance: 0.0,
+            resistances: Resistances::default(),
+            status_effects: StatusEffects::default(),
+            nav_path: Vec::new(),
+            health: 1,
+            max_health: 1,
+            is_alive: true,
+            has_dropped_loot: false,
+            melee_attack: None,
+            melee_cooldown: 0.0,
+            attack_direction: Direction::South,
+            attack_landed: false,
+            ranged_attack: None,
+            ranged_cooldown: 0.0,
+            pending_shot: None,
+            boss: None,
+            boss_state: BossState::default(),
+            hitbox_offset_x: 0,
+            hitbox_offset_y: 0,
+            hitbox_width: 1,
+            hitbox_height: 1,
+        }
+    }
+
+    /// Copies the type's configuration, leaving position and health alone
+    ///
+    /// Also used after loading a save, since saves only hold instance state.
+    pub fn apply_definition(&mut self, definition: &EnemyDefinition) {
+        self.enemy_type = definition.id.clone();
+        self.width = definition.width;
+        self.height = definition.height;
+        self.scale = definition.scale;
+        self.animations = definition.animations.clone();
+        self.jump_height = definition.movement.jump_height;
+        self.jump_duration = definition.movement.jump_duration;
+        self.ai = definition.ai;
+        self.melee_attack = definition.melee_attack;
+        self.ranged_attack = definition.ranged_attack;
+        self.knockback_resistance = definition.stats.knockback_resistance;
+        self.resistances = definition.stats.resistances();
+        self.boss = definition.boss.clone();
+        self.boss_state = BossState::default();
+
+        // Calculated from anchor point (bottom-center), like player
+        self.hitbox_offset_x = definition.hitbox.offset_x;
+        self.hitbox_offset_y = definition.hitbox.offset_y;
+        self.hitbox_width = definition.hitbox.width;
+        self.hitbox_height = definition.hitbox.height;
+    }
+
+    pub fn set_animation_controller(&mut self, controller: AnimationController<'a>) {
+        self.animation_controller = controller;
+    }
+
+    /// Returns true if the enemy is currently invulnerable
+    ///
+    /// Enemies are invulnerable while playing their damage or death animations.
+    /// This prevents stunlock and ensures visual feedback completes.
+    pub fn is_invulnerable(&self) -> bool {
+        matches!(self.behavior, EnemyBehavior::TakingDamage | EnemyBehavior::Dying)
+    }
+
+    pub fn is_dying(&self) -> bool {
+        self.behavior == EnemyBehavior::Dying
+    }
+
+    /// Sets both current and max health (spawning with a configured HP)
+    pub fn set_max_health(&mut self, health: i32) {
+        self.health = health;
+        self.max_health = health;
+    }
+
+    pub fn health_fraction(&self) -> f32 {
+        self.health as f32 / self.max_health.max(1) as f32
+    }
+
+    pub fn ai_mode(&self) -> EnemyAiMode {
+        self.ai_mode
+    }
+
+    /// Path the enemy is currently following (navigation cells)
+    pub fn nav_path(&self) -> &[GridPos] {
+        &self.nav_path
+    }
+
+    /// Center of the enemy's collision box while on the ground
+    ///
+    /// This is the point that navigates: it's what must fit through gaps, and
+    /// what is compared against the player's collision center.
+    pub fn nav_position(&self) -> (f32, f32) {
+        let (offset_x, offset_y) = self.nav_offset();
+        (self.x + offset_x, self.base_y + offset_y)
+    }
+
+    /// Offset from the anchor (x, base_y) to the ground collision center
+    fn nav_offset(&self) -> (f32, f32) {
+        let scale = self.scale as i32;
+        (
+            (self.hitbox_offset_x * scale + self.hitbox_width as i32 * scale / 2) as f32,
+            (self.hitbox_offset_y * scale + self.hitbox_height as i32 * scale / 2) as f32,
+        )
+    }
+
+    /// The boss's title, arena and phases (None for ordinary enemies)
+    pub fn boss(&self) -> Option<&BossConfig> {
+        self.boss.as_ref()
+    }
+
+    pub fn is_boss(&self) -> bool {
+        self.boss.is_some()
+    }
+
+    /// Index of the boss's current phase (None for ordinary enemies and before the fight starts)
+    #[allow(dead_code)] // Reserved for phase-specific visuals
+    pub fn boss_phase(&self) -> Option<usize> {
+        self.boss_state.phase()
+    }
+
+    /// Takes a minion summon queued during `update` (bosses only)
+    pub fn take_pending_summon(&mut self) -> Option<SummonSpec> {
+        let boss = self.boss.as_ref()?;
+        self.boss_state.take_summon(boss).cloned()
+    }
+
+    /// Moves a boss into the phase its health has reached and ticks its summons
+    fn update_boss_phase(&mut self, delta_time: f32) {
+        let health_fraction = self.health_fraction();
+        let Some(boss) = &self.boss else {
+            return;
+        };
+        let Some(index) = self.boss_state.update(boss, health_fraction, delta_time) else {
+            return;
+        };
+
+        let phase = &boss.phases[index];
+        self.melee_attack = phase.melee_attack;
+        self.ranged_attack = phase.ranged_attack;
+        self.ai.hop_distance = phase.hop_distance.unwrap_or(self.ai.hop_distance);
+        self.ai.rest_time = phase.rest_time.unwrap_or(self.ai.rest_time);
+    }
+
+    /// Advances animation, AI, attacks and hop movement
+    ///
+    /// # Arguments
+    /// * `delta_time` - Time elapsed since last frame (in seconds)
+    /// * `player_position` - Player collision center, or None when there is no player to react to
+    /// * `navigation` - Active zone's pathfinder, used to hop around obstacles
+    pub fn update(&mut self, delta_time: f32, player_position: Option<(i32, i32)>, navigation: &mut Pathfinder) {
+        // IMPORTANT: Update animation controller FIRST
+        // This ensures animations are reset before we check is_animation_finished()
+        // Otherwise, checking a "once" animation that was previously finished will
+        // return true even though we just set it to play again
+        self.animation_controller.update();
+
+        let resistances = &self.resistances;
+        let ticks = self.status_effects.update_whole(delta_time, |damage| calculate_damage_with_resistances(damage, resistances));
+        for (_, amount) in ticks {
+            self.take_status_damage(amount);
+        }
+
+        // Slide from the last hit (a push, so an interrupted hop keeps the offset)
+        if self.knockback.is_active() {
+            let (knockback_x, knockback_y) = self.knockback.step(delta_time);
+            self.apply_push(knockback_x, knockback_y);
+        }
+
+        // Bosses change phase (and call minions) only while there's a fight
+        if player_position.is_some() && !self.is_dying() {
+            self.update_boss_phase(delta_time);
+        }
+
+        // Stunned: frozen in place (even mid-hop) with every timer paused
+        let is_acting = matches!(self.behavior, EnemyBehavior::Idle | EnemyBehavior::Jumping | EnemyBehavior::Attacking);
+        if is_acting && self.status_effects.is_stunned() {
+            return;
+        }
+
+        // Slowed enemies hop the same distance, just more slowly
+        match self.behavior {
+            EnemyBehavior::Jumping => self.behavior_timer += delta_time * self.status_effects.speed_multiplier(),
+            _ if is_acting => self.behavior_timer += delta_time,
+            _ => {}
+        }
+        self.melee_cooldown = (self.melee_cooldown - delta_time).max(0.0);
+        let elapsed_time = self.behavior_timer;
+
+        // AI works in anchor space: the player becomes "our anchor if we stood on them"
+        let (offset_x, offset_y) = self.nav_offset();
+        let position = (self.x, self.base_y);
+        let home = (self.home_x as f32, self.home_y as f32);
+        let player = player_position.map(|(x, y)| (x as f32 - offset_x, y as f32 - offset_y));
+
+        self.update_ranged_attack(delta_time, position, player_position, player);
+
+        // Game Dev Pattern: Simple AI State Machine
+        // The enemy rests on the ground, then hops - toward the player, away from
+        // them or back home depending on its AI mode
+        match self.behavior {
+            EnemyBehavior::Idle => {
+                // Re-evaluate every frame so the enemy reacts as soon as it lands
+                self.ai_mode = ai::next_ai_mode(self.ai_mode, &self.ai, position, home, player, self.health_fraction());
+                let rest_time = if self.ai_mode == EnemyAiMode::Idle {
+                    self.ai.idle_time
+                } else {
+                    self.ai.rest_time
+                };
+
+                if self.melee_in_range(player_position) {
+                    // Lock the direction now so the telegraph shows where the hit will land
+                    self.previous_behavior = self.behavior.clone();
+                    self.behavior = EnemyBehavior::Attacking;
+                    self.behavior_timer = 0.0;
+                    self.attack_direction = self.aim_direction(player_position);
+                    self.attack_landed = false;
+                } else if elapsed_time >= rest_time {
+                    self.previous_behavior = self.behavior.clone();
+                    self.behavior = EnemyBehavior::Jumping;
+                    self.behavior_timer = 0.0;
+                    self.hop_start = (self.x, self.base_y);
+                    self.hop_offset = self.plan_hop(position, home, player, navigation);
+                    self.animation_controller.set_state(self.animations.moving.clone());
+                } else {
+                    // Make sure we're in idle animation
+                    if *self.animation_controller.current_state() != self.animations.idle {
+                        self.animation_controller.set_state(self.animations.idle.clone());
+                    }
+                }
+                // Stay at base position when idle
+                self.y = self.base_y;
+            }
+            EnemyBehavior::Jumping => {
+                // Jump for jump_duration, then return to idle
+                if elapsed_time >= self.jump_duration {
+                    self.previous_behavior = self.behavior.clone();
+                    self.behavior = EnemyBehavior::Idle;
+                    self.behavior_timer = 0.0;
+                    self.move_along_hop(1.0);
+                    self.animation_controller.set_state(self.animations.idle.clone());
+                    self.y = self.base_y; // Return to ground
+                } else {
+                    self.move_along_hop(elapsed_time / self.jump_duration);
+
+                    // Calculate jump position using sine wave
+                    // Game Dev Math: sin() gives smooth bounce motion (0 -> 1 -> 0)
+                    let jump_progress = (elapsed_time * std::f32::consts::PI / self.jump_duration).sin();
+                    let jump_offset = jump_progress * self.jump_height as f32;
+                    self.y = self.base_y - jump_offset;
+                }
+            }
+            EnemyBehavior::Attacking => {
+                // Hold still through the windup and hit window, then start the cooldown
+                if let Some(melee) = self.melee_attack
+                    && elapsed_time < melee.windup + melee.active_time
+                {
+                    self.y = self.base_y;
+                } else {
+                    self.previous_behavior = self.behavior.clone();
+                    self.behavior = EnemyBehavior::Idle;
+                    self.behavior_timer = 0.0;
+                    self.melee_cooldown = self.melee_attack.map_or(0.0, |melee| melee.cooldown);
+                }
+            }
+            EnemyBehavior::TakingDamage => {
+                // Play damage animation, then return to previous behavior
+                if self.animation_controller.is_animation_finished() {
+                    // Return to whatever we were doing before (idle or jumping)
+                    // The behavior timer was paused, so an interrupted hop resumes mid-air
+                    self.behavior = self.previous_behavior.clone();
+
+                    // Set appropriate animation based on previous behavior
+                    let animation = match self.previous_behavior {
+                        EnemyBehavior::Jumping => self.animations.moving.clone(),
+                        _ => self.animations.idle.clone(),
+                    };
+                    self.animation_controller.set_state(animation);
+                }
+            }
+            EnemyBehavior::Dying => {
+                // Play death animation, then mark as dead when finished
+                if self.animation_controller.is_animation_finished() {
+                    self.is_alive = false;
+                }
+            }
+        }
+
+        // Animation controller already updated at the beginning of this function
+    }
+
+    /// Chooses the next hop, following a path around obstacles when one is needed
+    ///
+    /// Chase and ReturnHome hop toward the next cell on the A* path (or straight at
+    /// the target when it's in a neighbouring cell or unreachable). Flee veers
+    /// away from blocked cells. Ranged enemies stop approaching at their
+    /// preferred distance and back off when the player gets too close.
+    fn plan_hop(
+        &mut self,
+        position: (f32, f32),
+        home: (f32, f32),
+        player: Option<(f32, f32)>,
+        navigation: &mut Pathfinder,
+    ) -> (f32, f32) {
+        let (offset_x, offset_y) = self.nav_offset();
+        self.nav_path.clear();
+
+        let target = match (self.ai_mode, player) {
+            (EnemyAiMode::Chase, Some(player)) => match ai::chase_intent(&self.ai, ai::distance(position, player)) {
+                ChaseIntent::Approach => player,
+                ChaseIntent::Hold => return (0.0, 0.0),
+                ChaseIntent::BackOff => {
+                    let away = ai::away_from(position, player, self.ai.hop_distance);
+                    return ai::flee_offset(away, self.nav_position(), navigation.grid());
+                }
+            },
+            (EnemyAiMode::ReturnHome, _) => home,
+            (EnemyAiMode::Flee, Some(_)) => {
+                let away = ai::hop_offset(self.ai_mode, &self.ai, position, home, player);
+                return ai::flee_offset(away, self.nav_position(), navigation.grid());
+            }
+            _ => return (0.0, 0.0),
+        };
+
+        let start = pathfinding::world_to_cell(position.0 + offset_x, position.1 + offset_y);
+        let goal = pathfinding::world_to_cell(target.0 + offset_x, target.1 + offset_y);
+        let waypoint = match navigation.find_path(start, goal) {
+            Some(path) if path.len() > 1 => {
+                let (cell_x, cell_y) = pathfinding::cell_center(path[0]);
+                self.nav_path = path;
+                (cell_x - offset_x, cell_y - offset_y)
+            }
+            Some(path) => {
+                self.nav_path = path;
+                target
+            }
+            None => target,
+        };
+
+        ai::hop_toward(position, waypoint, self.ai.hop_distance)
+    }
+
+    /// Counts down the ranged cooldown and queues a shot when the player is in range
+    ///
+    /// Only chasing enemies that aren't hurt or dying shoot. `player` is in
+    /// anchor space (for the range check), `player_center` is the aim point.
+    fn update_ranged_attack(
+        &mut self,
+        delta_time: f32,
+        position: (f32, f32),
+        player_center: Option<(i32, i32)>,
+        player: Option<(f32, f32)>,
+    ) {
+        let Some(ranged) = self.ranged_attack else {
+            return;
+        };
+        self.ranged_cooldown = (self.ranged_cooldown - delta_time).max(0.0);
+
+        let can_shoot = self.ai_mode == EnemyAiMode::Chase
+            && matches!(self.behavior, EnemyBehavior::Idle | EnemyBehavior::Jumping)
+            && self.ranged_cooldown <= 0.0;
+        if let (Some(player), Some((target_x, target_y))) = (player, player_center)
+            && can_shoot
+            && ai::distance(position, player) <= ranged.range
+        {
+            self.pending_shot = Some((target_x as f32, target_y as f32));
+            self.ranged_cooldown = ranged.cooldown;
+        }
+    }
+
+    /// True when a chasing, idle enemy is ready to start a melee windup
+    fn melee_in_range(&self, player_center: Option<(i32, i32)>) -> bool {
+        let (Some(melee), Some((player_x, player_y))) = (self.melee_attack, player_center) else {
+            return false;
+        };
+        let center = self.get_bounds().center();
+        self.ai_mode == EnemyAiMode::Chase
+            && self.melee_cooldown <= 0.0
+            && ai::distance((center.x() as f32, center.y() as f32), (player_x as f32, player_y as f32)) <= melee.range
+    }
+
+    /// The 8-way direction that best points from the enemy at the player
+    fn aim_direction(&self, player_center: Option<(i32, i32)>) -> Direction {
+        let Some((player_x, player_y)) = player_center else {
+            return self.attack_direction;
+        };
+        let center = self.get_bounds().center();
+        let (dx, dy) = (player_x - center.x(), player_y - center.y());
+
+        // Only count an axis when it's at least tan(22.5°) of the other, so
+        // a player almost straight ahead isn't treated as diagonal
+        let axis = |value: i32, other: i32| if value.abs() * 5 >= other.abs() * 2 { value } else { 0 };
+        Direction::from_velocity(axis(dx, dy), axis(dy, dx))
+    }
+
+    /// The current melee attack, from windup to the end of its hit window
+    ///
+    /// The attack originates from the enemy's collision center.
+    pub fn melee_attack_event(&self) -> Option<AttackEvent> {
+        let melee = self.melee_attack.filter(|_| self.behavior == EnemyBehavior::Attacking)?;
+        let center = self.get_bounds().center();
+        let attack = AttackEvent::new(melee.damage, (center.x(), center.y()), self.attack_direction, melee.reach)
+            .with_knockback(melee.knockback);
+        Some(match melee.status_effect {
+            Some(effect) => attack.with_status_effect(effect.to_effect()),
+            None => attack,
+        })
+    }
+
+    /// True while the melee hitbox can hit (after the windup, before it lands)
+    pub fn is_melee_active(&self) -> bool {
+        self.melee_attack.is_some_and(|melee| {
+            self.behavior == EnemyBehavior::Attacking
+                && !self.attack_landed
+                && self.behavior_timer >= melee.windup
+                && self.behavior_timer < melee.windup + melee.active_time
+        })
+    }
+
+    /// Lands the active melee attack on a target, at most once per attack
+    ///
+    /// Returns the attack if its hitbox overlaps `target` during the hit window.
+    pub fn try_melee_hit(&mut self, target: &Rect) -> Option<AttackEvent> {
+        if !self.is_melee_active() {
+            return None;
+        }
+        let attack = self.melee_attack_event()?;
+        if !attack.hits(*target) {
+            return None;
+        }
+        self.attack_landed = true;
+        Some(attack)
+    }
+
+    /// Takes the volley queued during `update`, fired from the enemy's collision center
+    ///
+    /// A volley of several shots fans out evenly around the aim, `spread`
+    /// degrees apart. Empty when no shot is queued.
+    pub fn take_pending_shots(&mut self) -> Vec<Projectile<'a>> {
+        let (Some(target), Some(ranged)) = (self.pending_shot.take(), self.ranged_attack) else {
+            return Vec::new();
+        };
+        let center = self.get_bounds().center();
+        let origin = (center.x() as f32, center.y() as f32);
+        let (aim_x, aim_y) = (target.0 - origin.0, target.1 - origin.1);
+
+        (0..ranged.count)
+            .map(|i| {
+                let angle = (i as f32 - (ranged.count - 1) as f32 / 2.0) * ranged.spread.to_radians();
+                let (sin, cos) = angle.sin_cos();
+                let target = (origin.0 + aim_x * cos - aim_y * sin, origin.1 + aim_x * sin + aim_y * cos);
+                let shot = Projectile::aimed(origin, target, ranged.speed, ranged.damage, CollisionLayer::Enemy);
+                match ranged.status_effect {
+                    Some(effect) => shot.with_status_effect(effect.to_effect()),
+                    None => shot,
+                }
+            })
+            .collect()
+    }
+
+    /// Places the enemy `progress` (0.0-1.0) of the way along its current hop
+    ///
+    /// Pushes applied mid-hop shift the hop start, so collision response is kept.
+    fn move_along_hop(&mut self, progress: f32) {
+        let progress = progress.clamp(0.0, 1.0);
+        let x = self.hop_start.0 + self.hop_offset.0 * progress;
+        let base_y = self.hop_start.1 + self.hop_offset.1 * progress;
+        self.y += base_y - self.base_y;
+        self.x = x;
+        self.base_y = base_y;
+    }
+
+    pub fn render(&self, canvas: &mut Canvas<Window>) -> Result<(), String> {
+        let scaled_width = self.width * self.scale;
+        let scaled_height = self.height * self.scale;
+
+        // Calculate render position from anchor (bottom-center)
+        // self.y is base_y or modified by jump, both anchor-based
+        let (anchor_x, anchor_y) = self.position();
+        let render_x = anchor_x - (scaled_width / 2) as i32;
+        let render_y = anchor_y - scaled_height as i32;
+
+        let dest_rect = Rect::new(render_x, render_y, scaled_width, scaled_height);
+
+        self.render_attack_telegraph(canvas)?;
+
+        if let Some(sprite_sheet) = self.animation_controller.get_current_sprite_sheet() {
+            sprite_sheet.render_flipped(canvas, dest_rect, false)
+        } else {
+            // Fallback red square if no sprite sheet
+            canvas.set_draw_color(sdl2::pixels::Color::RGB(255, 0, 0));
+            canvas.fill_rect(dest_rect).map_err(|e| e.to_string())
+        }
+    }
+
+    /// Draws the melee target area: a red flash that brightens and speeds up
+    /// through the windup, then solid while the hitbox is active
+    fn render_attack_telegraph(&self, canvas: &mut Canvas<Window>) -> Result<(), String> {
+        let (Some(melee), Some(attack)) = (self.melee_attack, self.melee_attack_event()) else {
+            return Ok(());
+        };
+        let hitbox = attack.get_hitbox();
+
+        let alpha = if self.behavior_timer < melee.windup {
+            let progress = self.behavior_timer / melee.windup;
+            let flash_rate = 6.0 + progress * 18.0;
+            let flash_on = (self.behavior_timer * flash_rate) as i32 % 2 == 0;
+            if flash_on { 60 + (progress * 80.0) as u8 } else { 30 }
+        } else {
+            170
+        };
+
+        canvas.set_blend_mode(BlendMode::Blend);
+        canvas.set_draw_color(Color::RGBA(230, 40, 40, alpha));
+        canvas.fill_rect(hitbox)?;
+        canvas.set_draw_color(Color::RGBA(255, 90, 90, alpha.saturating_add(60)));
+        canvas.draw_rect(hitbox)?;
+        canvas.set_blend_mode(BlendMode::None);
+        Ok(())
+    }
+
+    /// Applies a push force to the enemy (used for collision response).
+    ///
+    /// This is called when the enemy collides with something and needs to be
+    /// pushed away to prevent overlap.
+    ///
+    /// Note: We update both x and base_y so the enemy stays pushed even after jumping
+    pub fn apply_push(&mut self, push_x: f32, push_y: f32) {
+        self.x += push_x;
+        self.y += push_y;
+        self.base_y += push_y; // Keep base_y in sync so jump behavior works correctly
+        self.hop_start.0 += push_x; // Keep an in-progress hop from undoing the push
+        self.hop_start.1 += push_y;
+    }
+
+    /// Deals damage to the enemy.
+    ///
+    /// Returns true if the enemy died from this damage.
+    ///
+    /// Enemies are invulnerable while playing damage or death animations,
+    /// preventing stunlock and ensuring visual feedback completes.
+    pub fn take_damage(&mut self, damage: i32) -> bool {
+        // Check invulnerability (state-based: invulnerable during damage/death animations)
+        if self.is_invulnerable() {
+            return false;
+        }
+
+        self.health -= damage;
+
+        if self.health <= 0 {
+            self.start_dying();
+            return true;
+        }
+
+        // Take damage but still alive - play damage animation
+        // Enemy becomes invulnerable until the hurt animation finishes
+        // Getting hit cancels a melee attack (the enemy goes back to idle and cools down)
+        self.previous_behavior = self.behavior.clone();
+        if self.behavior == EnemyBehavior::Attacking {
+            self.previous_behavior = EnemyBehavior::Idle;
+            self.behavior_timer = 0.0;
+            self.melee_cooldown = self.melee_attack.map_or(0.0, |melee| melee.cooldown);
+        }
+        self.behavior = EnemyBehavior::TakingDamage;
+        self.animation_controller.set_state(self.animations.hurt.clone());
+        false
+    }
+
+    /// Deals a damage event (after the enemy's resistances; rounded, at least 1)
+    ///
+    /// Same rules as `take_damage`; the result reports the health lost, death
+    /// and crits. A hit that lands also starts the event's knockback, reduced by
+    /// the enemy's resistance, and leaves its status effect.
+    pub fn take_damage_event(&mut self, damage: &DamageEvent) -> DamageResult {
+        if self.is_invulnerable() {
+            return DamageResult::no_damage();
+        }
+        if let Some(impulse) = damage.knockback {
+            self.knockback.apply(impulse, self.knockback_resistance);
+        }
+        if let Some(effect) = damage.status_effect {
+            self.status_effects.apply(effect);
+        }
+
+        let amount = self.resisted_damage(damage);
+        let health_before = self.health.max(0);
+        let is_fatal = self.take_damage(amount);
+        DamageResult {
+            damage_dealt: (health_before - self.health.max(0)) as f32,
+            is_fatal,
+            overkill: if is_fatal { (amount - health_before) as f32 } else { 0.0 },
+            is_crit: damage.is_crit,
+        }
+    }
+
+    /// Deals whole points of status effect damage (already resisted)
+    ///
+    /// Ticks land even while the hurt animation plays and don't start it, so
+    /// poison never stunlocks. Returns true if the enemy died.
+    pub fn take_status_damage(&mut self, amount: i32) -> bool {
+        if self.is_dying() {
+            return false;
+        }
+        self.health -= amount;
+        if self.health <= 0 {
+            self.start_dying();
+            return true;
+        }
+        false
+    }
+
+    /// Whole-point damage of a direct hit after resistances; every hit does at least 1
+    fn resisted_damage(&self, damage: &DamageEvent) -> i32 {
+        (calculate_damage_with_resistances(damage, &self.resistances).round() as i32).max(1)
+    }
+
+    /// Starts the death animation (is_alive stays true until it finishes)
+    ///
+    /// The enemy is invulnerable while dying.
+    fn start_dying(&mut self) {
+        self.previous_behavior = self.behavior.clone();
+        self.behavior = EnemyBehavior::Dying;
+        self.status_effects.clear();
+        self.animation_controller.set_state(self.animations.death.clone());
+    }
+
+    /// Stops a knockback slide on the axes a wall pushed the enemy back on
+    pub fn block_knockback(&mut self, push_x: f32, push_y: f32) {
+        self.knockback.block(push_x, push_y);
+    }
+
+    /// Anchor position (current, mid-jump y) rounded to whole pixels
+    pub fn position(&self) -> (i32, i32) {
+        (self.x.round() as i32, self.y.round() as i32)
+    }
+
+    /// Sets custom hitbox parameters for fine-tuning collision detection.
+    ///
+    /// All values are in unscaled sprite pixels (will be multiplied by scale factor).
+    #[allow(dead_code)]
+    pub fn set_hitbox(&mut self, offset_x: i32, offset_y: i32, width: u32, height: u32) {
+        self.hitbox_offset_x = offset_x;
+        self.hitbox_offset_y = offset_y;
+        self.hitbox_width = width;
+        self.hitbox_height = height;
+    }
+}
+
+// ==============================================================================
+// Depth Sorting Render System
+// ==============================================================================
+
+/// Implementation of depth sorting for Enemy.
+///
+/// The enemy's depth is determined by its base Y-coordinate (where it touches ground).
+/// We use base_y rather than y to ensure consistent depth even when jumping.
+///
+/// See docs/systems/depth-sorting-render-system.md for design documentation.
+impl DepthSortable for Enemy<'_> {
+    fn get_depth_y(&self) -> i32 {
+        // Enemy's base_y is already at the anchor point (bottom)
+        // No calculation needed - just return the anchor!
+        // Use base_y (not y) to ensure consistent depth during jump animation
+        self.base_y.round() as i32
+    }
+
+    fn render(&self, canvas: &mut Canvas<Window>) -> Result<(), String> {
+        // Delegate to existing render implementation
+        // This avoids code duplication and keeps the existing render logic intact
+        Enemy::render(self, canvas)
+    }
+}
+
+// ==============================================================================
+// Collision System Implementation
+// ==============================================================================
+
+// This trait implementation makes Enemy participate in the collision system.
+// Important: Collision bounds use the enemy's current Y position (which changes during jumps)
+// rather than base_y, so collision detection works correctly mid-jump.
+impl<'a> Collidable for Enemy<'a> {
+    fn get_bounds(&self) -> Rect {
+        // Use configurable hitbox instead of full sprite size
+        let offset_x = self.hitbox_offset_x * self.scale as i32;
+        let offset_y = self.hitbox_offset_y * self.scale as i32;
+        let scaled_width = self.hitbox_width * self.scale;
+        let scaled_height = self.hitbox_height * self.scale;
+
+        // Use current Y position (self.y), not base_y
+        // This ensures collision detection works when the enemy is jumping
+        let (anchor_x, anchor_y) = self.position();
+        Rect::new(
+            anchor_x + offset_x,
+            anchor_y + offset_y,
+            scaled_width,
+            scaled_height,
+        )
+    }
+
+    fn get_collision_layer(&self) -> CollisionLayer {
+        CollisionLayer::Enemy
+    }
+}
+// ==============================================================================
+// Save/Load Implementation
+// ==============================================================================
+
+impl Saveable for Enemy<'_> {
+    fn to_save_data(&self) -> Result<SaveData, SaveError> {
+        #[derive(Serialize)]
+        struct EnemyData<'s> {
+            enemy_type: &'s str,
+            spawner_id: &'s Option<String>,
+            x: f32,
+            y: f32,
+            base_y: f32,
+            health: i32,
+            max_health: i32,
+            is_alive: bool,
+            has_dropped_loot: bool,
+            home_x: i32,
+            home_y: i32,
+            status_effects: &'s StatusEffects,
+            // Hitbox, stats and AI are NOT saved - they come from the enemy definition
+        }
+
+        let enemy_data = EnemyData {
+            enemy_type: &self.enemy_type,
+            spawner_id: &self.spawner_id,
+            x: self.x,
+            y: self.y,
+            base_y: self.base_y,
+            health: self.health,
+            max_health: self.max_health,
+            is_alive: self.is_alive,
+            has_dropped_loot: self.has_dropped_loot,
+            home_x: self.home_x,
+            home_y: self.home_y,
+            status_effects: &self.status_effects,
+        };
+
+        Ok(SaveData {
+            data_type: "enemy".to_string(),
+            json_data: serde_json::to_string(&enemy_data)?,
+        })
+    }
+
+    /// Restores instance state only
+    ///
+    /// The definition and animation controller are applied afterwards by
+    /// `EnemyRegistry::restore`. Saves from before enemy definitions existed
+    /// ("slime" data without an enemy_type) load as slimes.
+    fn from_save_data(data: &SaveData) -> Result<Self, SaveError> {
+        #[derive(Deserialize)]
+        struct EnemyData {
+            #[serde(default = "legacy_enemy_type")]
+            enemy_type: String,
+            #[serde(default)]
+            spawner_id: Option<String>,
+            // Older saves store whole pixels, which read fine as f32
+            x: f32,
+            y: f32,
+            base_y: f32,
+            health: i32,
+            #[serde(default)]
+            max_health: Option<i32>,
+            is_alive: bool,
+            #[serde(default)]
+            has_dropped_loot: bool,
+            #[serde(default)]
+            home_x: Option<i32>,
+            #[serde(default)]
+            home_y: Option<i32>,
+            #[serde(default)]
+            status_effects: StatusEffects,
+        }
+
+        fn legacy_enemy_type() -> String {
+            "slime".to_string()
+        }
+
+        if data.data_type != "enemy" && data.data_type != "slime" {
+            return Err(SaveError::CorruptedData(format!(
+                "Expected enemy data, got {}",
+                data.data_type
+            )));
+        }
+
+        let enemy_data: EnemyData = serde_json::from_str(&data.json_data)?;
+
+        // Create enemy with animation controller placeholder
+        // The definition and animation controller are set externally
+        let mut enemy = Enemy::blank(
+            &enemy_data.enemy_type,
+            enemy_data.x.round() as i32,
+            enemy_data.base_y.round() as i32,
+            AnimationController::new(),
+        );
+
+        // Older saves have no home/max health: home is where the enemy stands
+        enemy.spawner_id = enemy_data.spawner_id;
+        enemy.x = enemy_data.x;
+        enemy.y = enemy_data.y;
+        enemy.base_y = enemy_data.base_y;
+        enemy.health = enemy_data.health;
+        enemy.max_health = enemy_data.max_health.unwrap_or(enemy_data.health.max(8));
+        enemy.is_alive = enemy_data.is_alive;
+        enemy.has_dropped_loot = enemy_data.has_dropped_loot;
+        enemy.home_x = enemy_data.home_x.unwrap_or(enemy.home_x);
+        enemy.home_y = enemy_data.home_y.unwrap_or(enemy.home_y);
+        enemy.hop_start = (enemy.x, enemy.base_y);
+        enemy.status_effects = enemy_data.status_effects;
+
+        // Note: Behavior state, AI mode and timers are NOT saved
+        // Enemies will start in Idle state with reset timers and re-acquire the player
+        // Invulnerability is derived from behavior state (not saved separately)
+
+        Ok(enemy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tile::{TileId, WorldGrid};
+
+    fn definition(json: &str) -> EnemyDefinition {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn slime_definition() -> EnemyDefinition {
+        definition(include_str!("../../assets/enemies/slime.json"))
+    }
+
+    /// Runs an enemy until it has finished one hop toward the player
+    fn hop_once(enemy: &mut Enemy, player: Option<(i32, i32)>, navigation: &mut Pathfinder) {
+        // First frame notices the player, the rest time passes, then a full hop plays out
+        enemy.update(0.0, player, navigation);
+        assert_eq!(enemy.ai_mode(), EnemyAiMode::Chase);
+        enemy.update(enemy.ai.rest_time, player, navigation);
+        for _ in 0..10 {
+            enemy.update(0.1, player, navigation);
+        }
+    }
+
+    #[test]
+    fn test_new_uses_definition() {
+        let rock_slime = definition(include_str!("../../assets/enemies/rock_slime.json"));
+        let enemy = Enemy::new(&rock_slime, 100, 100, AnimationController::new());
+        assert_eq!(enemy.enemy_type, "rock_slime");
+        assert_eq!(enemy.health, rock_slime.stats.max_health);
+        assert_eq!(enemy.max_health, rock_slime.stats.max_health);
+        assert_eq!(enemy.melee_attack, rock_slime.melee_attack);
+        assert_eq!(enemy.ai, rock_slime.ai);
+    }
+
+    #[test]
+    fn test_update_hops_toward_player() {
+        let mut slime = Enemy::new(&slime_definition(), 100, 100, AnimationController::new());
+        let (center_x, center_y) = slime.nav_position();
+        let player = Some((center_x as i32 + 100, center_y as i32));
+
+        // No navigation data: hops straight at the player
+        hop_once(&mut slime, player, &mut Pathfinder::new());
+
+        // Hops land exactly (sub-pixel), with no rounding along the way
+        assert!((slime.x - (100.0 + slime.ai.hop_distance)).abs() < 0.001);
+        assert_eq!(slime.base_y, 100.0);
+        assert_eq!(slime.y, slime.base_y);
+    }
+
+    #[test]
+    fn test_chase_paths_around_wall() {
+        // Wall in column 5 from the top down to row 4; the only way round is below it
+        let mut navigation = Pathfinder::new();
+        navigation.sync(&WorldGrid::new(10, 10, TileId::Grass), &[Rect::new(160, 0, 32, 160)]);
+
+        // Slime centered in cell (3, 2), player in cell (6, 2) on the other side
+        let mut slime = Enemy::new(&slime_definition(), 110, 116, AnimationController::new());
+        assert_eq!(pathfinding::world_to_cell(slime.nav_position().0, slime.nav_position().1), (3, 2));
+        hop_once(&mut slime, Some((208, 80)), &mut navigation);
+
+        // A straight hop would keep base_y; the path heads down around the wall
+        assert!(slime.base_y > 116.0, "slime should detour downward, base_y = {}", slime.base_y);
+        assert!(slime.nav_path().contains(&(5, 5)), "path {:?}", slime.nav_path());
+    }
+
+    #[test]
+    fn test_ranged_enemy_shoots_in_range_then_waits_for_cooldown() {
+        let spitter = definition(include_str!("../../assets/enemies/spitter.json"));
+        let ranged = spitter.ranged_attack.unwrap();
+        let mut enemy = Enemy::new(&spitter, 100, 100, AnimationController::new());
+        let (center_x, center_y) = enemy.nav_position();
+        let player = Some((center_x as i32 + 80, center_y as i32));
+        let mut navigation = Pathfinder::new();
+
+        // Noticing the player switches to Chase; the next frame fires
+        enemy.update(0.0, player, &mut navigation);
+        enemy.update(0.0, player, &mut navigation);
+        let shots = enemy.take_pending_shots();
+        assert_eq!(shots.len(), 1, "spitter should fire one shot");
+        assert!(shots[0].velocity_x > 0.0);
+        assert_eq!(shots[0].damage, ranged.damage);
+        assert!(enemy.take_pending_shots().is_empty());
+
+        enemy.update(ranged.cooldown * 0.5, player, &mut navigation);
+        assert!(enemy.take_pending_shots().is_empty());
+    }
+
+    #[test]
+    fn test_boss_phases_swap_attacks_and_summon() {
+        let slime_king = definition(include_str!("../../assets/enemies/slime_king.json"));
+        let boss = slime_king.boss.clone().unwrap();
+        let mut enemy = Enemy::new(&slime_king, 200, 200, AnimationController::new());
+        let (center_x, center_y) = enemy.nav_position();
+        let player = Some((center_x as i32 + 150, center_y as i32));
+        let mut navigation = Pathfinder::new();
+
+        // Bigger sprite, bigger hitbox
+        assert_eq!(enemy.get_bounds().width(), slime_king.hitbox.width * 4);
+
+        // Nothing happens until there's a player to fight
+        enemy.update(0.0, None, &mut navigation);
+        assert_eq!(enemy.boss_phase(), None);
+
+        enemy.update(0.0, player, &mut navigation);
+        assert_eq!(enemy.boss_phase(), Some(0));
+        assert_eq!(enemy.melee_attack, boss.phases[0].melee_attack);
+        assert!(enemy.ranged_attack.is_none());
+        assert!(enemy.take_pending_summon().is_none());
+
+        // Dropping past the second threshold brings in its volley and minions
+        enemy.health = (enemy.max_health as f32 * 0.5) as i32;
+        enemy.update(0.0, player, &mut navigation);
+        assert_eq!(enemy.boss_phase(), Some(1));
+        assert_eq!(enemy.ranged_attack, boss.phases[1].ranged_attack);
+        assert_eq!(enemy.take_pending_summon().map(|summon| summon.enemy_id), Some("slime".to_string()));
+        assert!(enemy.take_pending_summon().is_none());
+
+        // The volley fans out around the aim
+        enemy.update(0.0, player, &mut navigation);
+        let shots = enemy.take_pending_shots();
+        assert_eq!(shots.len(), 3);
+        assert!(shots[1].velocity_y.abs() < 0.001 && shots[1].velocity_x > 0.0);
+        assert!(shots[0].velocity_y < 0.0 && shots[2].velocity_y > 0.0);
+
+        // Ordinary enemies never summon
+        let mut slime = Enemy::new(&slime_definition(), 100, 100, AnimationController::new());
+        slime.update(1.0, player, &mut navigation);
+        assert!(!slime.is_boss() && slime.take_pending_summon().is_none());
+    }
+
+    #[test]
+    fn test_melee_enemy_never_shoots() {
+        let mut slime = Enemy::new(&slime_definition(), 100, 100, AnimationController::new());
+        let (center_x, center_y) = slime.nav_position();
+        let player = Some((center_x as i32 + 40, center_y as i32));
+        slime.update(0.0, player, &mut Pathfinder::new());
+        slime.update(0.0, player, &mut Pathfinder::new());
+        assert!(slime.take_pending_shots().is_empty());
+    }
+
+    #[test]
+    fn test_melee_attack_telegraphs_then_hits_once() {
+        let slime_definition = slime_definition();
+        let melee = slime_definition.melee_attack.unwrap();
+        let mut slime = Enemy::new(&slime_definition, 100, 100, AnimationController::new());
+        let center = slime.get_bounds().center();
+        let player = Some((center.x() + 30, center.y()));
+        let player_bounds = Rect::from_center((center.x() + 30, center.y()), 24, 24);
+        let mut navigation = Pathfinder::new();
+
+        // Noticing the player switches to Chase; the next frame starts the windup facing east
+        slime.update(0.0, player, &mut navigation);
+        slime.update(0.0, player, &mut navigation);
+        let attack = slime.melee_attack_event().expect("slime should wind up");
+        assert_eq!(attack.direction, Direction::East);
+        assert!(attack.get_hitbox().has_intersection(player_bounds));
+
+        // The telegraph can't hurt
+        assert!(slime.try_melee_hit(&player_bounds).is_none());
+
+        // Once active, the hitbox lands a single time
+        slime.update(melee.windup, player, &mut navigation);
+        assert!(slime.is_melee_active());
+        assert_eq!(slime.try_melee_hit(&player_bounds).map(|attack| attack.damage), Some(melee.damage));
+        assert!(slime.try_melee_hit(&player_bounds).is_none());
+
+        // After the hit window the slime cools down before winding up again
+        slime.update(melee.active_time, player, &mut navigation);
+        assert!(slime.melee_attack_event().is_none());
+        slime.update(0.0, player, &mut navigation);
+        assert!(slime.melee_attack_event().is_none());
+    }
+
+    #[test]
+    fn test_dodged_melee_attack_misses() {
+        let slime_definition = slime_definition();
+        let melee = slime_definition.melee_attack.unwrap();
+        let mut slime = Enemy::new(&slime_definition, 100, 100, AnimationController::new());
+        let center = slime.get_bounds().center();
+        let mut navigation = Pathfinder::new();
+
+        let player = Some((center.x(), center.y() + 30));
+        slime.update(0.0, player, &mut navigation);
+        slime.update(0.0, player, &mut navigation);
+        assert_eq!(slime.melee_attack_event().unwrap().direction, Direction::South);
+
+        // The player steps aside during the windup: the locked direction keeps the hit south
+        let dodged = Some((center.x() + 60, center.y()));
+        slime.update(melee.windup, dodged, &mut navigation);
+        assert!(slime.is_melee_active());
+        assert!(slime.try_melee_hit(&Rect::from_center((center.x() + 60, center.y()), 24, 24)).is_none());
+    }
+
+    #[test]
+    fn test_getting_hit_cancels_melee_attack() {
+        let mut slime = Enemy::new(&slime_definition(), 100, 100, AnimationController::new());
+        let center = slime.get_bounds().center();
+        let player = Some((center.x() + 30, center.y()));
+        let mut navigation = Pathfinder::new();
+        slime.update(0.0, player, &mut navigation);
+        slime.update(0.0, player, &mut navigation);
+        assert!(slime.melee_attack_event().is_some());
+
+        slime.take_damage(1);
+        assert!(slime.melee_attack_event().is_none());
+        assert!(slime.melee_cooldown > 0.0);
+    }
+
+    #[test]
+    fn test_knockback_slides_and_is_resisted() {
+        let hit = DamageEvent::physical(1.0, crate::combat::DamageSource::Player).with_knockback((240.0, 0.0));
+        let slide = |definition: &EnemyDefinition| {
+            let mut enemy = Enemy::new(definition, 100, 100, AnimationController::new());
+            enemy.take_damage_event(&hit);
+            for _ in 0..30 {
+                enemy.update(1.0 / 60.0, None, &mut Pathfinder::new());
+            }
+            assert_eq!(enemy.y, enemy.base_y);
+            enemy.x - 100.0
+        };
+
+        let slime_slide = slide(&slime_definition());
+        let rock_slide = slide(&definition(include_str!("../../assets/enemies/rock_slime.json")));
+        assert!(slime_slide > 20.0, "slime slid {}", slime_slide);
+        assert!(rock_slide > 0.0 && rock_slide < slime_slide / 4.0, "rock slime slid {}", rock_slide);
+    }
+
+    #[test]
+    fn test_resistances_reduce_damage_by_type() {
+        use crate::combat::DamageSource;
+        let rock_slime = definition(include_str!("../../assets/enemies/rock_slime.json"));
+
+        // 30% defense: a 3 damage punch does 2, magic still does 3
+        let mut enemy = Enemy::new(&rock_slime, 100, 100, AnimationController::new());
+        enemy.take_damage_event(&DamageEvent::physical(3.0, DamageSource::Player));
+        assert_eq!(enemy.health, rock_slime.stats.max_health - 2);
+
+        let mut enemy = Enemy::new(&rock_slime, 100, 100, AnimationController::new());
+        enemy.take_damage_event(&DamageEvent::magical(3.0, DamageSource::Player));
+        assert_eq!(enemy.health, rock_slime.stats.max_health - 3);
+    }
+
+    #[test]
+    fn test_damage_result_reports_crits_and_overkill() {
+        use crate::combat::DamageSource;
+        let mut slime = Enemy::new(&slime_definition(), 100, 100, AnimationController::new());
+
+        let mut crit = DamageEvent::physical(6.0, DamageSource::Player);
+        crit.is_crit = true;
+        let result = slime.take_damage_event(&crit);
+        assert!(result.is_crit && !result.is_fatal);
+        assert_eq!(result.damage_dealt, 6.0);
+
+        // Still invulnerable from the first hit
+        assert_eq!(slime.take_damage_event(&crit).damage_dealt, 0.0);
+
+        let mut slime = Enemy::new(&slime_definition(), 100, 100, AnimationController::new());
+        let result = slime.take_damage_event(&DamageEvent::physical(11.0, DamageSource::Player));
+        assert!(result.is_fatal && !result.is_crit);
+        assert_eq!((result.damage_dealt, result.overkill), (8.0, 3.0));
+    }
+
+    #[test]
+    fn test_status_effects_tick_slow_and_stun() {
+        use crate::status::{StatusEffect, StatusKind};
+        let mut navigation = Pathfinder::new();
+
+        // Poison ticks don't play the hurt animation (or make the slime invulnerable)
+        let mut slime = Enemy::new(&slime_definition(), 100, 100, AnimationController::new());
+        slime.status_effects.apply(StatusEffect::new(StatusKind::Poison, 1.0, 3.0));
+        for _ in 0..4 {
+            slime.update(1.0, None, &mut navigation);
+        }
+        assert_eq!(slime.health, slime.max_health - 3);
+        assert!(!slime.is_invulnerable());
+
+        // Weak poison builds up to whole points instead of dealing 1 a tick
+        let mut slime = Enemy::new(&slime_definition(), 100, 100, AnimationController::new());
+        slime.status_effects.apply(StatusEffect::new(StatusKind::Poison, 0.25, 4.0));
+        for _ in 0..4 {
+            slime.update(1.0, None, &mut navigation);
+        }
+        assert_eq!(slime.health, slime.max_health - 1);
+
+        // A stunned slime doesn't wind up on a player in range until the stun wears off
+        let mut slime = Enemy::new(&slime_definition(), 100, 100, AnimationController::new());
+        let center = slime.get_bounds().center();
+        let player = Some((center.x() + 30, center.y()));
+        slime.status_effects.apply(StatusEffect::new(StatusKind::Stun, 0.0, 0.5));
+        slime.update(0.0, player, &mut navigation);
+        slime.update(0.1, player, &mut navigation);
+        assert!(slime.melee_attack_event().is_none());
+        slime.update(0.4, player, &mut navigation);
+        slime.update(0.0, player, &mut navigation);
+        assert!(slime.melee_attack_event().is_some());
+
+        // Slowed hops take longer to cover the same distance
+        let hop_progress = |slow: Option<f32>| {
+            let mut slime = Enemy::new(&slime_definition(), 100, 100, AnimationController::new());
+            if let Some(amount) = slow {
+                slime.status_effects.apply(StatusEffect::new(StatusKind::Slow, amount, 10.0));
+            }
+            let (center_x, center_y) = slime.nav_position();
+            let player = Some((center_x as i32 + 100, center_y as i32));
+            let mut navigation = Pathfinder::new();
+            slime.update(0.0, player, &mut navigation);
+            slime.update(slime.ai.rest_time, player, &mut navigation);
+            slime.update(0.2, player, &mut navigation);
+            slime.x - 100.0
+        };
+        let normal = hop_progress(None);
+        let slowed = hop_progress(Some(0.5));
+        assert!((slowed - normal * 0.5).abs() < 0.01, "normal {} slowed {}", normal, slowed);
+    }
+
+    #[test]
+    fn test_save_round_trip_keeps_type() {
+        let rock_slime = definition(include_str!("../../assets/enemies/rock_slime.json"));
+        let mut enemy = Enemy::new(&rock_slime, 40, 50, AnimationController::new());
+        enemy.health = 3;
+        enemy.status_effects.apply(crate::status::StatusEffect::new(crate::status::StatusKind::Slow, 0.4, 2.0));
+
+        let restored = Enemy::from_save_data(&enemy.to_save_data().unwrap()).unwrap();
+        assert_eq!(restored.status_effects, enemy.status_effects);
+        assert_eq!(restored.enemy_type, "rock_slime");
+        assert_eq!(restored.spawner_id, None);
+        assert_eq!((restored.health, restored.max_health), (3, rock_slime.stats.max_health));
+    }
+
+    #[test]
+    fn test_legacy_slime_save_loads_as_slime() {
+        let data = SaveData {
+            data_type: "slime".to_string(),
+            json_data: r#"{"x":40,"y":50,"base_y":50,"health":5,"is_alive":true}"#.to_string(),
+        };
+        let slime = Enemy::from_save_data(&data).unwrap();
+        assert_eq!(slime.enemy_type, "slime");
+        assert_eq!((slime.home_x, slime.home_y), (40, 50));
+        assert_eq!(slime.max_health, 8);
+    }
+}
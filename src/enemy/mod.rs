@@ -0,0 +1,23 @@
+// Enemy system module
+//
+// Enemies are data-driven: each type is an EnemyDefinition loaded from
+// assets/enemies/*.json into the EnemyRegistry, and every enemy in the
+// world is an Enemy instance built from one.
+// - ai: chase/flee/return-home state machine and hop maths
+// - boss: health-threshold phases and minion summons for boss enemies
+// - definition: the JSON format for an enemy type
+// - instance: a live enemy (movement, health, saving)
+// - registry: loads definitions and creates enemies
+// - spawner: placed spawners with respawn rules and wave mode
+
+pub mod ai;
+pub mod boss;
+pub mod definition;
+pub mod instance;
+pub mod registry;
+pub mod spawner;
+
+// Re-export main types for convenient access
+pub use instance::Enemy;
+pub use registry::EnemyRegistry;
+pub use spawner::{Spawner, SpawnerDefinition, SpawnerEvent};
@@ -0,0 +1,252 @@
+// Enemy registry - every enemy type the game knows about
+//
+// Loaded once at startup from assets/enemies/*.json. Each definition's
+// animation config is loaded alongside it, so creating an enemy only needs
+// the texture map (textures can't live here because of SDL lifetimes).
+
+use crate::animation::{AnimationConfig, AnimationController};
+use crate::rng::SimpleRng;
+use sdl2::render::Texture;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::definition::EnemyDefinition;
+use super::instance::Enemy;
+
+/// Central registry of enemy definitions (like ItemRegistry for items)
+pub struct EnemyRegistry {
+    definitions: HashMap<String, EnemyDefinition>,
+    /// Animation configs keyed by definition ID
+    animation_configs: HashMap<String, AnimationConfig>,
+}
+
+impl EnemyRegistry {
+    pub fn new() -> Self {
+        EnemyRegistry {
+            definitions: HashMap::new(),
+            animation_configs: HashMap::new(),
+        }
+    }
+
+    /// Loads every `*.json` definition in a directory
+    ///
+    /// Fails on the first invalid file, naming it in the error.
+    pub fn load_from_directory(directory: &str) -> Result<Self, String> {
+        let mut paths: Vec<_> = fs::read_dir(directory)
+            .map_err(|e| format!("Failed to read enemy directory '{}': {}", directory, e))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        let mut registry = Self::new();
+        for path in paths {
+            registry.load_file(&path)
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+
+        if registry.definitions.is_empty() {
+            return Err(format!("No enemy definitions found in '{}'", directory));
+        }
+        registry.check_summons()?;
+        Ok(registry)
+    }
+
+    /// Checks that every enemy a boss summons is registered
+    ///
+    /// Done once everything is loaded, since a boss may load before its minions.
+    fn check_summons(&self) -> Result<(), String> {
+        for definition in self.definitions.values() {
+            let mut summoned = definition.boss.iter().flat_map(|boss| boss.summoned_ids());
+            if let Some(missing) = summoned.find(|id| !self.definitions.contains_key(*id)) {
+                return Err(format!("'{}': summons unknown enemy '{}'", definition.id, missing));
+            }
+        }
+        Ok(())
+    }
+
+    fn load_file(&mut self, path: &Path) -> Result<(), String> {
+        let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let definition: EnemyDefinition = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+        let animation_config = AnimationConfig::load_from_file(&definition.animation_config)
+            .map_err(|e| format!("failed to load '{}': {}", definition.animation_config, e))?;
+        self.register(definition, animation_config)
+    }
+
+    /// Registers a definition with its animation config
+    ///
+    /// Returns error if the definition is invalid, its ID is taken, or the
+    /// config is missing one of its animation states.
+    pub fn register(&mut self, definition: EnemyDefinition, animation_config: AnimationConfig) -> Result<(), String> {
+        definition.validate()?;
+        if self.definitions.contains_key(&definition.id) {
+            return Err(format!("Enemy '{}' already registered", definition.id));
+        }
+        if let Some(state) = definition.animations.all().into_iter().find(|state| !animation_config.has_state(state)) {
+            return Err(format!(
+                "'{}': animation '{}' not found in {}",
+                definition.id, state, definition.animation_config
+            ));
+        }
+
+        self.animation_configs.insert(definition.id.clone(), animation_config);
+        self.definitions.insert(definition.id.clone(), definition);
+        Ok(())
+    }
+
+    pub fn get(&self, id: &str) -> Option<&EnemyDefinition> {
+        self.definitions.get(id)
+    }
+
+    /// The first boss by ID (the one the pyramids summon), if any is registered
+    pub fn first_boss(&self) -> Option<&str> {
+        self.ids().into_iter().find(|id| self.definitions[*id].boss.is_some())
+    }
+
+    /// All registered IDs, sorted (stable order for menus)
+    pub fn ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.definitions.keys().map(String::as_str).collect();
+        ids.sort();
+        ids
+    }
+
+    /// Builds a full-health enemy with its animations
+    ///
+    /// `textures` maps definition IDs to their sprite sheets.
+    pub fn create_enemy<'a>(
+        &self,
+        id: &str,
+        x: i32,
+        y: i32,
+        textures: &'a HashMap<String, Texture<'a>>,
+    ) -> Result<Enemy<'a>, String> {
+        let definition = self.get(id).ok_or_else(|| format!("Unknown enemy type '{}'", id))?;
+        let controller = self.create_controller(definition, textures)?;
+        Ok(Enemy::new(definition, x, y, controller))
+    }
+
+    /// Re-attaches definition and animations to an enemy loaded from a save
+    pub fn restore<'a>(&self, enemy: &mut Enemy<'a>, textures: &'a HashMap<String, Texture<'a>>) -> Result<(), String> {
+        let definition = self.get(&enemy.enemy_type)
+            .ok_or_else(|| format!("Unknown enemy type '{}' in save", enemy.enemy_type))?;
+        enemy.apply_definition(definition);
+        enemy.set_animation_controller(self.create_controller(definition, textures)?);
+        Ok(())
+    }
+
+    fn create_controller<'a>(
+        &self,
+        definition: &EnemyDefinition,
+        textures: &'a HashMap<String, Texture<'a>>,
+    ) -> Result<AnimationController<'a>, String> {
+        let texture = textures.get(&definition.id)
+            .ok_or_else(|| format!("Missing texture for enemy '{}'", definition.id))?;
+        self.animation_configs[&definition.id].create_controller(texture, &definition.animations.all())
+    }
+
+    /// Picks an enemy type for ambient spawning, weighted by the definitions'
+    /// day or night weights. Returns None when no type spawns at this time.
+    pub fn pick_ambient(&self, is_dark: bool, rng: &mut SimpleRng) -> Option<&str> {
+        let weight = |definition: &EnemyDefinition| {
+            if is_dark { definition.ambient_spawn.night_weight } else { definition.ambient_spawn.day_weight }
+        };
+
+        let total: u32 = self.definitions.values().map(weight).sum();
+        if total == 0 {
+            return None;
+        }
+
+        // Walk the IDs in sorted order so the same roll always picks the same type
+        let mut roll = rng.range_u32(0, total - 1);
+        for id in self.ids() {
+            let w = weight(&self.definitions[id]);
+            if roll < w {
+                return Some(id);
+            }
+            roll -= w;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(json: &str) -> EnemyDefinition {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn slime_animations() -> AnimationConfig {
+        serde_json::from_str(include_str!("../../assets/config/slime_animations.json")).unwrap()
+    }
+
+    fn bundled_registry() -> EnemyRegistry {
+        let mut registry = EnemyRegistry::new();
+        for json in [
+            include_str!("../../assets/enemies/slime.json"),
+            include_str!("../../assets/enemies/spitter.json"),
+            include_str!("../../assets/enemies/rock_slime.json"),
+        ] {
+            registry.register(definition(json), slime_animations()).unwrap();
+        }
+        registry
+    }
+
+    #[test]
+    fn test_register_rejects_duplicates_and_missing_animations() {
+        let mut registry = bundled_registry();
+        assert_eq!(registry.ids(), vec!["rock_slime", "slime", "spitter"]);
+
+        let duplicate = definition(include_str!("../../assets/enemies/slime.json"));
+        assert!(registry.register(duplicate, slime_animations()).is_err());
+
+        let mut missing = definition(include_str!("../../assets/enemies/slime.json"));
+        missing.id = "ghost".to_string();
+        missing.animations.hurt = "ghost_hurt".to_string();
+        assert!(registry.register(missing, slime_animations()).is_err());
+        assert!(registry.get("ghost").is_none());
+    }
+
+    #[test]
+    fn test_pick_ambient_respects_weights() {
+        let registry = bundled_registry();
+        let mut rng = SimpleRng::new(7);
+
+        // Only slimes have a day weight
+        for _ in 0..50 {
+            assert_eq!(registry.pick_ambient(false, &mut rng), Some("slime"));
+        }
+
+        let mut picked = HashMap::new();
+        for _ in 0..300 {
+            *picked.entry(registry.pick_ambient(true, &mut rng).unwrap()).or_insert(0) += 1;
+        }
+        assert_eq!(picked.len(), 3, "every night type should appear: {:?}", picked);
+        assert!(picked["slime"] > picked["rock_slime"]);
+    }
+
+    #[test]
+    fn test_bosses_need_their_summons_registered() {
+        let slime_king = || definition(include_str!("../../assets/enemies/slime_king.json"));
+        let mut registry = bundled_registry();
+        assert_eq!(registry.first_boss(), None);
+        registry.register(slime_king(), slime_animations()).unwrap();
+        assert_eq!(registry.first_boss(), Some("slime_king"));
+        assert!(registry.check_summons().is_ok());
+
+        let mut lonely = EnemyRegistry::new();
+        lonely.register(slime_king(), slime_animations()).unwrap();
+        assert!(lonely.check_summons().is_err());
+    }
+
+    #[test]
+    fn test_pick_ambient_with_no_weights() {
+        let mut registry = EnemyRegistry::new();
+        let mut quiet = definition(include_str!("../../assets/enemies/slime.json"));
+        quiet.ambient_spawn = Default::default();
+        registry.register(quiet, slime_animations()).unwrap();
+        assert_eq!(registry.pick_ambient(true, &mut SimpleRng::new(1)), None);
+    }
+}
@@ -0,0 +1,394 @@
+// Enemy spawners - placed sources of enemies with spawn rules
+//
+// A spawner keeps up to `max_alive` enemies of one type around a point or
+// area, respawning them after `respawn_interval`. Spawners can be limited to
+// day or night and never spawn close to the player.
+//
+// In wave mode a spawner instead releases escalating waves: each wave is
+// larger and tougher than the last, and the next one starts a while after
+// the previous wave is cleared.
+//
+// Spawners come from a zone's map file (or built-in zone defaults) and are
+// saved with the zone, timers and wave progress included. The spawner itself
+// never touches the world: `update` returns SpawnerEvents for the game to act on.
+
+use crate::rng::SimpleRng;
+use serde::{Deserialize, Serialize};
+
+/// Attempts at finding a spawn position far enough from the player
+const SPAWN_POSITION_ATTEMPTS: u32 = 5;
+
+/// The data describing a spawner (stored in map files)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpawnerDefinition {
+    /// Unique within the zone; enemies remember which spawner made them
+    pub id: String,
+
+    /// Enemy definition ID (e.g. "slime")
+    pub enemy_type: String,
+
+    /// Where enemies appear
+    pub area: SpawnArea,
+
+    /// Most enemies from this spawner alive at once
+    #[serde(default = "default_max_alive")]
+    pub max_alive: usize,
+
+    /// Seconds between spawns (between individual spawns within a wave, in wave mode)
+    #[serde(default = "default_respawn_interval")]
+    pub respawn_interval: f32,
+
+    /// Time of day the spawner works
+    #[serde(default)]
+    pub active_time: SpawnTime,
+
+    /// Never spawn closer than this to the player (world pixels)
+    #[serde(default = "default_min_player_distance")]
+    pub min_player_distance: f32,
+
+    /// Present for wave-mode spawners
+    #[serde(default)]
+    pub waves: Option<WaveConfig>,
+}
+
+fn default_max_alive() -> usize {
+    3
+}
+
+fn default_respawn_interval() -> f32 {
+    20.0
+}
+
+fn default_min_player_distance() -> f32 {
+    96.0
+}
+
+/// Spawn region in world pixels (top-left + size; zero size = a single point)
+///
+/// Positions inside the area become the spawned enemy's collision center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpawnArea {
+    pub x: i32,
+    pub y: i32,
+    #[serde(default)]
+    pub width: u32,
+    #[serde(default)]
+    pub height: u32,
+}
+
+impl SpawnArea {
+    pub fn point(x: i32, y: i32) -> Self {
+        SpawnArea { x, y, width: 0, height: 0 }
+    }
+
+    pub fn center(&self) -> (i32, i32) {
+        (self.x + self.width as i32 / 2, self.y + self.height as i32 / 2)
+    }
+
+    /// Random position inside the area
+    fn random_position(&self, rng: &mut SimpleRng) -> (i32, i32) {
+        (
+            self.x + rng.range_u32(0, self.width) as i32,
+            self.y + rng.range_u32(0, self.height) as i32,
+        )
+    }
+}
+
+/// When a spawner is active
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpawnTime {
+    #[default]
+    Always,
+    Day,
+    /// Night, or any time underground
+    Night,
+}
+
+impl SpawnTime {
+    pub fn allows(&self, is_dark: bool) -> bool {
+        match self {
+            SpawnTime::Always => true,
+            SpawnTime::Day => !is_dark,
+            SpawnTime::Night => is_dark,
+        }
+    }
+}
+
+/// Wave-mode settings
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WaveConfig {
+    /// Enemies in the first wave
+    pub first_wave_size: usize,
+    /// Extra enemies per wave after the first
+    #[serde(default)]
+    pub size_increase: usize,
+    /// Extra max health per wave after the first (0.25 = +25% each wave)
+    #[serde(default)]
+    pub health_increase: f32,
+    /// Seconds between clearing a wave and the next one starting
+    pub wave_delay: f32,
+    /// Stop after this many waves (None = endless)
+    #[serde(default)]
+    pub max_waves: Option<u32>,
+}
+
+impl WaveConfig {
+    /// Number of enemies in the given wave (1-based)
+    pub fn wave_size(&self, wave: u32) -> usize {
+        self.first_wave_size + self.size_increase * wave.saturating_sub(1) as usize
+    }
+
+    /// Health multiplier for enemies in the given wave (1-based)
+    pub fn health_scale(&self, wave: u32) -> f32 {
+        1.0 + self.health_increase * wave.saturating_sub(1) as f32
+    }
+}
+
+/// What the game should do after a spawner update
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpawnerEvent {
+    /// Spawn an enemy centered at (x, y) with its max health scaled
+    Spawn { enemy_type: String, x: i32, y: i32, health_scale: f32 },
+    /// A new wave began (1-based)
+    WaveStarted(u32),
+    /// The final wave was cleared
+    WavesCompleted,
+}
+
+/// A spawner placed in a zone, with its runtime state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Spawner {
+    pub definition: SpawnerDefinition,
+    /// Seconds since the last spawn (or since the last wave was cleared)
+    pub timer: f32,
+    /// Waves started so far (wave mode)
+    #[serde(default)]
+    pub wave: u32,
+    /// Enemies of the current wave still to be spawned
+    #[serde(default)]
+    pub wave_remaining: usize,
+    /// True once every wave has been cleared
+    #[serde(default)]
+    pub finished: bool,
+}
+
+impl Spawner {
+    /// New spawner, ready to spawn (or start its first wave) right away
+    pub fn new(definition: SpawnerDefinition) -> Self {
+        let timer = match &definition.waves {
+            Some(waves) => waves.wave_delay,
+            None => definition.respawn_interval,
+        };
+        Spawner { definition, timer, wave: 0, wave_remaining: 0, finished: false }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.definition.id
+    }
+
+    /// Advances the spawner's timers
+    ///
+    /// # Arguments
+    /// * `delta_time` - Time elapsed since last frame (in seconds)
+    /// * `alive` - Enemies from this spawner currently alive (dying ones excluded)
+    /// * `is_dark` - Night time or underground
+    /// * `player_position` - Player collision center
+    /// * `rng` - Used to pick spawn positions
+    pub fn update(
+        &mut self,
+        delta_time: f32,
+        alive: usize,
+        is_dark: bool,
+        player_position: (i32, i32),
+        rng: &mut SimpleRng,
+    ) -> Vec<SpawnerEvent> {
+        let mut events = Vec::new();
+        if self.finished || !self.definition.active_time.allows(is_dark) {
+            return events;
+        }
+        self.timer += delta_time;
+
+        if let Some(waves) = self.definition.waves {
+            // Between waves: wait for the current wave to be cleared, then the delay
+            if self.wave_remaining == 0 {
+                if alive > 0 {
+                    self.timer = 0.0;
+                    return events;
+                }
+                if self.wave > 0 && waves.max_waves.is_some_and(|max| self.wave >= max) {
+                    self.finished = true;
+                    events.push(SpawnerEvent::WavesCompleted);
+                    return events;
+                }
+                if self.timer < waves.wave_delay {
+                    return events;
+                }
+                self.wave += 1;
+                self.wave_remaining = waves.wave_size(self.wave);
+                // First enemy of the wave appears immediately
+                self.timer = self.definition.respawn_interval;
+                events.push(SpawnerEvent::WaveStarted(self.wave));
+            }
+
+            if alive < self.definition.max_alive && self.timer >= self.definition.respawn_interval {
+                let health_scale = waves.health_scale(self.wave);
+                if let Some(event) = self.spawn_event(player_position, health_scale, rng) {
+                    events.push(event);
+                    self.wave_remaining -= 1;
+                    self.timer = 0.0;
+                }
+            }
+            return events;
+        }
+
+        if alive >= self.definition.max_alive {
+            // Full: the respawn countdown starts once one of ours dies
+            self.timer = 0.0;
+        } else if self.timer >= self.definition.respawn_interval
+            && let Some(event) = self.spawn_event(player_position, 1.0, rng)
+        {
+            events.push(event);
+            self.timer = 0.0;
+        }
+        events
+    }
+
+    /// Picks a position away from the player (None if every attempt was too close)
+    fn spawn_event(&self, player_position: (i32, i32), health_scale: f32, rng: &mut SimpleRng) -> Option<SpawnerEvent> {
+        for _ in 0..SPAWN_POSITION_ATTEMPTS {
+            let (x, y) = self.definition.area.random_position(rng);
+            let (dx, dy) = ((x - player_position.0) as f32, (y - player_position.1) as f32);
+            if (dx * dx + dy * dy).sqrt() >= self.definition.min_player_distance {
+                return Some(SpawnerEvent::Spawn {
+                    enemy_type: self.definition.enemy_type.clone(),
+                    x,
+                    y,
+                    health_scale,
+                });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAR_AWAY: (i32, i32) = (1000, 1000);
+
+    fn definition() -> SpawnerDefinition {
+        SpawnerDefinition {
+            id: "test".to_string(),
+            enemy_type: "slime".to_string(),
+            area: SpawnArea { x: 100, y: 100, width: 32, height: 32 },
+            max_alive: 2,
+            respawn_interval: 10.0,
+            active_time: SpawnTime::Always,
+            min_player_distance: 50.0,
+            waves: None,
+        }
+    }
+
+    fn spawn_count(events: &[SpawnerEvent]) -> usize {
+        events.iter().filter(|event| matches!(event, SpawnerEvent::Spawn { .. })).count()
+    }
+
+    #[test]
+    fn test_spawns_inside_area_then_waits_for_interval() {
+        let mut spawner = Spawner::new(definition());
+        let mut rng = SimpleRng::new(3);
+
+        let events = spawner.update(0.0, 0, false, FAR_AWAY, &mut rng);
+        let Some(SpawnerEvent::Spawn { x, y, .. }) = events.first() else {
+            panic!("expected an immediate spawn, got {:?}", events);
+        };
+        assert!((100..=132).contains(x) && (100..=132).contains(y));
+
+        assert_eq!(spawn_count(&spawner.update(9.0, 1, false, FAR_AWAY, &mut rng)), 0);
+        assert_eq!(spawn_count(&spawner.update(1.0, 1, false, FAR_AWAY, &mut rng)), 1);
+    }
+
+    #[test]
+    fn test_respects_max_alive() {
+        let mut spawner = Spawner::new(definition());
+        let mut rng = SimpleRng::new(3);
+        assert_eq!(spawn_count(&spawner.update(100.0, 2, false, FAR_AWAY, &mut rng)), 0);
+
+        // One died: the countdown starts from that moment
+        assert_eq!(spawn_count(&spawner.update(5.0, 1, false, FAR_AWAY, &mut rng)), 0);
+        assert_eq!(spawn_count(&spawner.update(5.0, 1, false, FAR_AWAY, &mut rng)), 1);
+    }
+
+    #[test]
+    fn test_time_of_day_and_player_distance() {
+        let mut night_only = Spawner::new(SpawnerDefinition { active_time: SpawnTime::Night, ..definition() });
+        let mut rng = SimpleRng::new(3);
+        assert_eq!(spawn_count(&night_only.update(100.0, 0, false, FAR_AWAY, &mut rng)), 0);
+        assert_eq!(spawn_count(&night_only.update(0.0, 0, true, FAR_AWAY, &mut rng)), 1);
+
+        // Player standing in the middle of the area blocks every position
+        let mut spawner = Spawner::new(definition());
+        assert_eq!(spawn_count(&spawner.update(0.0, 0, false, (116, 116), &mut rng)), 0);
+    }
+
+    #[test]
+    fn test_waves_escalate_and_finish() {
+        let waves = WaveConfig {
+            first_wave_size: 2,
+            size_increase: 1,
+            health_increase: 0.5,
+            wave_delay: 5.0,
+            max_waves: Some(2),
+        };
+        let mut spawner = Spawner::new(SpawnerDefinition {
+            max_alive: 10,
+            respawn_interval: 1.0,
+            waves: Some(waves),
+            ..definition()
+        });
+        let mut rng = SimpleRng::new(3);
+
+        // Wave 1 starts immediately and releases 2 enemies, one per second
+        let events = spawner.update(0.0, 0, false, FAR_AWAY, &mut rng);
+        assert_eq!(events[0], SpawnerEvent::WaveStarted(1));
+        assert_eq!(spawn_count(&events), 1);
+        assert_eq!(spawn_count(&spawner.update(1.0, 1, false, FAR_AWAY, &mut rng)), 1);
+        assert_eq!(spawn_count(&spawner.update(1.0, 2, false, FAR_AWAY, &mut rng)), 0);
+
+        // Nothing happens until the wave is cleared and the delay has passed
+        assert!(spawner.update(10.0, 1, false, FAR_AWAY, &mut rng).is_empty());
+        assert!(spawner.update(4.0, 0, false, FAR_AWAY, &mut rng).is_empty());
+        let events = spawner.update(1.0, 0, false, FAR_AWAY, &mut rng);
+        assert_eq!(events[0], SpawnerEvent::WaveStarted(2));
+        let Some(SpawnerEvent::Spawn { health_scale, .. }) = events.get(1) else {
+            panic!("expected a spawn, got {:?}", events);
+        };
+        assert_eq!(*health_scale, 1.5);
+        assert_eq!(spawner.wave_remaining, waves.wave_size(2) - 1);
+
+        // Clear wave 2 (the last one)
+        for _ in 0..2 {
+            spawner.update(1.0, 1, false, FAR_AWAY, &mut rng);
+        }
+        assert_eq!(spawner.update(0.0, 0, false, FAR_AWAY, &mut rng), vec![SpawnerEvent::WavesCompleted]);
+        assert!(spawner.finished);
+        assert!(spawner.update(100.0, 0, false, FAR_AWAY, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_definition_defaults_and_save_round_trip() {
+        let definition: SpawnerDefinition = serde_json::from_str(
+            r#"{ "id": "a", "enemy_type": "spitter", "area": { "x": 5, "y": 6 } }"#,
+        ).unwrap();
+        assert_eq!(definition.area, SpawnArea::point(5, 6));
+        assert_eq!(definition.max_alive, 3);
+        assert_eq!(definition.active_time, SpawnTime::Always);
+        assert!(definition.waves.is_none());
+
+        let mut spawner = Spawner::new(definition);
+        spawner.timer = 4.5;
+        let json = serde_json::to_string(&spawner).unwrap();
+        assert_eq!(serde_json::from_str::<Spawner>(&json).unwrap(), spawner);
+    }
+}
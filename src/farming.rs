@@ -0,0 +1,494 @@
+//! Farming system - tilled soil, crops, watering and harvesting
+//!
+//! The hoe turns Grass into Dirt. Each tilled tile becomes a `FarmPlot` that can
+//! hold a single `Crop`. Crops grow once per in-game day (see `GameClock`), but only
+//! if their plot was watered that day. All soil dries out again at midnight.
+//!
+//! # Lifecycle of a plot
+//!
+//! 1. **Till**: Hoe converts Grass → Dirt and registers an empty plot
+//! 2. **Plant**: Using seeds on an empty plot plants a stage-0 crop
+//! 3. **Water**: Watering can marks the soil wet so the crop can grow
+//! 4. **Grow**: Each watered day counts toward the next stage (`days_per_stage`)
+//! 5. **Harvest**: Clicking a mature crop rolls its loot table and clears the plot
+//!
+//! Empty, dry plots are "untended". After `SOIL_REVERT_SECONDS` they turn back
+//! into grass and the plot is removed.
+//!
+//! # Rendering
+//!
+//! Crops render on the ground layer (after tiles, before depth-sorted entities)
+//! from `assets/sprites/crops/crops.png`: one row per crop type, one 16×16 column
+//! per growth stage.
+
+use crate::loot::LootTable;
+use crate::save::{Saveable, SaveData, SaveError};
+use crate::tile::{TileId, WorldGrid};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// World tile size in pixels
+const TILE_SIZE: i32 = 32;
+
+/// Size of a single crop frame in the sprite sheet
+const CROP_FRAME_SIZE: i32 = 16;
+
+/// Seconds an empty, dry plot survives before reverting to grass
+pub const SOIL_REVERT_SECONDS: f32 = 120.0;
+
+/// Types of crops that can be planted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CropType {
+    Wheat,
+    Carrot,
+}
+
+impl CropType {
+    /// Crop ID used by seed items (`ItemProperties::Seed { crop_id }`)
+    pub fn id(&self) -> &'static str {
+        match self {
+            CropType::Wheat => "wheat",
+            CropType::Carrot => "carrot",
+        }
+    }
+
+    /// Looks up a crop type from its ID
+    pub fn from_id(id: &str) -> Option<CropType> {
+        match id {
+            "wheat" => Some(CropType::Wheat),
+            "carrot" => Some(CropType::Carrot),
+            _ => None,
+        }
+    }
+
+    /// Item ID of the seeds that plant this crop
+    pub fn seed_item_id(&self) -> &'static str {
+        match self {
+            CropType::Wheat => "wheat_seeds",
+            CropType::Carrot => "carrot_seeds",
+        }
+    }
+
+    /// Number of growth stages (the last stage is harvestable)
+    pub fn stage_count(&self) -> u32 {
+        4
+    }
+
+    /// Watered in-game days needed to advance one growth stage
+    pub fn days_per_stage(&self) -> u32 {
+        match self {
+            CropType::Wheat => 1,
+            CropType::Carrot => 2,
+        }
+    }
+
+    /// Items dropped when a mature crop is harvested
+    pub fn loot_table(&self) -> LootTable {
+        match self {
+            CropType::Wheat => LootTable::new()
+                .with_entry("wheat", 1, 2, 1.0)
+                .with_entry("wheat_seeds", 1, 2, 0.75),
+            CropType::Carrot => LootTable::new()
+                .with_entry("carrot", 1, 3, 1.0)
+                .with_entry("carrot_seeds", 1, 1, 0.5),
+        }
+    }
+
+    /// Row in the crop sprite sheet
+    fn sprite_row(&self) -> i32 {
+        match self {
+            CropType::Wheat => 0,
+            CropType::Carrot => 1,
+        }
+    }
+}
+
+/// A crop growing in a farm plot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Crop {
+    pub crop_type: CropType,
+    /// Current growth stage (0 = freshly planted seed)
+    pub stage: u32,
+    /// Watered days accumulated toward the next stage
+    #[serde(default)]
+    pub days_watered: u32,
+}
+
+impl Crop {
+    /// Creates a freshly planted crop
+    pub fn new(crop_type: CropType) -> Self {
+        Crop {
+            crop_type,
+            stage: 0,
+            days_watered: 0,
+        }
+    }
+
+    /// Returns true if the crop has reached its final stage
+    pub fn is_mature(&self) -> bool {
+        self.stage + 1 >= self.crop_type.stage_count()
+    }
+}
+
+/// A single tilled tile and whatever is growing in it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FarmPlot {
+    pub tile_x: i32,
+    pub tile_y: i32,
+    pub crop: Option<Crop>,
+    pub watered: bool,
+    /// Time spent empty and dry (reverts to grass at SOIL_REVERT_SECONDS)
+    pub untended_timer: f32,
+}
+
+impl FarmPlot {
+    /// Creates an empty, dry plot on the given tile
+    pub fn new(tile_x: i32, tile_y: i32) -> Self {
+        FarmPlot {
+            tile_x,
+            tile_y,
+            crop: None,
+            watered: false,
+            untended_timer: 0.0,
+        }
+    }
+
+    /// Advances the untended timer
+    ///
+    /// Returns true if the plot has been untended long enough to revert to grass.
+    fn update(&mut self, delta_time: f32) -> bool {
+        if self.crop.is_some() || self.watered {
+            self.untended_timer = 0.0;
+            return false;
+        }
+
+        self.untended_timer += delta_time;
+        self.untended_timer >= SOIL_REVERT_SECONDS
+    }
+
+    /// Grows the crop if the soil was watered today, then dries the soil
+    fn advance_day(&mut self) {
+        if let Some(crop) = &mut self.crop
+            && self.watered
+            && !crop.is_mature()
+        {
+            crop.days_watered += 1;
+            if crop.days_watered >= crop.crop_type.days_per_stage() {
+                crop.days_watered = 0;
+                crop.stage += 1;
+            }
+        }
+
+        self.watered = false;
+    }
+}
+
+/// Owns every farm plot in the world, keyed by tile coordinate
+pub struct Farm {
+    plots: HashMap<(i32, i32), FarmPlot>,
+}
+
+impl Farm {
+    /// Creates a farm with no tilled plots
+    pub fn new() -> Self {
+        Farm {
+            plots: HashMap::new(),
+        }
+    }
+
+    /// Registers a freshly tilled tile as an empty plot
+    ///
+    /// Does nothing if the tile is already a plot.
+    pub fn till(&mut self, tile_x: i32, tile_y: i32) {
+        self.plots
+            .entry((tile_x, tile_y))
+            .or_insert_with(|| FarmPlot::new(tile_x, tile_y));
+    }
+
+    /// Gets the plot on a tile, if any
+    pub fn get_plot(&self, tile_x: i32, tile_y: i32) -> Option<&FarmPlot> {
+        self.plots.get(&(tile_x, tile_y))
+    }
+
+    /// Plants a crop on an empty plot
+    ///
+    /// Returns false if the tile isn't tilled or already has a crop.
+    pub fn plant(&mut self, tile_x: i32, tile_y: i32, crop_type: CropType) -> bool {
+        match self.plots.get_mut(&(tile_x, tile_y)) {
+            Some(plot) if plot.crop.is_none() => {
+                plot.crop = Some(Crop::new(crop_type));
+                plot.untended_timer = 0.0;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Waters a plot so its crop can grow
+    ///
+    /// Returns false if the tile isn't tilled or is already wet.
+    pub fn water(&mut self, tile_x: i32, tile_y: i32) -> bool {
+        match self.plots.get_mut(&(tile_x, tile_y)) {
+            Some(plot) if !plot.watered => {
+                plot.watered = true;
+                plot.untended_timer = 0.0;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Harvests a mature crop, leaving the plot tilled and empty
+    ///
+    /// Returns the harvested crop type, or None if there was nothing ripe.
+    pub fn harvest(&mut self, tile_x: i32, tile_y: i32) -> Option<CropType> {
+        let plot = self.plots.get_mut(&(tile_x, tile_y))?;
+        if !plot.crop.as_ref().is_some_and(|crop| crop.is_mature()) {
+            return None;
+        }
+
+        let crop = plot.crop.take()?;
+        plot.untended_timer = 0.0;
+        Some(crop.crop_type)
+    }
+
+    /// Called once per in-game day (at midnight): grows watered crops and dries all soil
+    pub fn advance_day(&mut self) {
+        for plot in self.plots.values_mut() {
+            plot.advance_day();
+        }
+    }
+
+    /// Reverts untended plots to grass
+    ///
+    /// Plots whose tile is no longer Dirt (e.g. changed by other systems) are dropped.
+    ///
+    /// # Returns
+    /// Tile coordinates that were changed back to Grass (their render tiles need updating)
+    pub fn update(&mut self, delta_time: f32, world_grid: &mut WorldGrid) -> Vec<(i32, i32)> {
+        let mut reverted = Vec::new();
+
+        self.plots.retain(|&(tile_x, tile_y), plot| {
+            if world_grid.get_tile(tile_x, tile_y) != Some(TileId::Dirt) {
+                return false;
+            }
+
+            if plot.update(delta_time) {
+                world_grid.set_tile(tile_x, tile_y, TileId::Grass);
+                reverted.push((tile_x, tile_y));
+                return false;
+            }
+
+            true
+        });
+
+        reverted
+    }
+
+    /// Removes a plot (and its crop), e.g. when the map editor repaints the tile
+    pub fn remove_plot(&mut self, tile_x: i32, tile_y: i32) -> Option<FarmPlot> {
+        self.plots.remove(&(tile_x, tile_y))
+    }
+
+    /// Iterates over all plots (used for saving)
+    pub fn plots(&self) -> impl Iterator<Item = &FarmPlot> {
+        self.plots.values()
+    }
+
+    /// Inserts a plot (used for loading), replacing any plot on the same tile
+    pub fn insert_plot(&mut self, plot: FarmPlot) {
+        self.plots.insert((plot.tile_x, plot.tile_y), plot);
+    }
+
+    /// Renders wet soil and crops on the ground layer
+    ///
+    /// # Parameters
+    /// - `canvas`: SDL2 canvas to render to
+    /// - `crop_texture`: Crop sprite sheet (rows = crop types, columns = stages)
+    pub fn render(&self, canvas: &mut Canvas<Window>, crop_texture: &Texture) -> Result<(), String> {
+        for plot in self.plots.values() {
+            let dest_rect = Rect::new(
+                plot.tile_x * TILE_SIZE,
+                plot.tile_y * TILE_SIZE,
+                TILE_SIZE as u32,
+                TILE_SIZE as u32,
+            );
+
+            if plot.watered {
+                // Darken wet soil
+                canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+                canvas.set_draw_color(Color::RGBA(40, 25, 10, 110));
+                canvas.fill_rect(dest_rect)?;
+                canvas.set_blend_mode(sdl2::render::BlendMode::None);
+            }
+
+            if let Some(crop) = &plot.crop {
+                let src_rect = Rect::new(
+                    crop.stage as i32 * CROP_FRAME_SIZE,
+                    crop.crop_type.sprite_row() * CROP_FRAME_SIZE,
+                    CROP_FRAME_SIZE as u32,
+                    CROP_FRAME_SIZE as u32,
+                );
+                canvas.copy(crop_texture, Some(src_rect), Some(dest_rect))
+                    .map_err(|e| format!("Crop render error: {}", e))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Farm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ==============================================================================
+// Save/Load Implementation
+// ==============================================================================
+
+impl Saveable for FarmPlot {
+    fn to_save_data(&self) -> Result<SaveData, SaveError> {
+        Ok(SaveData {
+            data_type: "farm_plot".to_string(),
+            json_data: serde_json::to_string(self)?,
+        })
+    }
+
+    fn from_save_data(data: &SaveData) -> Result<Self, SaveError> {
+        if data.data_type != "farm_plot" {
+            return Err(SaveError::CorruptedData(format!(
+                "Expected farm_plot data, got {}",
+                data.data_type
+            )));
+        }
+
+        Ok(serde_json::from_str(&data.json_data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tilled_world(tile_x: i32, tile_y: i32) -> (Farm, WorldGrid) {
+        let mut grid = WorldGrid::new(4, 4, TileId::Grass);
+        grid.set_tile(tile_x, tile_y, TileId::Dirt);
+        let mut farm = Farm::new();
+        farm.till(tile_x, tile_y);
+        (farm, grid)
+    }
+
+    #[test]
+    fn test_crop_id_round_trip() {
+        for crop_type in [CropType::Wheat, CropType::Carrot] {
+            assert_eq!(CropType::from_id(crop_type.id()), Some(crop_type));
+        }
+        assert_eq!(CropType::from_id("pumpkin"), None);
+    }
+
+    #[test]
+    fn test_plant_requires_tilled_empty_plot() {
+        let (mut farm, _) = tilled_world(1, 1);
+
+        assert!(!farm.plant(0, 0, CropType::Wheat)); // Not tilled
+        assert!(farm.plant(1, 1, CropType::Wheat));
+        assert!(!farm.plant(1, 1, CropType::Carrot)); // Already planted
+    }
+
+    #[test]
+    fn test_crop_only_grows_when_watered() {
+        let (mut farm, _) = tilled_world(1, 1);
+        farm.plant(1, 1, CropType::Wheat);
+
+        farm.advance_day();
+        assert_eq!(farm.get_plot(1, 1).unwrap().crop.as_ref().unwrap().stage, 0);
+
+        assert!(farm.water(1, 1));
+        farm.advance_day();
+
+        let plot = farm.get_plot(1, 1).unwrap();
+        assert_eq!(plot.crop.as_ref().unwrap().stage, 1);
+        assert!(!plot.watered); // Soil dried overnight
+    }
+
+    #[test]
+    fn test_slow_crop_needs_several_watered_days() {
+        let (mut farm, _) = tilled_world(1, 1);
+        farm.plant(1, 1, CropType::Carrot);
+
+        for _ in 0..CropType::Carrot.days_per_stage() - 1 {
+            farm.water(1, 1);
+            farm.advance_day();
+        }
+        assert_eq!(farm.get_plot(1, 1).unwrap().crop.as_ref().unwrap().stage, 0);
+
+        farm.water(1, 1);
+        farm.advance_day();
+        assert_eq!(farm.get_plot(1, 1).unwrap().crop.as_ref().unwrap().stage, 1);
+    }
+
+    #[test]
+    fn test_harvest_only_when_mature() {
+        let (mut farm, _) = tilled_world(2, 2);
+        farm.plant(2, 2, CropType::Wheat);
+        assert_eq!(farm.harvest(2, 2), None);
+
+        for _ in 1..CropType::Wheat.stage_count() {
+            farm.water(2, 2);
+            farm.advance_day();
+        }
+
+        assert!(farm.get_plot(2, 2).unwrap().crop.as_ref().unwrap().is_mature());
+        assert_eq!(farm.harvest(2, 2), Some(CropType::Wheat));
+        assert!(farm.get_plot(2, 2).unwrap().crop.is_none());
+    }
+
+    #[test]
+    fn test_untended_plot_reverts_to_grass() {
+        let (mut farm, mut grid) = tilled_world(0, 3);
+
+        let reverted = farm.update(SOIL_REVERT_SECONDS - 1.0, &mut grid);
+        assert!(reverted.is_empty());
+
+        let reverted = farm.update(1.0, &mut grid);
+        assert_eq!(reverted, vec![(0, 3)]);
+        assert_eq!(grid.get_tile(0, 3), Some(TileId::Grass));
+        assert!(farm.get_plot(0, 3).is_none());
+    }
+
+    #[test]
+    fn test_planted_plot_never_reverts() {
+        let (mut farm, mut grid) = tilled_world(1, 2);
+        farm.plant(1, 2, CropType::Wheat);
+
+        let reverted = farm.update(SOIL_REVERT_SECONDS * 2.0, &mut grid);
+        assert!(reverted.is_empty());
+        assert_eq!(grid.get_tile(1, 2), Some(TileId::Dirt));
+    }
+
+    #[test]
+    fn test_plot_dropped_when_tile_changes() {
+        let (mut farm, mut grid) = tilled_world(3, 3);
+        grid.set_tile(3, 3, TileId::Grass);
+
+        farm.update(0.1, &mut grid);
+        assert!(farm.get_plot(3, 3).is_none());
+    }
+
+    #[test]
+    fn test_plot_save_round_trip() {
+        let mut plot = FarmPlot::new(4, 5);
+        plot.crop = Some(Crop { crop_type: CropType::Wheat, stage: 2, days_watered: 1 });
+        plot.watered = true;
+
+        let data = plot.to_save_data().unwrap();
+        let loaded = FarmPlot::from_save_data(&data).unwrap();
+        assert_eq!(loaded, plot);
+    }
+}
@@ -0,0 +1,626 @@
+// Map editor - in-game editing of the active zone's layout
+//
+// Toggled with F4 (alongside the F3 debug menu and G grid overlay). While the
+// editor is open the world is frozen and the mouse edits the active zone:
+// - Left click uses the selected tool (paint/fill tiles, place objects)
+// - Right click deletes the spawner, static object or pyramid under the cursor
+//
+// Every edit is recorded as an `EditAction` so it can be undone and redone.
+// A paint stroke (mouse down -> drag -> release) is a single action.
+//
+// Ctrl+S exports the zone as a map file (see map.rs) that `zone::create_zone`
+// loads in place of the built-in layout.
+
+use crate::collision::StaticCollidable;
+use crate::map::{MapObject, MapPyramid, SpawnPoint};
+use crate::pyramid::PyramidRegistry;
+use crate::text::draw_simple_text;
+use crate::the_entity::EntityType;
+use crate::tile::{TileId, WorldGrid};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use super::zone::create_pyramid;
+use super::GameWorld;
+
+/// Selectable brush widths (in tiles, square brush centered on the cursor)
+pub const BRUSH_SIZES: [i32; 3] = [1, 3, 5];
+
+/// Oldest edits are dropped past this many undo steps
+const MAX_UNDO_STEPS: usize = 100;
+
+/// Size of a tile in world pixels
+const TILE_SIZE: i32 = 32;
+
+/// Size of a pyramid on screen (32px sprite at 2x scale)
+const PYRAMID_SIZE: i32 = 64;
+
+/// Right-clicks within this many pixels of a spawn point delete it
+const SPAWN_POINT_PICK_RADIUS: i32 = 10;
+
+/// Editing tools, selected with the number keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorTool {
+    /// Paint the selected tile with the current brush (drag to paint)
+    Paint,
+    /// Flood-fill the connected area of matching tiles
+    Fill,
+    /// Place a pyramid of the selected type
+    Pyramid,
+    /// Place a one-tile solid block
+    StaticObject,
+    /// Place a slime spawn point
+    Spawner,
+    /// Move the player's new-game start position
+    PlayerSpawn,
+}
+
+impl EditorTool {
+    /// Tools in number-key order (1-6)
+    pub fn all() -> [EditorTool; 6] {
+        [
+            EditorTool::Paint,
+            EditorTool::Fill,
+            EditorTool::Pyramid,
+            EditorTool::StaticObject,
+            EditorTool::Spawner,
+            EditorTool::PlayerSpawn,
+        ]
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            EditorTool::Paint => "PAINT",
+            EditorTool::Fill => "FILL",
+            EditorTool::Pyramid => "PYRAMID",
+            EditorTool::StaticObject => "BLOCK",
+            EditorTool::Spawner => "SPAWNER",
+            EditorTool::PlayerSpawn => "PLAYER START",
+        }
+    }
+}
+
+/// Pyramid buff types, in the order T cycles through them
+const PYRAMID_TYPES: [EntityType; 7] = EntityType::ALL;
+
+/// A single tile edit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TileChange {
+    pub x: i32,
+    pub y: i32,
+    pub before: TileId,
+    pub after: TileId,
+}
+
+/// One undoable edit
+#[derive(Debug, Clone, PartialEq)]
+pub enum EditAction {
+    Tiles(Vec<TileChange>),
+    AddPyramid(MapPyramid),
+    RemovePyramid(MapPyramid),
+    AddStaticObject(MapObject),
+    RemoveStaticObject(MapObject),
+    AddSpawnPoint(SpawnPoint),
+    RemoveSpawnPoint(SpawnPoint),
+    SetPlayerSpawn {
+        before: Option<SpawnPoint>,
+        after: Option<SpawnPoint>,
+    },
+}
+
+impl EditAction {
+    /// The action that undoes this one
+    pub fn inverse(&self) -> EditAction {
+        match self {
+            EditAction::Tiles(changes) => EditAction::Tiles(
+                changes.iter().rev()
+                    .map(|change| TileChange { before: change.after, after: change.before, ..*change })
+                    .collect(),
+            ),
+            EditAction::AddPyramid(pyramid) => EditAction::RemovePyramid(pyramid.clone()),
+            EditAction::RemovePyramid(pyramid) => EditAction::AddPyramid(pyramid.clone()),
+            EditAction::AddStaticObject(obj) => EditAction::RemoveStaticObject(*obj),
+            EditAction::RemoveStaticObject(obj) => EditAction::AddStaticObject(*obj),
+            EditAction::AddSpawnPoint(point) => EditAction::RemoveSpawnPoint(*point),
+            EditAction::RemoveSpawnPoint(point) => EditAction::AddSpawnPoint(*point),
+            EditAction::SetPlayerSpawn { before, after } => EditAction::SetPlayerSpawn {
+                before: *after,
+                after: *before,
+            },
+        }
+    }
+}
+
+/// Undo/redo stacks
+///
+/// `undo()` and `redo()` return the action to *apply* to the world
+/// (the inverse of the recorded action when undoing).
+#[derive(Debug, Default)]
+pub struct EditHistory {
+    undo_stack: Vec<EditAction>,
+    redo_stack: Vec<EditAction>,
+}
+
+impl EditHistory {
+    /// Records a new edit (clears the redo stack)
+    pub fn record(&mut self, action: EditAction) {
+        self.undo_stack.push(action);
+        if self.undo_stack.len() > MAX_UNDO_STEPS {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    pub fn undo(&mut self) -> Option<EditAction> {
+        let action = self.undo_stack.pop()?;
+        let inverse = action.inverse();
+        self.redo_stack.push(action);
+        Some(inverse)
+    }
+
+    pub fn redo(&mut self) -> Option<EditAction> {
+        let action = self.redo_stack.pop()?;
+        self.undo_stack.push(action.clone());
+        Some(action)
+    }
+
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+}
+
+/// Tiles covered by a square brush centered on (center_x, center_y)
+pub fn brush_tiles(center_x: i32, center_y: i32, size: i32) -> Vec<(i32, i32)> {
+    let radius = size / 2;
+    let mut tiles = Vec::new();
+    for y in (center_y - radius)..=(center_y + radius) {
+        for x in (center_x - radius)..=(center_x + radius) {
+            tiles.push((x, y));
+        }
+    }
+    tiles
+}
+
+/// Changes needed to flood-fill the 4-connected region of matching tiles at (x, y)
+///
+/// Returns no changes when the start is out of bounds or already the fill tile.
+pub fn flood_fill(world_grid: &WorldGrid, x: i32, y: i32, fill: TileId) -> Vec<TileChange> {
+    let Some(target) = world_grid.get_tile(x, y) else {
+        return Vec::new();
+    };
+    if target == fill {
+        return Vec::new();
+    }
+
+    let mut visited = vec![vec![false; world_grid.width]; world_grid.height];
+    let mut stack = vec![(x, y)];
+    let mut changes = Vec::new();
+
+    while let Some((cx, cy)) = stack.pop() {
+        if world_grid.get_tile(cx, cy) != Some(target) || visited[cy as usize][cx as usize] {
+            continue;
+        }
+        visited[cy as usize][cx as usize] = true;
+        changes.push(TileChange { x: cx, y: cy, before: target, after: fill });
+
+        stack.extend([(cx + 1, cy), (cx - 1, cy), (cx, cy + 1), (cx, cy - 1)]);
+    }
+
+    changes
+}
+
+/// Map editor state (tool selection, brush, history, stroke in progress)
+pub struct MapEditor {
+    pub is_open: bool,
+    pub tool: EditorTool,
+    pub tile: TileId,
+    pub pyramid_type: EntityType,
+    /// Index into BRUSH_SIZES
+    brush_index: usize,
+    history: EditHistory,
+    /// Tile changes of the paint stroke in progress (None when not painting)
+    stroke: Option<Vec<TileChange>>,
+}
+
+impl MapEditor {
+    pub fn new() -> Self {
+        MapEditor {
+            is_open: false,
+            tool: EditorTool::Paint,
+            tile: TileId::Grass,
+            pyramid_type: EntityType::Attack,
+            brush_index: 0,
+            history: EditHistory::default(),
+            stroke: None,
+        }
+    }
+
+    /// Opens or closes the editor, returning the new state
+    ///
+    /// History is kept per editing session; closing the editor clears it.
+    pub fn toggle(&mut self) -> bool {
+        self.is_open = !self.is_open;
+        if !self.is_open {
+            self.stroke = None;
+            self.history.clear();
+        }
+        self.is_open
+    }
+
+    pub fn brush_size(&self) -> i32 {
+        BRUSH_SIZES[self.brush_index]
+    }
+
+    /// Select a tool by its number-key index (0-based)
+    pub fn select_tool(&mut self, index: usize) {
+        if let Some(tool) = EditorTool::all().get(index) {
+            self.tool = *tool;
+        }
+    }
+
+    /// Cycles the selected tile (paint/fill) or pyramid type (pyramid tool)
+    pub fn cycle_variant(&mut self) {
+        match self.tool {
+            EditorTool::Pyramid => {
+                let index = PYRAMID_TYPES.iter().position(|t| *t == self.pyramid_type).unwrap_or(0);
+                self.pyramid_type = PYRAMID_TYPES[(index + 1) % PYRAMID_TYPES.len()];
+            }
+            _ => {
+                let tiles = TileId::all();
+                let index = tiles.iter().position(|t| *t == self.tile).unwrap_or(0);
+                self.tile = tiles[(index + 1) % tiles.len()];
+            }
+        }
+    }
+
+    /// Steps the brush size up (delta > 0) or down (delta < 0)
+    pub fn change_brush_size(&mut self, delta: i32) {
+        let index = (self.brush_index as i32 + delta).clamp(0, BRUSH_SIZES.len() as i32 - 1);
+        self.brush_index = index as usize;
+    }
+
+    /// Left click at a world position: use the selected tool
+    pub fn primary_click<'a>(&mut self, world: &mut GameWorld<'a>, x: i32, y: i32, pyramids: &PyramidRegistry, entity_texture: &'a Texture<'a>) {
+        let (tile_x, tile_y) = (x.div_euclid(TILE_SIZE), y.div_euclid(TILE_SIZE));
+        let (snap_x, snap_y) = (tile_x * TILE_SIZE, tile_y * TILE_SIZE);
+
+        let action = match self.tool {
+            EditorTool::Paint => {
+                self.stroke = Some(Vec::new());
+                self.drag(world, x, y);
+                return;
+            }
+            EditorTool::Fill => {
+                let changes = flood_fill(&world.world_grid, tile_x, tile_y, self.tile);
+                if changes.is_empty() {
+                    return;
+                }
+                EditAction::Tiles(changes)
+            }
+            EditorTool::Pyramid => EditAction::AddPyramid(MapPyramid {
+                x: snap_x,
+                y: snap_y,
+                entity_type: self.pyramid_type,
+                link: None,
+            }),
+            EditorTool::StaticObject => {
+                let occupied = world.static_objects.iter().any(|obj| obj.x == snap_x && obj.y == snap_y);
+                if occupied {
+                    return;
+                }
+                EditAction::AddStaticObject(MapObject {
+                    x: snap_x,
+                    y: snap_y,
+                    width: TILE_SIZE as u32,
+                    height: TILE_SIZE as u32,
+                })
+            }
+            EditorTool::Spawner => EditAction::AddSpawnPoint(SpawnPoint::new(x, y)),
+            EditorTool::PlayerSpawn => EditAction::SetPlayerSpawn {
+                before: world.player_spawn,
+                after: Some(SpawnPoint::new(x, y)),
+            },
+        };
+
+        apply_action(world, &action, pyramids, entity_texture);
+        self.history.record(action);
+    }
+
+    /// Mouse moved while the left button is held: continue the paint stroke
+    pub fn drag(&mut self, world: &mut GameWorld, x: i32, y: i32) {
+        let brush_size = self.brush_size();
+        let Some(stroke) = self.stroke.as_mut() else {
+            return;
+        };
+
+        let (tile_x, tile_y) = (x.div_euclid(TILE_SIZE), y.div_euclid(TILE_SIZE));
+        for (bx, by) in brush_tiles(tile_x, tile_y, brush_size) {
+            let Some(before) = world.world_grid.get_tile(bx, by) else {
+                continue;
+            };
+            if before == self.tile {
+                continue;
+            }
+            world.set_tile(bx, by, self.tile);
+            stroke.push(TileChange { x: bx, y: by, before, after: self.tile });
+        }
+    }
+
+    /// Left button released: commit the paint stroke as one undo step
+    pub fn end_stroke(&mut self) {
+        if let Some(stroke) = self.stroke.take()
+            && !stroke.is_empty()
+        {
+            self.history.record(EditAction::Tiles(stroke));
+        }
+    }
+
+    /// Right click: delete the topmost spawner, static object or pyramid under the cursor
+    pub fn delete_at<'a>(&mut self, world: &mut GameWorld<'a>, x: i32, y: i32, pyramids: &PyramidRegistry, entity_texture: &'a Texture<'a>) {
+        let spawn_point = world.spawn_points.iter().find(|point| {
+            (point.x - x).abs() <= SPAWN_POINT_PICK_RADIUS && (point.y - y).abs() <= SPAWN_POINT_PICK_RADIUS
+        });
+        let static_object = world.static_objects.iter().find(|obj| obj.get_bounds().contains_point((x, y)));
+        let pyramid = world.entities.iter().find(|entity| {
+            Rect::new(entity.x, entity.y, PYRAMID_SIZE as u32, PYRAMID_SIZE as u32).contains_point((x, y))
+        });
+
+        let action = if let Some(point) = spawn_point {
+            EditAction::RemoveSpawnPoint(*point)
+        } else if let Some(obj) = static_object {
+            EditAction::RemoveStaticObject(MapObject::from_static_object(obj))
+        } else if let Some(entity) = pyramid {
+            EditAction::RemovePyramid(MapPyramid {
+                x: entity.x,
+                y: entity.y,
+                entity_type: entity.entity_type,
+                link: entity.link.clone(),
+            })
+        } else {
+            return;
+        };
+
+        apply_action(world, &action, pyramids, entity_texture);
+        self.history.record(action);
+    }
+
+    /// Undo the most recent edit
+    pub fn undo<'a>(&mut self, world: &mut GameWorld<'a>, pyramids: &PyramidRegistry, entity_texture: &'a Texture<'a>) {
+        self.end_stroke();
+        if let Some(action) = self.history.undo() {
+            apply_action(world, &action, pyramids, entity_texture);
+        }
+    }
+
+    /// Redo the most recently undone edit
+    pub fn redo<'a>(&mut self, world: &mut GameWorld<'a>, pyramids: &PyramidRegistry, entity_texture: &'a Texture<'a>) {
+        self.end_stroke();
+        if let Some(action) = self.history.redo() {
+            apply_action(world, &action, pyramids, entity_texture);
+        }
+    }
+
+    /// Status line shown at the top of the screen, e.g. "EDITOR  PAINT DIRT  BRUSH 3"
+    pub fn status_text(&self) -> String {
+        match self.tool {
+            EditorTool::Paint => format!("EDITOR  PAINT {}  BRUSH {}", self.tile.to_string().to_uppercase(), self.brush_size()),
+            EditorTool::Fill => format!("EDITOR  FILL {}", self.tile.to_string().to_uppercase()),
+            EditorTool::Pyramid => {
+                let pyramid_name = format!("{:?}", self.pyramid_type).to_uppercase();
+                format!("EDITOR  PYRAMID {}", pyramid_name)
+            }
+            tool => format!("EDITOR  {}", tool.name()),
+        }
+    }
+
+    /// Draws editor-only markers, the brush preview and the status line
+    pub fn render(&self, canvas: &mut Canvas<Window>, world: &GameWorld, mouse_x: i32, mouse_y: i32) -> Result<(), String> {
+        canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+
+        // Spawn points: red squares
+        canvas.set_draw_color(Color::RGBA(220, 40, 40, 220));
+        for point in &world.spawn_points {
+            canvas.fill_rect(Rect::new(point.x - 4, point.y - 4, 8, 8))?;
+        }
+
+        // Player start: cyan outline
+        if let Some(spawn) = world.player_spawn {
+            canvas.set_draw_color(Color::RGBA(40, 220, 220, 220));
+            canvas.draw_rect(Rect::new(spawn.x - 6, spawn.y - 6, 12, 12))?;
+        }
+
+        // Brush / placement preview under the cursor
+        let (tile_x, tile_y) = (mouse_x.div_euclid(TILE_SIZE), mouse_y.div_euclid(TILE_SIZE));
+        let preview_tiles = match self.tool {
+            EditorTool::Paint => self.brush_size(),
+            EditorTool::Pyramid => PYRAMID_SIZE / TILE_SIZE,
+            _ => 1,
+        };
+        let preview = if self.tool == EditorTool::Paint {
+            let radius = preview_tiles / 2;
+            Rect::new(
+                (tile_x - radius) * TILE_SIZE,
+                (tile_y - radius) * TILE_SIZE,
+                (preview_tiles * TILE_SIZE) as u32,
+                (preview_tiles * TILE_SIZE) as u32,
+            )
+        } else {
+            Rect::new(
+                tile_x * TILE_SIZE,
+                tile_y * TILE_SIZE,
+                (preview_tiles * TILE_SIZE) as u32,
+                (preview_tiles * TILE_SIZE) as u32,
+            )
+        };
+        canvas.set_draw_color(Color::RGBA(255, 255, 255, 180));
+        canvas.draw_rect(preview)?;
+
+        // Status line
+        let status = self.status_text();
+        canvas.set_draw_color(Color::RGBA(0, 0, 0, 170));
+        canvas.fill_rect(Rect::new(0, 0, (status.len() * 12 + 16) as u32, 26))?;
+        canvas.set_blend_mode(sdl2::render::BlendMode::None);
+        draw_simple_text(canvas, &status, 8, 6, Color::RGB(255, 230, 120), 2)?;
+
+        Ok(())
+    }
+}
+
+impl Default for MapEditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Applies an edit to the world
+///
+/// Removals match by exact position (and type for pyramids), so undoing an
+/// edit finds the object the original edit created.
+fn apply_action<'a>(world: &mut GameWorld<'a>, action: &EditAction, pyramids: &PyramidRegistry, entity_texture: &'a Texture<'a>) {
+    match action {
+        EditAction::Tiles(changes) => {
+            for change in changes {
+                world.set_tile(change.x, change.y, change.after);
+            }
+        }
+        EditAction::AddPyramid(pyramid) => {
+            let id = world.entities.iter().map(|entity| entity.id + 1).max().unwrap_or(0);
+            let mut entity = create_pyramid(id, pyramid.x, pyramid.y, pyramids.get(pyramid.entity_type), entity_texture);
+            entity.link = pyramid.link.clone();
+            world.entities.push(entity);
+        }
+        EditAction::RemovePyramid(pyramid) => {
+            if let Some(index) = world.entities.iter().position(|entity| {
+                entity.x == pyramid.x && entity.y == pyramid.y && entity.entity_type == pyramid.entity_type
+            }) {
+                world.entities.remove(index);
+            }
+        }
+        EditAction::AddStaticObject(obj) => {
+            world.static_objects.push(obj.to_static_object());
+        }
+        EditAction::RemoveStaticObject(obj) => {
+            if let Some(index) = world.static_objects.iter().position(|existing| MapObject::from_static_object(existing) == *obj) {
+                world.static_objects.remove(index);
+            }
+        }
+        EditAction::AddSpawnPoint(point) => {
+            world.spawn_points.push(*point);
+        }
+        EditAction::RemoveSpawnPoint(point) => {
+            if let Some(index) = world.spawn_points.iter().position(|existing| existing == point) {
+                world.spawn_points.remove(index);
+            }
+        }
+        EditAction::SetPlayerSpawn { after, .. } => {
+            world.player_spawn = *after;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brush_tiles() {
+        assert_eq!(brush_tiles(4, 4, 1), vec![(4, 4)]);
+
+        let tiles = brush_tiles(4, 4, 3);
+        assert_eq!(tiles.len(), 9);
+        assert!(tiles.contains(&(3, 3)));
+        assert!(tiles.contains(&(5, 5)));
+
+        assert_eq!(brush_tiles(0, 0, 5).len(), 25);
+    }
+
+    #[test]
+    fn test_flood_fill_stays_in_region() {
+        // Dirt wall down column 2 splits the grass into two regions
+        let mut grid = WorldGrid::new(5, 3, TileId::Grass);
+        for y in 0..3 {
+            grid.set_tile(2, y, TileId::Dirt);
+        }
+
+        let changes = flood_fill(&grid, 0, 0, TileId::Dirt);
+        assert_eq!(changes.len(), 6);
+        assert!(changes.iter().all(|change| change.x < 2));
+        assert!(changes.iter().all(|change| change.before == TileId::Grass && change.after == TileId::Dirt));
+    }
+
+    #[test]
+    fn test_flood_fill_noop_cases() {
+        let grid = WorldGrid::new(3, 3, TileId::Grass);
+        assert!(flood_fill(&grid, 1, 1, TileId::Grass).is_empty());
+        assert!(flood_fill(&grid, -1, 0, TileId::Dirt).is_empty());
+        assert!(flood_fill(&grid, 3, 0, TileId::Dirt).is_empty());
+    }
+
+    #[test]
+    fn test_inverse_reverses_tile_changes() {
+        let action = EditAction::Tiles(vec![
+            TileChange { x: 0, y: 0, before: TileId::Grass, after: TileId::Dirt },
+            TileChange { x: 1, y: 0, before: TileId::Dirt, after: TileId::Grass },
+        ]);
+        let EditAction::Tiles(inverse) = action.inverse() else {
+            panic!("inverse of Tiles should be Tiles");
+        };
+        assert_eq!(inverse[0], TileChange { x: 1, y: 0, before: TileId::Grass, after: TileId::Dirt });
+        assert_eq!(inverse[1], TileChange { x: 0, y: 0, before: TileId::Dirt, after: TileId::Grass });
+        assert_eq!(action.inverse().inverse(), action);
+    }
+
+    #[test]
+    fn test_history_undo_redo() {
+        let mut history = EditHistory::default();
+        let place = EditAction::AddSpawnPoint(SpawnPoint::new(10, 10));
+        history.record(place.clone());
+
+        assert_eq!(history.undo(), Some(EditAction::RemoveSpawnPoint(SpawnPoint::new(10, 10))));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some(place.clone()));
+        assert_eq!(history.redo(), None);
+
+        // A new edit after undoing discards the redo stack
+        history.undo();
+        history.record(EditAction::AddSpawnPoint(SpawnPoint::new(20, 20)));
+        assert_eq!(history.redo(), None);
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut history = EditHistory::default();
+        for i in 0..(MAX_UNDO_STEPS + 10) {
+            history.record(EditAction::AddSpawnPoint(SpawnPoint::new(i as i32, 0)));
+        }
+        let mut undone = 0;
+        while history.undo().is_some() {
+            undone += 1;
+        }
+        assert_eq!(undone, MAX_UNDO_STEPS);
+    }
+
+    #[test]
+    fn test_cycle_variant_and_brush() {
+        let mut editor = MapEditor::new();
+        editor.cycle_variant();
+        assert_eq!(editor.tile, TileId::Dirt);
+        editor.cycle_variant();
+        assert_eq!(editor.tile, TileId::Grass);
+
+        editor.select_tool(2);
+        assert_eq!(editor.tool, EditorTool::Pyramid);
+        editor.cycle_variant();
+        assert_eq!(editor.pyramid_type, EntityType::Defense);
+
+        editor.change_brush_size(1);
+        assert_eq!(editor.brush_size(), 3);
+        editor.change_brush_size(10);
+        assert_eq!(editor.brush_size(), 5);
+        editor.change_brush_size(-10);
+        assert_eq!(editor.brush_size(), 1);
+    }
+}
@@ -0,0 +1,152 @@
+// Boss encounters - the arena locked around a boss fight
+//
+// While a boss is alive in the active zone, four temporary walls box the
+// player and the boss in. The walls are ordinary static colliders, but they
+// live here rather than in `GameWorld::static_objects` so they're never saved
+// with the zone layout or picked up by the map editor. The game adds them to
+// collisions and navigation for as long as the encounter lasts.
+
+use crate::collision::StaticObject;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{BlendMode, Canvas};
+use sdl2::video::Window;
+
+/// Thickness of the arena walls in pixels
+const WALL_THICKNESS: u32 = 16;
+/// Room left between the fighters and the walls when an arena has to stretch to fit them
+const FIGHTER_MARGIN: u32 = 16;
+
+/// An active boss fight and the walls locking it in
+pub struct BossEncounter {
+    /// The area the fight is held in (inside the walls)
+    pub arena: Rect,
+    pub walls: Vec<StaticObject>,
+}
+
+impl BossEncounter {
+    /// Locks an arena of the given size centered on `center`, shifted to stay inside `bounds`
+    ///
+    /// An arena bigger than `bounds` shrinks to fit it.
+    pub fn new(center: (i32, i32), width: u32, height: u32, bounds: Rect) -> Self {
+        let (width, height) = (width.min(bounds.width()), height.min(bounds.height()));
+        let x = (center.0 - width as i32 / 2).clamp(bounds.left(), bounds.right() - width as i32);
+        let y = (center.1 - height as i32 / 2).clamp(bounds.top(), bounds.bottom() - height as i32);
+        let arena = Rect::new(x, y, width, height);
+
+        BossEncounter {
+            arena,
+            walls: arena_walls(arena),
+        }
+    }
+
+    /// Locks an arena of at least the given size around every fighter's bounds
+    ///
+    /// The arena is centered on the fighters and stretches to take them all
+    /// in when they're too far apart for the usual size (still kept inside
+    /// `bounds`, which the fighters are in too).
+    pub fn enclosing(fighters: &[Rect], width: u32, height: u32, bounds: Rect) -> Self {
+        let Some(area) = fighters.iter().copied().reduce(|area, fighter| area.union(fighter)) else {
+            return Self::new(bounds.center().into(), width, height, bounds);
+        };
+        let width = width.max(area.width() + 2 * FIGHTER_MARGIN);
+        let height = height.max(area.height() + 2 * FIGHTER_MARGIN);
+        Self::new(area.center().into(), width, height, bounds)
+    }
+
+    /// Draws the walls as a glowing barrier
+    pub fn render(&self, canvas: &mut Canvas<Window>) -> Result<(), String> {
+        canvas.set_blend_mode(BlendMode::Blend);
+        for wall in &self.walls {
+            let bounds = Rect::new(wall.x, wall.y, wall.width, wall.height);
+            canvas.set_draw_color(Color::RGBA(150, 60, 220, 110));
+            canvas.fill_rect(bounds)?;
+            canvas.set_draw_color(Color::RGBA(220, 170, 255, 200));
+            canvas.draw_rect(bounds)?;
+        }
+        canvas.set_blend_mode(BlendMode::None);
+        Ok(())
+    }
+}
+
+/// Four walls just outside the arena, overlapping at the corners
+fn arena_walls(arena: Rect) -> Vec<StaticObject> {
+    let thickness = WALL_THICKNESS as i32;
+    let outer_width = arena.width() + 2 * WALL_THICKNESS;
+    vec![
+        StaticObject::new(arena.left() - thickness, arena.top() - thickness, outer_width, WALL_THICKNESS),
+        StaticObject::new(arena.left() - thickness, arena.bottom(), outer_width, WALL_THICKNESS),
+        StaticObject::new(arena.left() - thickness, arena.top(), WALL_THICKNESS, arena.height()),
+        StaticObject::new(arena.right(), arena.top(), WALL_THICKNESS, arena.height()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collision::StaticCollidable;
+
+    #[test]
+    fn test_arena_stays_in_bounds_and_is_walled_in() {
+        let bounds = Rect::new(0, 0, 640, 360);
+
+        // Centered on the player when there's room
+        let encounter = BossEncounter::new((320, 180), 300, 200, bounds);
+        assert_eq!(encounter.arena, Rect::new(170, 80, 300, 200));
+
+        // Pushed back inside near an edge, and shrunk when too big
+        let encounter = BossEncounter::new((20, 350), 300, 200, bounds);
+        assert_eq!(encounter.arena, Rect::new(0, 160, 300, 200));
+        let encounter = BossEncounter::new((320, 180), 300, 500, bounds);
+        assert_eq!(encounter.arena, Rect::new(170, 0, 300, 360));
+
+        // The walls touch the arena on every side without covering any of it
+        let arena = BossEncounter::new((320, 180), 300, 200, bounds).arena;
+        let walls = arena_walls(arena);
+        assert!(walls.iter().all(|wall| !wall.get_bounds().has_intersection(arena)));
+        let grown = Rect::new(arena.x() - 1, arena.y() - 1, arena.width() + 2, arena.height() + 2);
+        assert!(walls.iter().all(|wall| wall.get_bounds().has_intersection(grown)));
+    }
+
+    #[test]
+    fn test_arena_stays_on_screen_in_the_meadow() {
+        use crate::game::world::play_area;
+        use crate::game::zone::MEADOW_GRID_SIZE;
+        use crate::tile::{TileId, WorldGrid};
+
+        // The meadow's grid is bigger than the walled-off screen
+        let bounds = play_area(&WorldGrid::new(MEADOW_GRID_SIZE.0, MEADOW_GRID_SIZE.1, TileId::Grass));
+        let screen = Rect::new(0, 0, crate::GAME_WIDTH, crate::GAME_HEIGHT);
+        assert_eq!(bounds, screen);
+
+        // A Slime King sized arena around a player in the bottom-right corner
+        let encounter = BossEncounter::new((620, 340), 352, 256, bounds);
+        assert!(screen.contains_rect(encounter.arena));
+        assert!(encounter.arena.contains_point((620, 340)));
+
+        let boss = Rect::new(560, 300, 48, 48);
+        let encounter = BossEncounter::enclosing(&[Rect::new(40, 40, 16, 16), boss], 352, 256, bounds);
+        assert!(screen.contains_rect(encounter.arena));
+        assert!(encounter.arena.contains_rect(boss));
+    }
+
+    #[test]
+    fn test_arena_takes_in_a_boss_outside_the_players_arena() {
+        let bounds = Rect::new(0, 0, crate::GAME_WIDTH, crate::GAME_HEIGHT);
+        let player = Rect::new(60, 60, 16, 16);
+        let boss = Rect::new(540, 280, 48, 48);
+
+        // An arena around the player alone would shut the boss out
+        let around_player = BossEncounter::new(player.center().into(), 300, 200, bounds).arena;
+        assert!(!around_player.contains_rect(boss));
+
+        let arena = BossEncounter::enclosing(&[player, boss], 300, 200, bounds).arena;
+        assert!(arena.contains_rect(player) && arena.contains_rect(boss));
+        assert!(bounds.contains_rect(arena));
+
+        // Fighters close together get the usual size
+        let arena = BossEncounter::enclosing(&[player, Rect::new(100, 80, 48, 48)], 300, 200, bounds).arena;
+        assert_eq!((arena.width(), arena.height()), (300, 200));
+        assert!(arena.contains_point(player.center()));
+    }
+}
@@ -4,6 +4,9 @@
 // - world.rs: GameWorld struct and entity management
 // - systems.rs: Systems configuration and helper systems
 // - types.rs: Shared enums and helper structs
+// - zone.rs: Zones (meadow, cave), portals and zone transitions
+// - editor.rs: In-game map editor (F4) with undo/redo and map export
+// - encounter.rs: Boss encounters and the walls locking their arena
 // - ui_manager.rs: UI management struct
 // - constructors.rs: Game initialization (new/load)
 // - events.rs: Input handling and event processing
@@ -19,6 +22,9 @@ pub mod constructors;
 pub mod events;
 pub mod update;
 pub mod rendering;
+pub mod zone;
+pub mod editor;
+pub mod encounter;
 
 // Re-export types for convenience
 pub use types::*;
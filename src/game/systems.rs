@@ -4,7 +4,12 @@
 // and helper systems that configure gameplay but aren't entities.
 
 use crate::animation::AnimationConfig;
-use crate::collision::StaticObject;
+use crate::clock::GameClock;
+use crate::collision::{CollisionMatrix, StaticObject};
+use crate::enemy::EnemyRegistry;
+use crate::pyramid::PyramidRegistry;
+use crate::rng::SimpleRng;
+use crate::weapon::WeaponRegistry;
 use std::time::Instant;
 
 use super::DebugConfig;
@@ -17,21 +22,32 @@ const GAME_HEIGHT: u32 = 360;
 /// This struct contains things that configure gameplay but aren't entities
 pub struct Systems {
     pub player_config: AnimationConfig,
-    pub slime_config: AnimationConfig,
-    pub punch_config: AnimationConfig,
+    pub enemy_registry: EnemyRegistry,
+    /// Attack profiles for the fists and each weapon
+    pub weapon_registry: WeaponRegistry,
+    /// Tuning and buffs for each pyramid type
+    pub pyramid_registry: PyramidRegistry,
     pub debug_config: DebugConfig,
     pub static_objects: Vec<StaticObject>,
+    /// Which collision layers collide, trigger or ignore each other
+    pub collision_matrix: CollisionMatrix,
     pub regen_timer: Instant,
     pub regen_interval: f32,
     pub has_regen: bool,
+    pub rng: SimpleRng,
+    pub clock: GameClock,
+    pub enemy_spawn_timer: f32,
+    /// Time of the last thrown item (throws have a cooldown)
+    pub throw_timer: Instant,
 }
 
 impl Systems {
     /// Create systems with default configuration
     pub fn new(
         player_config: AnimationConfig,
-        slime_config: AnimationConfig,
-        punch_config: AnimationConfig,
+        enemy_registry: EnemyRegistry,
+        weapon_registry: WeaponRegistry,
+        pyramid_registry: PyramidRegistry,
     ) -> Self {
         let boundary_thickness = 10;
         let static_objects = vec![
@@ -43,13 +59,19 @@ impl Systems {
 
         Systems {
             player_config,
-            slime_config,
-            punch_config,
+            enemy_registry,
+            weapon_registry,
+            pyramid_registry,
             debug_config: DebugConfig::new(),
             static_objects,
+            collision_matrix: CollisionMatrix::standard(),
             regen_timer: Instant::now(),
             regen_interval: 5.0,
             has_regen: false,
+            rng: SimpleRng::from_time(),
+            clock: GameClock::new(),
+            enemy_spawn_timer: 0.0,
+            throw_timer: Instant::now(),
         }
     }
 }
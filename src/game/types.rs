@@ -19,6 +19,8 @@ pub struct FloatingTextInstance {
     pub color: Color,
     pub lifetime: f32,
     pub max_lifetime: f32,
+    /// Drawn at the large font size (critical hits)
+    pub large: bool,
 }
 
 /// Debug menu state
@@ -34,8 +36,12 @@ pub enum DebugMenuItem {
     PlayerMaxHealth,
     PlayerAttackDamage,
     PlayerAttackSpeed,
-    SlimeHealth,
-    SlimeContactDamage,
+    EnemyHealthScale,
+    EnemyDamageScale,
+    SpawnEnemyType,
+    TimeScale,
+    TimeOfDay,
+    GrantXp,
     ClearInventory,
 }
 
@@ -45,8 +51,12 @@ impl DebugMenuItem {
             Self::PlayerMaxHealth,
             Self::PlayerAttackDamage,
             Self::PlayerAttackSpeed,
-            Self::SlimeHealth,
-            Self::SlimeContactDamage,
+            Self::EnemyHealthScale,
+            Self::EnemyDamageScale,
+            Self::SpawnEnemyType,
+            Self::TimeScale,
+            Self::TimeOfDay,
+            Self::GrantXp,
             Self::ClearInventory,
         ]
     }
@@ -56,8 +66,12 @@ impl DebugMenuItem {
             Self::PlayerMaxHealth => "Player Max HP",
             Self::PlayerAttackDamage => "Player Damage",
             Self::PlayerAttackSpeed => "Player Atk Spd",
-            Self::SlimeHealth => "Slime Health",
-            Self::SlimeContactDamage => "Slime Contact Dmg",
+            Self::EnemyHealthScale => "Enemy HP Scale",
+            Self::EnemyDamageScale => "Enemy Dmg Scale",
+            Self::SpawnEnemyType => "Spawn Enemy",
+            Self::TimeScale => "Time Scale",
+            Self::TimeOfDay => "Time Of Day",
+            Self::GrantXp => "Grant XP",
             Self::ClearInventory => "Clear Inventory",
         }
     }
@@ -66,15 +80,20 @@ impl DebugMenuItem {
 /// Debug configuration for combat tuning
 #[derive(Debug, Clone)]
 pub struct DebugConfig {
-    pub slime_base_health: i32,
-    pub slime_contact_damage: f32,
+    /// Multiplier on each enemy type's max health (applied at spawn)
+    pub enemy_health_scale: f32,
+    /// Multiplier on enemy melee, shot and status effect damage
+    pub enemy_damage_scale: f32,
+    /// Enemy type spawned by right-clicking
+    pub spawn_enemy_type: String,
 }
 
 impl DebugConfig {
     pub fn new() -> Self {
         DebugConfig {
-            slime_base_health: 8,
-            slime_contact_damage: 1.0,
+            enemy_health_scale: 1.0,
+            enemy_damage_scale: 1.0,
+            spawn_enemy_type: "slime".to_string(),
         }
     }
 }
@@ -83,9 +102,14 @@ impl DebugConfig {
 /// This avoids repeating texture parameters everywhere
 pub struct GameTextures<'a> {
     pub character: &'a sdl2::render::Texture<'a>,
-    pub slime: &'a sdl2::render::Texture<'a>,
+    /// Enemy sprite sheets keyed by definition ID
+    pub enemies: &'a HashMap<String, sdl2::render::Texture<'a>>,
     pub entity: &'a sdl2::render::Texture<'a>,
-    pub punch: &'a sdl2::render::Texture<'a>,
+    /// Attack effect sprite sheets keyed by weapon profile ID
+    pub weapon_effects: &'a HashMap<String, sdl2::render::Texture<'a>>,
     pub grass_tile: &'a sdl2::render::Texture<'a>,
+    pub cave_tile: &'a sdl2::render::Texture<'a>,
+    pub portals: &'a sdl2::render::Texture<'a>,
+    pub crops: &'a sdl2::render::Texture<'a>,
     pub items: &'a HashMap<String, sdl2::render::Texture<'a>>,
 }
@@ -3,9 +3,10 @@
 // This module contains the UIManager struct which holds all UI state and components,
 // managing menus, HUD elements, and debug overlays.
 
-use crate::gui::{SaveExitMenu, DeathScreen, InventoryUI};
+use crate::gui::{SaveExitMenu, DeathScreen, InventoryUI, CharacterScreen};
 use crate::ui::{HealthBar, FloatingText, BuffDisplay};
 
+use super::editor::MapEditor;
 use super::DebugMenuState;
 
 /// UIManager holds all UI state and components
@@ -14,13 +15,20 @@ pub struct UIManager<'a> {
     pub save_exit_menu: SaveExitMenu,
     pub death_screen: DeathScreen,
     pub inventory_ui: InventoryUI<'a>,
+    pub character_screen: CharacterScreen,
     pub player_health_bar: HealthBar,
+    /// Sits just under the player's health bar
+    pub player_stamina_bar: HealthBar,
     pub enemy_health_bar: HealthBar,
+    /// Screen-wide bar shown during a boss fight
+    pub boss_health_bar: HealthBar,
     pub floating_text_renderer: FloatingText,
     pub buff_display: BuffDisplay<'a>,
     pub debug_menu_state: DebugMenuState,
     pub show_collision_boxes: bool,
     pub show_tile_grid: bool,
+    pub show_nav_paths: bool,
+    pub map_editor: MapEditor,
     pub is_tilling: bool,
     pub last_tilled_tile: Option<(i32, i32)>,
     pub mouse_x: i32,
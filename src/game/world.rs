@@ -5,46 +5,245 @@
 
 use crate::animation::{self, AnimationController};
 use crate::attack_effect::AttackEffect;
-use crate::collision::{self, Collidable};
+use crate::collision::{self, Collidable, StaticCollidable, StaticObject};
 use crate::combat;
 use crate::dropped_item::{self, DroppedItem};
+use crate::enemy::{Enemy, EnemyRegistry, Spawner};
+use crate::farming::Farm;
 use crate::inventory::PlayerInventory;
 use crate::item::ItemRegistry;
+use crate::map::{MapFile, MapObject, MapPyramid, SpawnPoint};
+use crate::pathfinding::{self, Pathfinder};
 use crate::player::Player;
-use crate::slime::Slime;
+use crate::projectile::Projectile;
+use crate::pyramid::{self, GroupStep, PyramidGroup, PyramidGroupDefinition};
+use crate::spatial::SpatialHash;
 use crate::sprite;
-use crate::stats::{ModifierEffect, StatModifier, StatType};
+use crate::stats::StatType;
 use crate::the_entity::{TheEntity, EntityState, EntityType};
-use crate::tile::{WorldGrid, RenderGrid};
+use crate::tile::{TileId, WorldGrid, RenderGrid};
 use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::Texture;
+use std::collections::HashMap;
 
 use super::FloatingTextInstance;
+use super::encounter::BossEncounter;
+use super::zone::{Portal, Zone, ZoneTheme, ZoneTransition, INACTIVE_ZONE_TICK_INTERVAL, ZONE_FADE_DURATION};
 
 // Constants from main.rs
 const SPRITE_SCALE: u32 = 2;
 const GAME_WIDTH: u32 = 640;
 const GAME_HEIGHT: u32 = 360;
 
+/// Cell size of the enemy and item spatial hashes (two tiles)
+const SPATIAL_CELL_SIZE: i32 = 64;
+
 /// GameWorld encapsulates all game entities and world state
 /// This struct owns all the game objects that exist in the world
+///
+/// The per-zone fields (enemies through player_spawn) always hold the *active* zone.
+/// Other zones wait in `inactive_zones` and are swapped in by `switch_zone`.
 pub struct GameWorld<'a> {
     pub player: Player<'a>,
-    pub slimes: Vec<Slime<'a>>,
+    pub zone_id: String,
+    pub zone_name: String,
+    pub zone_theme: ZoneTheme,
+    pub enemies: Vec<Enemy<'a>>,
     pub entities: Vec<TheEntity<'a>>,
     pub dropped_items: Vec<DroppedItem<'a>>,
     pub world_grid: WorldGrid,
     pub render_grid: RenderGrid,
+    pub farm: Farm,
+    pub portals: Vec<Portal>,
+    pub static_objects: Vec<StaticObject>,
+    pub spawn_points: Vec<SpawnPoint>,
+    pub spawners: Vec<Spawner>,
+    pub player_spawn: Option<SpawnPoint>,
+    pub pyramid_groups: Vec<PyramidGroup>,
+    pub inactive_zones: HashMap<String, Zone<'a>>,
+    pub zone_transition: Option<ZoneTransition>,
+    /// Portals only trigger after the player has stepped off every portal
+    /// (prevents bouncing straight back after arriving on top of one)
+    pub portals_armed: bool,
+    pub inactive_zone_timer: f32,
+    /// Enemy navigation for the active zone (rebuilt by `sync_navigation`)
+    pub navigation: Pathfinder,
     pub player_inventory: PlayerInventory,
     pub attack_effects: Vec<AttackEffect<'a>>,
+    /// Projectiles in flight in the active zone (enemy spit, thrown items)
+    pub projectiles: Vec<Projectile<'a>>,
     pub floating_texts: Vec<FloatingTextInstance>,
     pub active_attack: Option<combat::AttackEvent>,
+    /// Broadphase over `enemies` (rebuilt by `rebuild_spatial_index`)
+    pub enemy_index: SpatialHash,
+    /// Broadphase over `dropped_items` (rebuilt by `update_dropped_items`)
+    pub item_index: SpatialHash,
+    /// The boss fight in the active zone, with its arena walls
+    pub boss_encounter: Option<BossEncounter>,
 }
 
 impl<'a> GameWorld<'a> {
+    /// Assemble a world with the player standing in `active_zone`
+    pub fn new(
+        player: Player<'a>,
+        player_inventory: PlayerInventory,
+        active_zone: Zone<'a>,
+        inactive_zones: Vec<Zone<'a>>,
+    ) -> Self {
+        GameWorld {
+            player,
+            zone_id: active_zone.id,
+            zone_name: active_zone.name,
+            zone_theme: active_zone.theme,
+            enemies: active_zone.enemies,
+            entities: active_zone.entities,
+            dropped_items: active_zone.dropped_items,
+            world_grid: active_zone.world_grid,
+            render_grid: active_zone.render_grid,
+            farm: active_zone.farm,
+            portals: active_zone.portals,
+            static_objects: active_zone.static_objects,
+            spawn_points: active_zone.spawn_points,
+            spawners: active_zone.spawners,
+            player_spawn: active_zone.player_spawn,
+            pyramid_groups: active_zone.pyramid_groups,
+            inactive_zones: inactive_zones.into_iter().map(|zone| (zone.id.clone(), zone)).collect(),
+            zone_transition: None,
+            portals_armed: true,
+            inactive_zone_timer: 0.0,
+            navigation: Pathfinder::new(),
+            player_inventory,
+            attack_effects: Vec::new(),
+            projectiles: Vec::new(),
+            floating_texts: Vec::new(),
+            active_attack: None,
+            enemy_index: SpatialHash::new(SPATIAL_CELL_SIZE),
+            item_index: SpatialHash::new(SPATIAL_CELL_SIZE),
+            boss_encounter: None,
+        }
+    }
+
+    /// Swap the active zone's data with the given zone
+    fn swap_active_zone(&mut self, zone: &mut Zone<'a>) {
+        std::mem::swap(&mut self.zone_id, &mut zone.id);
+        std::mem::swap(&mut self.zone_name, &mut zone.name);
+        std::mem::swap(&mut self.zone_theme, &mut zone.theme);
+        std::mem::swap(&mut self.enemies, &mut zone.enemies);
+        std::mem::swap(&mut self.entities, &mut zone.entities);
+        std::mem::swap(&mut self.dropped_items, &mut zone.dropped_items);
+        std::mem::swap(&mut self.world_grid, &mut zone.world_grid);
+        std::mem::swap(&mut self.render_grid, &mut zone.render_grid);
+        std::mem::swap(&mut self.farm, &mut zone.farm);
+        std::mem::swap(&mut self.portals, &mut zone.portals);
+        std::mem::swap(&mut self.static_objects, &mut zone.static_objects);
+        std::mem::swap(&mut self.spawn_points, &mut zone.spawn_points);
+        std::mem::swap(&mut self.spawners, &mut zone.spawners);
+        std::mem::swap(&mut self.player_spawn, &mut zone.player_spawn);
+        std::mem::swap(&mut self.pyramid_groups, &mut zone.pyramid_groups);
+    }
+
+    /// Make another zone the active one
+    ///
+    /// The current zone is parked in `inactive_zones`. Transient effects
+    /// (attack effects, floating text, pending attack) are cleared since they
+    /// belong to the zone being left.
+    ///
+    /// # Returns
+    /// Err if no inactive zone has the given ID
+    pub fn switch_zone(&mut self, target_zone: &str) -> Result<(), String> {
+        let mut zone = self.inactive_zones.remove(target_zone)
+            .ok_or_else(|| format!("Unknown zone '{}'", target_zone))?;
+
+        self.swap_active_zone(&mut zone);
+        self.inactive_zones.insert(zone.id.clone(), zone);
+
+        self.attack_effects.clear();
+        self.projectiles.clear();
+        self.floating_texts.clear();
+        self.active_attack = None;
+        // A boss left behind locks a new arena if the player comes back
+        self.boss_encounter = None;
+
+        println!("Entered zone: {}", self.zone_name);
+        Ok(())
+    }
+
+    /// Start a zone transition if the player walks onto an armed portal
+    pub fn check_portals(&mut self) {
+        if self.zone_transition.is_some() {
+            return;
+        }
+
+        let player_bounds = self.player.get_bounds();
+        let touched = self.portals.iter().find(|portal| portal.is_touching(&player_bounds));
+
+        match touched {
+            Some(portal) if self.portals_armed => {
+                self.zone_transition = Some(ZoneTransition::from_portal(portal));
+                self.portals_armed = false;
+            }
+            Some(_) => {}
+            None => self.portals_armed = true,
+        }
+    }
+
+    /// Advance the fade-out / zone swap / fade-in sequence
+    pub fn update_zone_transition(&mut self, delta_time: f32) -> Result<(), String> {
+        let Some(transition) = self.zone_transition.as_mut() else {
+            return Ok(());
+        };
+
+        transition.timer += delta_time;
+        if transition.timer < ZONE_FADE_DURATION {
+            return Ok(());
+        }
+
+        if transition.switched {
+            self.zone_transition = None;
+            return Ok(());
+        }
+
+        // Screen is fully black: swap zones and start fading back in
+        transition.switched = true;
+        transition.timer = 0.0;
+        let (target_zone, target_x, target_y) =
+            (transition.target_zone.clone(), transition.target_x, transition.target_y);
+
+        self.switch_zone(&target_zone)?;
+        self.player.x = target_x as f32;
+        self.player.y = target_y as f32;
+        Ok(())
+    }
+
+    /// Low-frequency update of every inactive zone
+    ///
+    /// Accumulates time and ticks inactive zones once per `INACTIVE_ZONE_TICK_INTERVAL`.
+    pub fn tick_inactive_zones(&mut self, delta_time: f32) {
+        self.inactive_zone_timer += delta_time;
+        if self.inactive_zone_timer < INACTIVE_ZONE_TICK_INTERVAL {
+            return;
+        }
+
+        let elapsed = self.inactive_zone_timer;
+        self.inactive_zone_timer = 0.0;
+        for zone in self.inactive_zones.values_mut() {
+            zone.tick_inactive(elapsed);
+        }
+    }
+
+    /// Called at in-game midnight: grow crops in every zone
+    pub fn advance_day(&mut self) {
+        self.farm.advance_day();
+        for zone in self.inactive_zones.values_mut() {
+            zone.farm.advance_day();
+        }
+    }
+
     /// Spawn a dropped item in the world at given coordinates
     ///
     /// This method encapsulates the repeated item spawning logic that appears
-    /// throughout the codebase (entity loot, slime drops, death drops, etc.)
+    /// throughout the codebase (entity loot, enemy drops, death drops, etc.)
     ///
     /// # Arguments
     /// * `x` - X coordinate for the item
@@ -80,7 +279,7 @@ impl<'a> GameWorld<'a> {
         Ok(())
     }
 
-    /// Update all world entities (slimes, pyramids, effects, floating text)
+    /// Update all world entities (enemies, projectiles, pyramids, effects, floating text)
     ///
     /// This method centralizes entity update logic that was previously scattered
     /// throughout the Game::update() method.
@@ -88,9 +287,23 @@ impl<'a> GameWorld<'a> {
     /// # Arguments
     /// * `delta_time` - Time elapsed since last frame (in seconds)
     pub fn update_entities(&mut self, delta_time: f32) {
-        // Update slimes
-        for slime in self.slimes.iter_mut() {
-            slime.update();
+        // Update enemies (they chase/flee the player while it's alive)
+        let player_center = self.player.get_bounds().center();
+        let player_position = self.player.is_alive().then_some((player_center.x(), player_center.y()));
+        for enemy in self.enemies.iter_mut() {
+            enemy.update(delta_time, player_position, &mut self.navigation);
+            self.projectiles.extend(enemy.take_pending_shots());
+        }
+
+        // Move projectiles; the zone edge stops them (hits on walls and
+        // targets are resolved with the other collisions)
+        let (grid_width, grid_height) = (self.world_grid.width as i32, self.world_grid.height as i32);
+        for projectile in self.projectiles.iter_mut() {
+            projectile.update(delta_time);
+            let (cell_x, cell_y) = pathfinding::world_to_cell(projectile.x, projectile.y);
+            if cell_x < 0 || cell_y < 0 || cell_x >= grid_width || cell_y >= grid_height {
+                projectile.is_alive = false;
+            }
         }
 
         // Update entities (pyramids)
@@ -110,18 +323,131 @@ impl<'a> GameWorld<'a> {
         }
     }
 
+    /// Till a grass tile into dirt and register it as a farm plot
+    ///
+    /// # Returns
+    /// true if the tile was converted (false if it wasn't grass or is out of bounds)
+    pub fn till_tile(&mut self, tile_x: i32, tile_y: i32) -> bool {
+        if self.world_grid.get_tile(tile_x, tile_y) != Some(TileId::Grass) {
+            return false;
+        }
+
+        if !self.world_grid.set_tile(tile_x, tile_y, TileId::Dirt) {
+            return false;
+        }
+
+        self.render_grid.update_tile_and_neighbors(&self.world_grid, tile_x, tile_y);
+        self.farm.till(tile_x, tile_y);
+        true
+    }
+
+    /// Set a tile directly (map editor), keeping the render grid in sync
+    ///
+    /// Farm plots are removed when their tile is painted over with anything but dirt.
+    ///
+    /// # Returns
+    /// true if the tile was in bounds
+    pub fn set_tile(&mut self, tile_x: i32, tile_y: i32, tile: TileId) -> bool {
+        if !self.world_grid.set_tile(tile_x, tile_y, tile) {
+            return false;
+        }
+
+        if tile != TileId::Dirt {
+            self.farm.remove_plot(tile_x, tile_y);
+        }
+        self.render_grid.update_tile_and_neighbors(&self.world_grid, tile_x, tile_y);
+        true
+    }
+
+    /// Rebuild the enemy navigation grid if tiles or obstacles changed
+    ///
+    /// Obstacles are the boundary walls passed in plus the zone's static objects,
+    /// any boss arena walls and pyramids. Cached paths survive as long as the grid
+    /// is unchanged.
+    pub fn sync_navigation(&mut self, boundary_objects: &[StaticObject]) {
+        let arena_walls = self.boss_encounter.iter().flat_map(|encounter| encounter.walls.iter());
+        let obstacles: Vec<_> = boundary_objects.iter()
+            .chain(self.static_objects.iter())
+            .chain(arena_walls)
+            .map(|obj| obj.get_bounds())
+            .chain(self.entities.iter().map(StaticCollidable::get_bounds))
+            .collect();
+        self.navigation.sync(&self.world_grid, &obstacles);
+    }
+
+    /// Capture the active zone's static layout as a map file
+    pub fn to_map_file(&self) -> MapFile {
+        let mut map_file = MapFile::new(&self.zone_name, self.world_grid.width, self.world_grid.height, TileId::Grass);
+        map_file.set_tiles(&self.world_grid);
+
+        // Groups left with a gap by deleted pyramids are dropped along with their links
+        let links = || self.entities.iter().filter_map(|entity| entity.link.as_ref());
+        map_file.pyramid_groups = self.pyramid_groups.iter()
+            .map(|group| group.definition.clone())
+            .filter(|group| {
+                let group_links = links().filter(|link| link.group == group.id);
+                pyramid::validate_groups(std::slice::from_ref(group), group_links).is_ok()
+            })
+            .collect();
+        map_file.pyramids = self.entities.iter()
+            .map(|entity| MapPyramid {
+                x: entity.x,
+                y: entity.y,
+                entity_type: entity.entity_type,
+                link: entity.link.clone()
+                    .filter(|link| map_file.pyramid_groups.iter().any(|group| group.id == link.group)),
+            })
+            .collect();
+        map_file.static_objects = self.static_objects.iter().map(MapObject::from_static_object).collect();
+        map_file.spawn_points = self.spawn_points.clone();
+        map_file.spawners = self.spawners.iter().map(|spawner| spawner.definition.clone()).collect();
+        map_file.player_spawn = self.player_spawn;
+        map_file
+    }
+
+    /// Update crop growth and revert untended soil back to grass
+    ///
+    /// # Arguments
+    /// * `delta_time` - Time elapsed since last frame (in seconds)
+    pub fn update_farm(&mut self, delta_time: f32) {
+        let reverted = self.farm.update(delta_time, &mut self.world_grid);
+        for (tile_x, tile_y) in reverted {
+            self.render_grid.update_tile_and_neighbors(&self.world_grid, tile_x, tile_y);
+        }
+    }
+
     /// Remove dead/expired entities from world
     ///
     /// This method cleans up entities that have finished their lifecycle:
-    /// - Dead slimes
+    /// - Dead enemies and spent shots
     /// - Finished attack effects
     /// - Expired floating text
     pub fn cleanup_dead_entities(&mut self) {
-        self.slimes.retain(|slime| slime.is_alive);
+        self.enemies.retain(|enemy| enemy.is_alive);
+        self.projectiles.retain(|projectile| projectile.is_alive);
         self.attack_effects.retain(|effect| !effect.is_finished());
         self.floating_texts.retain(|text| text.lifetime < text.max_lifetime);
     }
 
+    /// Rebuild the enemy spatial hash from the enemies' current bounds
+    ///
+    /// Called once per tick before anything queries it. Enemies spawned later
+    /// in the tick are missing from the index until the next rebuild.
+    pub fn rebuild_spatial_index(&mut self) {
+        self.enemy_index.rebuild(self.enemies.iter().enumerate().map(|(i, enemy)| (i, enemy.get_bounds())));
+    }
+
+    /// Indices of the enemies whose bounds intersect `area`
+    pub fn enemies_in_rect(&self, area: &Rect) -> Vec<usize> {
+        self.enemy_index.query_rect(area)
+    }
+
+    /// Indices of the enemies whose bounds come within `radius` of `center`
+    #[allow(dead_code)] // Reserved for area attacks
+    pub fn enemies_in_radius(&self, center: (i32, i32), radius: f32) -> Vec<usize> {
+        self.enemy_index.query_radius(center, radius)
+    }
+
     /// Update dropped items (pickup collision, despawn timer)
     ///
     /// This method handles:
@@ -137,15 +463,30 @@ impl<'a> GameWorld<'a> {
     /// Vec of (item_id, quantity) tuples for items that were picked up
     pub fn update_dropped_items(&mut self, item_registry: &ItemRegistry) -> Vec<(String, u32)> {
         let mut picked_up_items = Vec::new();
+
+        // Only items the broadphase finds under the player are candidates
+        // (pickup radius buffs widen the player's reach on every side)
+        self.item_index.rebuild(self.dropped_items.iter().enumerate().map(|(i, item)| (i, item.get_bounds())));
+        let reach = self.player.stats.effective_stat(StatType::PickupRadius, &self.player.modifiers()).max(0.0) as i32;
         let player_bounds = self.player.get_bounds();
+        let pickup_area = Rect::new(
+            player_bounds.x() - reach,
+            player_bounds.y() - reach,
+            player_bounds.width() + 2 * reach as u32,
+            player_bounds.height() + 2 * reach as u32,
+        );
+        let touching = self.item_index.query_rect(&pickup_area);
 
         // Handle item pickup
+        let mut index = 0;
         self.dropped_items.retain(|item| {
+            let is_touching = touching.binary_search(&index).is_ok();
+            index += 1;
             if !item.can_pickup {
                 return true; // Keep items in cooldown
             }
 
-            if player_bounds.has_intersection(item.get_bounds()) {
+            if is_touching {
                 match self.player_inventory.quick_add(&item.item_id, item.quantity, item_registry) {
                     Ok(overflow) => {
                         if overflow == 0 {
@@ -174,54 +515,91 @@ impl<'a> GameWorld<'a> {
 
     /// Apply buffs from awakened pyramids to player
     ///
-    /// This method clears existing buffs and reapplies them based on which
-    /// pyramids are currently in the Awake state.
+    /// Each awake pyramid is an aura keyed by its id (with the buff from its
+    /// definition), so the set is replaced every frame without touching the
+    /// player's timed buffs.
     ///
     /// # Returns
     /// true if player has regeneration buff, false otherwise
     pub fn apply_pyramid_buffs(&mut self) -> bool {
-        // Clear existing buffs
-        self.player.active_modifiers.clear();
+        let mut auras = Vec::new();
         let mut has_regen = false;
 
-        // Check each entity and apply appropriate buffs
-        for entity in self.entities.iter() {
-            if entity.state == EntityState::Awake {
-                match entity.entity_type {
-                    EntityType::Attack => {
-                        self.player.active_modifiers.push(ModifierEffect {
-                            stat_type: StatType::AttackDamage,
-                            modifier: StatModifier::Flat(1.0),
-                            duration: None,
-                            source: "Pyramid of Attack".to_string(),
-                        });
-                    }
-                    EntityType::Defense => {
-                        self.player.active_modifiers.push(ModifierEffect {
-                            stat_type: StatType::Defense,
-                            modifier: StatModifier::Flat(1.0),
-                            duration: None,
-                            source: "Pyramid of Defense".to_string(),
-                        });
-                    }
-                    EntityType::Speed => {
-                        self.player.active_modifiers.push(ModifierEffect {
-                            stat_type: StatType::MovementSpeed,
-                            modifier: StatModifier::Flat(1.0),
-                            duration: None,
-                            source: "Pyramid of Speed".to_string(),
-                        });
-                    }
-                    EntityType::Regeneration => {
-                        has_regen = true;
-                    }
-                }
+        for entity in self.entities.iter().filter(|entity| entity.state == EntityState::Awake) {
+            if entity.entity_type == EntityType::Regeneration {
+                has_regen = true;
+            }
+            if !entity.definition.modifiers.is_empty() {
+                auras.push(entity.definition.aura(format!("pyramid_{}", entity.id)));
             }
         }
 
+        self.player.active_effects.set_auras(auras);
         has_regen
     }
 
+    /// Advance linked pyramid groups for pyramids that just woke
+    ///
+    /// Each in-order awakening shows its place in the order over the pyramid;
+    /// an out-of-order one shows an X.
+    ///
+    /// # Returns
+    /// The groups completed this frame (the game hands out their rewards)
+    pub fn update_pyramid_groups(&mut self) -> Vec<PyramidGroupDefinition> {
+        let mut completed = Vec::new();
+
+        for index in 0..self.entities.len() {
+            if !self.entities[index].take_awakened() {
+                continue;
+            }
+            let Some(link) = self.entities[index].link.clone() else {
+                continue;
+            };
+            let size = self.entities.iter()
+                .filter(|entity| entity.link.as_ref().is_some_and(|other| other.group == link.group))
+                .count() as u32;
+            let Some(group) = self.pyramid_groups.iter_mut().find(|group| group.definition.id == link.group) else {
+                continue;
+            };
+
+            let (text, color) = match group.record_awakening(link.order, size) {
+                Some(GroupStep::InOrder(count)) => (count.to_string(), Color::RGB(255, 215, 90)),
+                Some(GroupStep::Completed) => {
+                    completed.push(group.definition.clone());
+                    (format!("{}!", size), Color::RGB(255, 215, 90))
+                }
+                Some(GroupStep::Broken) => ("X".to_string(), Color::RGB(220, 60, 60)),
+                None => continue,
+            };
+            let (x, y) = (self.entities[index].x as f32 + 28.0, self.entities[index].y as f32 - 8.0);
+            self.spawn_large_floating_text(text, x, y, color, 1.5);
+        }
+
+        completed
+    }
+
+    /// True when the zone has pyramids and every one of them is awake
+    pub fn pyramids_all_awake(&self) -> bool {
+        !self.entities.is_empty() && self.entities.iter().all(|entity| entity.state == EntityState::Awake)
+    }
+
+    /// Sends every awake pyramid back to sleep (their power summoned the boss)
+    pub fn drain_pyramids(&mut self) {
+        for entity in self.entities.iter_mut() {
+            entity.return_to_dormant();
+        }
+    }
+
+    /// The living boss in the active zone, if there is one
+    pub fn boss(&self) -> Option<&Enemy<'a>> {
+        self.enemies.iter().find(|enemy| enemy.is_boss() && enemy.is_alive && !enemy.is_dying())
+    }
+
+    /// The part of the active zone the player can reach (see `play_area`)
+    pub fn zone_bounds(&self) -> Rect {
+        play_area(&self.world_grid)
+    }
+
     /// Apply regeneration healing to player and create floating text
     ///
     /// This method handles the regeneration buff effect, healing the player
@@ -232,12 +610,13 @@ impl<'a> GameWorld<'a> {
 
             // Create floating text at player
             self.floating_texts.push(FloatingTextInstance {
-                x: self.player.x as f32,
-                y: (self.player.y - (self.player.height * SPRITE_SCALE) as i32) as f32,
+                x: self.player.x,
+                y: self.player.y - (self.player.height * SPRITE_SCALE) as f32,
                 text: "+2".to_string(),
                 color: Color::RGB(0, 255, 0),
                 lifetime: 0.0,
                 max_lifetime: 1.5,
+                large: false,
             });
 
             // Create floating text at regen pyramid
@@ -250,6 +629,7 @@ impl<'a> GameWorld<'a> {
                         color: Color::RGB(0, 255, 0),
                         lifetime: 0.0,
                         max_lifetime: 1.5,
+                        large: false,
                     });
                     break;
                 }
@@ -257,44 +637,40 @@ impl<'a> GameWorld<'a> {
         }
     }
 
-    /// Spawn a new slime at the given position
+    /// Spawn a new enemy at the given position
     ///
-    /// This method handles the complex positioning logic for slime spawning,
-    /// ensuring that the click/spawn position corresponds to the center of
-    /// the slime's collision box (not its sprite anchor).
+    /// The click/spawn position becomes the center of the enemy's collision
+    /// box (not its sprite anchor).
     ///
     /// # Arguments
+    /// * `enemy_registry` - Registry holding the enemy type's definition
+    /// * `enemy_type` - Definition ID (e.g. "slime")
     /// * `x` - Desired X position (collision box center)
     /// * `y` - Desired Y position (collision box center)
-    /// * `slime_animation_controller` - Animation controller for the slime
-    /// * `health` - Initial health for the slime
+    /// * `textures` - Enemy sprite sheets keyed by definition ID
+    /// * `health_scale` - Multiplier on the definition's max health (debug tuning)
     ///
     /// # Returns
-    /// Ok(()) on success
-    pub fn spawn_slime(
+    /// The new enemy (so callers can tag it), or Err if the enemy type or its
+    /// texture is unknown
+    pub fn spawn_enemy(
         &mut self,
+        enemy_registry: &EnemyRegistry,
+        enemy_type: &str,
         x: i32,
         y: i32,
-        slime_animation_controller: AnimationController<'a>,
-        health: i32,
-    ) -> Result<(), String> {
-        // Create temp slime to get hitbox dimensions
-        // Slime uses anchor positioning (bottom-center of sprite)
-        // but we want spawn position to be collision box center
-        let temp_slime = Slime::new(0, 0, AnimationController::new());
-
-        // Calculate anchor position from desired collision center
-        // anchor = click - collision_offset - (collision_size / 2)
-        let anchor_x = x - (temp_slime.hitbox_offset_x * SPRITE_SCALE as i32)
-                       - (temp_slime.hitbox_width * SPRITE_SCALE / 2) as i32;
-        let anchor_y = y - (temp_slime.hitbox_offset_y * SPRITE_SCALE as i32)
-                       - (temp_slime.hitbox_height * SPRITE_SCALE / 2) as i32;
-
-        let mut new_slime = Slime::new(anchor_x, anchor_y, slime_animation_controller);
-        new_slime.health = health;
-        self.slimes.push(new_slime);
-
-        Ok(())
+        textures: &'a HashMap<String, Texture<'a>>,
+        health_scale: f32,
+    ) -> Result<&mut Enemy<'a>, String> {
+        let definition = enemy_registry.get(enemy_type)
+            .ok_or_else(|| format!("Unknown enemy type '{}'", enemy_type))?;
+        let (anchor_x, anchor_y) = definition.hitbox.anchor_for_center(x, y, definition.scale);
+
+        let mut enemy = enemy_registry.create_enemy(enemy_type, anchor_x, anchor_y, textures)?;
+        enemy.set_max_health(((enemy.max_health as f32 * health_scale).round() as i32).max(1));
+        self.enemies.push(enemy);
+
+        Ok(self.enemies.last_mut().expect("enemy was just pushed"))
     }
 
     /// Spawn an attack effect (punch, slash, etc.)
@@ -306,7 +682,7 @@ impl<'a> GameWorld<'a> {
     /// * `y` - Y position (top-left corner)
     /// * `width` - Effect width in pixels (before scaling)
     /// * `height` - Effect height in pixels (before scaling)
-    /// * `direction` - Direction the effect is facing
+    /// * `attack` - The attack the effect shows (its direction and combo step)
     /// * `animation_controller` - Animation controller for the effect
     pub fn spawn_attack_effect(
         &mut self,
@@ -314,7 +690,7 @@ impl<'a> GameWorld<'a> {
         y: i32,
         width: u32,
         height: u32,
-        direction: animation::Direction,
+        attack: &combat::AttackEvent,
         animation_controller: AnimationController<'a>,
     ) {
         let effect = AttackEffect::new(
@@ -322,7 +698,8 @@ impl<'a> GameWorld<'a> {
             y,
             width,
             height,
-            direction,
+            attack.direction,
+            attack.combo_step,
             animation_controller,
         );
         self.attack_effects.push(effect);
@@ -354,6 +731,27 @@ impl<'a> GameWorld<'a> {
             color,
             lifetime: 0.0,
             max_lifetime,
+            large: false,
+        });
+    }
+
+    /// Like `spawn_floating_text`, but drawn at the large font size
+    pub fn spawn_large_floating_text(
+        &mut self,
+        text: String,
+        x: f32,
+        y: f32,
+        color: Color,
+        max_lifetime: f32,
+    ) {
+        self.floating_texts.push(FloatingTextInstance {
+            x,
+            y,
+            text,
+            color,
+            lifetime: 0.0,
+            max_lifetime,
+            large: true,
         });
     }
 
@@ -370,9 +768,9 @@ impl<'a> GameWorld<'a> {
         // Add player
         collidables.push(&self.player as &dyn collision::Collidable);
 
-        // Add all slimes
-        for slime in &self.slimes {
-            collidables.push(slime as &dyn collision::Collidable);
+        // Add all enemies
+        for enemy in &self.enemies {
+            collidables.push(enemy as &dyn collision::Collidable);
         }
 
         // Note: TheEntity (pyramids) don't implement Collidable trait
@@ -386,7 +784,7 @@ impl<'a> GameWorld<'a> {
     /// # Returns
     /// Tuple of (x, y) coordinates
     pub fn get_player_pos(&self) -> (i32, i32) {
-        (self.player.x, self.player.y)
+        self.player.position()
     }
 
     /// Get a mutable reference to the player
@@ -424,3 +822,14 @@ impl<'a> GameWorld<'a> {
     }
 }
 
+/// A zone's area in pixels, cut down to the walled-off screen
+///
+/// There's no camera, so the boundary walls in `Systems` keep the player
+/// inside the first `GAME_WIDTH` x `GAME_HEIGHT` pixels even when the zone's
+/// grid is bigger.
+pub fn play_area(world_grid: &WorldGrid) -> Rect {
+    let tile_size = pathfinding::NAV_TILE_SIZE as u32;
+    let grid = Rect::new(0, 0, world_grid.width as u32 * tile_size, world_grid.height as u32 * tile_size);
+    let screen = Rect::new(0, 0, GAME_WIDTH, GAME_HEIGHT);
+    grid.intersection(screen).unwrap_or(screen)
+}
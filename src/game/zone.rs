@@ -0,0 +1,486 @@
+// Zones - separate areas of the world connected by portals
+//
+// Each zone owns its own tile grid, farm, enemies, pyramids and dropped items.
+// The player, inventory and game clock are global and travel between zones.
+//
+// Only the active zone lives in GameWorld's fields and is fully simulated.
+// Inactive zones are parked in `GameWorld::inactive_zones` and tick at a low
+// frequency (see `INACTIVE_ZONE_TICK_INTERVAL`) so slow systems like soil
+// reversion keep running while the player is away.
+//
+// A zone's static layout (tiles, pyramids and their linked groups, static
+// objects, spawn points, spawners) comes
+// from its map in `assets/maps/` when one exists (see map.rs and tiled.rs),
+// otherwise from the built-in defaults below.
+
+use crate::collision::{self, StaticObject};
+use crate::dropped_item::DroppedItem;
+use crate::farming::Farm;
+use crate::map::{self, MapFile, SpawnPoint};
+use crate::enemy::spawner::{SpawnArea, SpawnTime, WaveConfig};
+use crate::enemy::{Enemy, Spawner, SpawnerDefinition};
+use crate::pyramid::{GroupReward, PyramidDefinition, PyramidGroup, PyramidGroupDefinition, PyramidLink, PyramidRegistry, RewardBuff};
+use crate::sprite;
+use crate::stats::{ModifierEffect, StatModifier, StatType};
+use crate::the_entity::{EntityType, TheEntity};
+use crate::tile::{RenderGrid, TileId, WorldGrid};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+/// ID of the starting zone (also used for saves from before zones existed)
+pub const MEADOW_ZONE_ID: &str = "meadow";
+
+/// ID of the cave zone below the meadow
+pub const CAVE_ZONE_ID: &str = "cave";
+
+/// Size of the meadow's tile grid (width, height)
+pub const MEADOW_GRID_SIZE: (usize, usize) = (40, 24);
+
+/// Seconds for each half of a zone transition (fade out, then fade in)
+pub const ZONE_FADE_DURATION: f32 = 0.35;
+
+/// Seconds between low-frequency updates of inactive zones
+pub const INACTIVE_ZONE_TICK_INTERVAL: f32 = 1.0;
+
+/// Size of a portal frame in the portal sprite sheet
+const PORTAL_FRAME_SIZE: i32 = 32;
+
+/// Visual and gameplay theme of a zone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneTheme {
+    /// Outdoors: grass tileset, follows the day/night cycle
+    Meadow,
+    /// Underground: stone tileset, always dark, night-time enemy spawning
+    Cave,
+}
+
+impl ZoneTheme {
+    /// Returns true if the zone is cut off from daylight
+    pub fn is_underground(&self) -> bool {
+        matches!(self, ZoneTheme::Cave)
+    }
+
+    /// Returns true if waking every pyramid here at once summons the boss
+    pub fn summons_boss(&self) -> bool {
+        matches!(self, ZoneTheme::Meadow)
+    }
+
+    /// Fixed tint for underground zones (None = follow the game clock)
+    pub fn fixed_tint(&self) -> Option<Color> {
+        match self {
+            ZoneTheme::Meadow => None,
+            ZoneTheme::Cave => Some(Color::RGBA(10, 5, 25, 120)),
+        }
+    }
+}
+
+/// Visual style of a portal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortalKind {
+    /// Dark opening leading underground
+    CaveEntrance,
+    /// Ladder leading back up to the surface
+    Ladder,
+}
+
+impl PortalKind {
+    /// Column in the portal sprite sheet
+    fn sprite_frame(&self) -> i32 {
+        match self {
+            PortalKind::CaveEntrance => 0,
+            PortalKind::Ladder => 1,
+        }
+    }
+}
+
+/// A door/portal that moves the player to another zone when touched
+#[derive(Debug, Clone)]
+pub struct Portal {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub kind: PortalKind,
+    /// Zone the portal leads to
+    pub target_zone: String,
+    /// Player anchor position in the target zone
+    pub target_x: i32,
+    pub target_y: i32,
+}
+
+impl Portal {
+    /// Creates a 32x32 portal
+    pub fn new(x: i32, y: i32, kind: PortalKind, target_zone: &str, target_x: i32, target_y: i32) -> Self {
+        Portal {
+            x,
+            y,
+            width: 32,
+            height: 32,
+            kind,
+            target_zone: target_zone.to_string(),
+            target_x,
+            target_y,
+        }
+    }
+
+    /// Trigger area of the portal
+    pub fn bounds(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width, self.height)
+    }
+
+    /// Returns true if the given rect (usually the player's collision box) touches the portal
+    pub fn is_touching(&self, other: &Rect) -> bool {
+        collision::aabb_intersect(&self.bounds(), other)
+    }
+
+    /// Renders the portal on the ground layer
+    pub fn render(&self, canvas: &mut Canvas<Window>, texture: &Texture) -> Result<(), String> {
+        let src_rect = Rect::new(
+            self.kind.sprite_frame() * PORTAL_FRAME_SIZE,
+            0,
+            PORTAL_FRAME_SIZE as u32,
+            PORTAL_FRAME_SIZE as u32,
+        );
+        canvas.copy(texture, Some(src_rect), Some(self.bounds()))
+            .map_err(|e| format!("Portal render error: {}", e))
+    }
+}
+
+/// Everything that belongs to a single zone
+///
+/// The active zone's data is swapped into GameWorld's fields, so this struct
+/// only holds zones the player is *not* currently in (plus zones being built/loaded).
+pub struct Zone<'a> {
+    pub id: String,
+    pub name: String,
+    pub theme: ZoneTheme,
+    pub enemies: Vec<Enemy<'a>>,
+    pub entities: Vec<TheEntity<'a>>,
+    pub dropped_items: Vec<DroppedItem<'a>>,
+    pub world_grid: WorldGrid,
+    pub render_grid: RenderGrid,
+    pub farm: Farm,
+    pub portals: Vec<Portal>,
+    /// Solid blocks placed by the map (boundary walls live in Systems)
+    pub static_objects: Vec<StaticObject>,
+    /// Where ambient enemies appear (empty = anywhere away from the player)
+    pub spawn_points: Vec<SpawnPoint>,
+    /// Placed enemy spawners with their timers
+    pub spawners: Vec<Spawner>,
+    /// Where a new game starts the player, if the map sets it
+    pub player_spawn: Option<SpawnPoint>,
+    /// Linked pyramid groups with their progress
+    pub pyramid_groups: Vec<PyramidGroup>,
+}
+
+impl<'a> Zone<'a> {
+    /// Creates an empty zone with the given grid and portals
+    pub fn new(id: &str, name: &str, theme: ZoneTheme, world_grid: WorldGrid, portals: Vec<Portal>) -> Self {
+        let render_grid = RenderGrid::new(&world_grid);
+        Zone {
+            id: id.to_string(),
+            name: name.to_string(),
+            theme,
+            enemies: Vec::new(),
+            entities: Vec::new(),
+            dropped_items: Vec::new(),
+            world_grid,
+            render_grid,
+            farm: Farm::new(),
+            portals,
+            static_objects: Vec::new(),
+            spawn_points: Vec::new(),
+            spawners: Vec::new(),
+            player_spawn: None,
+            pyramid_groups: Vec::new(),
+        }
+    }
+
+    /// Replaces the zone's static layout with a map file's contents
+    ///
+    /// Tiles, pyramids, pyramid groups, static objects, spawn points and
+    /// spawners are all replaced (spawners and groups start fresh).
+    /// Portals are part of the zone's wiring and are kept.
+    pub fn apply_map(&mut self, map: &MapFile, pyramids: &PyramidRegistry, entity_texture: &'a Texture<'a>) -> Result<(), String> {
+        self.set_world_grid(map.to_world_grid()?);
+        self.entities = map.pyramids.iter().enumerate()
+            .map(|(id, pyramid)| {
+                let mut entity = create_pyramid(id, pyramid.x, pyramid.y, pyramids.get(pyramid.entity_type), entity_texture);
+                entity.link = pyramid.link.clone();
+                entity
+            })
+            .collect();
+        self.pyramid_groups = map.pyramid_groups.iter().cloned().map(PyramidGroup::new).collect();
+        self.static_objects = map.static_objects.iter().map(|obj| obj.to_static_object()).collect();
+        self.spawn_points = map.spawn_points.clone();
+        self.spawners = map.spawners.iter().cloned().map(Spawner::new).collect();
+        self.player_spawn = map.player_spawn;
+        Ok(())
+    }
+
+    /// Replaces the zone's tile grid (rebuilding the render grid to match)
+    pub fn set_world_grid(&mut self, world_grid: WorldGrid) {
+        self.render_grid = RenderGrid::new(&world_grid);
+        self.world_grid = world_grid;
+    }
+
+    /// Low-frequency update while the player is in another zone
+    ///
+    /// Only slow, position-independent systems run here. Enemies and items
+    /// are frozen until the player comes back.
+    pub fn tick_inactive(&mut self, delta_time: f32) {
+        let reverted = self.farm.update(delta_time, &mut self.world_grid);
+        for (tile_x, tile_y) in reverted {
+            self.render_grid.update_tile_and_neighbors(&self.world_grid, tile_x, tile_y);
+        }
+    }
+}
+
+/// IDs of every zone in the game, in creation order
+pub fn zone_ids() -> [&'static str; 2] {
+    [MEADOW_ZONE_ID, CAVE_ZONE_ID]
+}
+
+/// Builds a zone in its initial (new game) state
+///
+/// If the zone has a map (`assets/maps/<id>.json` or a Tiled `<id>.tmj`),
+/// its layout replaces the built-in one.
+///
+/// # Returns
+/// Err for unknown zone IDs or a map file that fails to load
+pub fn create_zone<'a>(id: &str, pyramids: &PyramidRegistry, entity_texture: &'a Texture<'a>) -> Result<Zone<'a>, String> {
+    let mut zone = match id {
+        MEADOW_ZONE_ID => create_meadow(pyramids, entity_texture),
+        CAVE_ZONE_ID => create_cave(pyramids, entity_texture),
+        _ => return Err(format!("Unknown zone '{}'", id)),
+    };
+
+    if let Some((map_file, map_path)) = map::load_zone_map(id)? {
+        zone.apply_map(&map_file, pyramids, entity_texture)?;
+        println!("  - Loaded map {}", map_path.display());
+    }
+
+    Ok(zone)
+}
+
+/// Creates a dormant, unlinked pyramid with its 13-frame sprite sheet
+pub fn create_pyramid<'a>(
+    id: usize,
+    x: i32,
+    y: i32,
+    definition: &PyramidDefinition,
+    entity_texture: &'a Texture<'a>,
+) -> TheEntity<'a> {
+    let mut frames = Vec::new();
+    for i in 0..13 {
+        frames.push(sprite::Frame::new(i * 32, 0, 32, 32, 100));
+    }
+    let sprite_sheet = sprite::SpriteSheet::new(entity_texture, frames);
+    TheEntity::new(id, x, y, definition, sprite_sheet)
+}
+
+/// Places linked pyramids, numbering their `link` order in the order given
+fn add_linked_pyramids<'a>(
+    zone: &mut Zone<'a>,
+    group: PyramidGroupDefinition,
+    placements: &[(i32, i32, EntityType)],
+    pyramids: &PyramidRegistry,
+    entity_texture: &'a Texture<'a>,
+) {
+    for (order, (x, y, entity_type)) in (1..).zip(placements) {
+        let mut entity = create_pyramid(zone.entities.len(), *x, *y, pyramids.get(*entity_type), entity_texture);
+        entity.link = Some(PyramidLink { group: group.id.clone(), order });
+        zone.entities.push(entity);
+    }
+    zone.pyramid_groups.push(PyramidGroup::new(group));
+}
+
+/// A modifier for a group's reward buff (its source is filled in with the group name)
+fn reward_modifier(stat_type: StatType, modifier: StatModifier) -> ModifierEffect {
+    ModifierEffect {
+        stat_type,
+        modifier,
+        duration: None,
+        source: String::new(),
+    }
+}
+
+/// The starting meadow: grass field with the four pyramids and a cave entrance
+///
+/// The attack, defense and speed pyramids are linked: waking them in that
+/// order earns a blessing. Regeneration stands apart, so the group can be
+/// completed without waking all four (which summons the boss).
+fn create_meadow<'a>(pyramids: &PyramidRegistry, entity_texture: &'a Texture<'a>) -> Zone<'a> {
+    let portals = vec![
+        Portal::new(576, 64, PortalKind::CaveEntrance, CAVE_ZONE_ID, 120, 320),
+    ];
+    let mut zone = Zone::new(
+        MEADOW_ZONE_ID,
+        "Meadow",
+        ZoneTheme::Meadow,
+        WorldGrid::new(MEADOW_GRID_SIZE.0, MEADOW_GRID_SIZE.1, TileId::Grass),
+        portals,
+    );
+
+    // Create pyramid entities at their spawn positions, linked ones in waking order
+    let warriors = PyramidGroupDefinition {
+        id: "meadow_warriors".to_string(),
+        name: "Warrior's Blessing".to_string(),
+        reward: GroupReward {
+            xp: 60,
+            buff: Some(RewardBuff {
+                duration: 120.0,
+                modifiers: vec![
+                    reward_modifier(StatType::AttackDamage, StatModifier::Flat(1.0)),
+                    reward_modifier(StatType::MovementSpeed, StatModifier::Flat(0.5)),
+                ],
+            }),
+        },
+    };
+    let entity_spawn_data = [
+        (160, 120, EntityType::Attack),
+        (480, 120, EntityType::Defense),
+        (160, 240, EntityType::Speed),
+    ];
+    add_linked_pyramids(&mut zone, warriors, &entity_spawn_data, pyramids, entity_texture);
+    let regeneration = create_pyramid(zone.entities.len(), 480, 240, pyramids.get(EntityType::Regeneration), entity_texture);
+    zone.entities.push(regeneration);
+    zone.spawners = built_in_spawners(MEADOW_ZONE_ID).into_iter().map(Spawner::new).collect();
+
+    zone
+}
+
+/// The cave: dark stone floor with a ladder back up to the meadow
+///
+/// Three linked pyramids of fortune stand near the ladder.
+fn create_cave<'a>(pyramids: &PyramidRegistry, entity_texture: &'a Texture<'a>) -> Zone<'a> {
+    let portals = vec![
+        Portal::new(48, 296, PortalKind::Ladder, MEADOW_ZONE_ID, 592, 140),
+    ];
+    let mut zone = Zone::new(
+        CAVE_ZONE_ID,
+        "Cave",
+        ZoneTheme::Cave,
+        WorldGrid::new(20, 12, TileId::Dirt),
+        portals,
+    );
+
+    let triad = PyramidGroupDefinition {
+        id: "cave_triad".to_string(),
+        name: "Fortune of the Triad".to_string(),
+        reward: GroupReward {
+            xp: 80,
+            buff: Some(RewardBuff {
+                duration: 180.0,
+                modifiers: vec![
+                    reward_modifier(StatType::XpGain, StatModifier::Flat(0.5)),
+                    reward_modifier(StatType::CritChance, StatModifier::Flat(0.1)),
+                ],
+            }),
+        },
+    };
+    let entity_spawn_data = [
+        (144, 64, EntityType::Crit),
+        (272, 64, EntityType::PickupRadius),
+        (208, 176, EntityType::XpGain),
+    ];
+    add_linked_pyramids(&mut zone, triad, &entity_spawn_data, pyramids, entity_texture);
+    zone.spawners = built_in_spawners(CAVE_ZONE_ID).into_iter().map(Spawner::new).collect();
+
+    zone
+}
+
+/// Enemy spawners a zone starts with when it has no map
+fn built_in_spawners(zone_id: &str) -> Vec<SpawnerDefinition> {
+    match zone_id {
+        // Slimes gather in the south field, between the lower pyramids, after dark
+        MEADOW_ZONE_ID => vec![SpawnerDefinition {
+            id: "meadow_night_slimes".to_string(),
+            enemy_type: "slime".to_string(),
+            area: SpawnArea { x: 256, y: 240, width: 192, height: 96 },
+            max_alive: 2,
+            respawn_interval: 25.0,
+            active_time: SpawnTime::Night,
+            min_player_distance: 128.0,
+            waves: None,
+        }],
+        // Five escalating waves of slimes from the back of the cave
+        CAVE_ZONE_ID => vec![SpawnerDefinition {
+            id: "cave_waves".to_string(),
+            enemy_type: "slime".to_string(),
+            area: SpawnArea { x: 384, y: 96, width: 192, height: 192 },
+            max_alive: 4,
+            respawn_interval: 1.5,
+            active_time: SpawnTime::Always,
+            min_player_distance: 96.0,
+            waves: Some(WaveConfig {
+                first_wave_size: 2,
+                size_increase: 1,
+                health_increase: 0.25,
+                wave_delay: 10.0,
+                max_waves: Some(5),
+            }),
+        }],
+        _ => Vec::new(),
+    }
+}
+
+/// In-progress fade between two zones
+///
+/// The screen fades to black, the zone is swapped while fully black,
+/// then the screen fades back in.
+#[derive(Debug, Clone)]
+pub struct ZoneTransition {
+    pub target_zone: String,
+    pub target_x: i32,
+    pub target_y: i32,
+    /// Time spent in the current half of the transition
+    pub timer: f32,
+    /// True once the zone has been swapped (fading in)
+    pub switched: bool,
+}
+
+impl ZoneTransition {
+    /// Starts a transition toward a portal's destination
+    pub fn from_portal(portal: &Portal) -> Self {
+        ZoneTransition {
+            target_zone: portal.target_zone.clone(),
+            target_x: portal.target_x,
+            target_y: portal.target_y,
+            timer: 0.0,
+            switched: false,
+        }
+    }
+
+    /// Opacity of the black fade overlay (0 = clear, 255 = black)
+    pub fn fade_alpha(&self) -> u8 {
+        let progress = (self.timer / ZONE_FADE_DURATION).clamp(0.0, 1.0);
+        let opacity = if self.switched { 1.0 - progress } else { progress };
+        (opacity * 255.0) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GAME_HEIGHT, GAME_WIDTH};
+
+    #[test]
+    fn test_built_in_spawners_fit_the_play_area() {
+        for zone_id in zone_ids() {
+            let spawners = built_in_spawners(zone_id);
+            assert!(!spawners.is_empty(), "{} has no spawners", zone_id);
+            for spawner in spawners {
+                let area = spawner.area;
+                assert!(
+                    area.x >= 0 && area.y >= 0
+                        && area.x + area.width as i32 <= GAME_WIDTH as i32
+                        && area.y + area.height as i32 <= GAME_HEIGHT as i32,
+                    "{} spawner {} is outside the play area", zone_id, spawner.id
+                );
+            }
+        }
+    }
+}
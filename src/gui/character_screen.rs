@@ -0,0 +1,165 @@
+//! Character Screen Component
+//!
+//! Shows the player's level, XP and the stats that level-up points can be
+//! spent on. Up/Down picks a stat and Enter spends a point on it; the stats
+//! themselves are raised by `Progression::allocate`.
+
+use crate::progression::{ALLOCATABLE_STATS, Progression};
+use crate::stats::{StatType, Stats};
+use crate::text::draw_simple_text;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+
+const PANEL_WIDTH: u32 = 420;
+const PANEL_HEIGHT: u32 = 300;
+const ROW_START_Y: u32 = 110;
+const ROW_HEIGHT: u32 = 28;
+
+/// State of the character screen
+///
+/// # Example
+///
+/// ```rust
+/// use crate::gui::CharacterScreen;
+///
+/// let mut character_screen = CharacterScreen::new();
+/// character_screen.is_open = true;
+///
+/// character_screen.navigate_down();
+/// player.progression.allocate(character_screen.selected_stat(), &mut player.stats);
+///
+/// character_screen.render(&mut canvas, &player.progression, &player.stats)?;
+/// ```
+pub struct CharacterScreen {
+    pub is_open: bool,
+    selected_index: usize,
+}
+
+impl CharacterScreen {
+    /// Creates a closed character screen with the first stat selected
+    pub fn new() -> Self {
+        CharacterScreen {
+            is_open: false,
+            selected_index: 0,
+        }
+    }
+
+    /// Navigate up (wraps to bottom)
+    pub fn navigate_up(&mut self) {
+        self.selected_index = (self.selected_index + ALLOCATABLE_STATS.len() - 1) % ALLOCATABLE_STATS.len();
+    }
+
+    /// Navigate down (wraps to top)
+    pub fn navigate_down(&mut self) {
+        self.selected_index = (self.selected_index + 1) % ALLOCATABLE_STATS.len();
+    }
+
+    /// The stat a point would be spent on
+    pub fn selected_stat(&self) -> StatType {
+        ALLOCATABLE_STATS[self.selected_index]
+    }
+
+    /// Render the screen centered over the game
+    pub fn render(&self, canvas: &mut Canvas<Window>, progression: &Progression, stats: &Stats) -> Result<(), String> {
+        canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+        canvas.set_draw_color(Color::RGBA(0, 0, 0, 160));
+        canvas.fill_rect(None)?;
+        canvas.set_blend_mode(sdl2::render::BlendMode::None);
+
+        let (screen_width, screen_height) = canvas.logical_size();
+        let panel_x = (screen_width - PANEL_WIDTH) as i32 / 2;
+        let panel_y = (screen_height - PANEL_HEIGHT) as i32 / 2;
+
+        canvas.set_draw_color(Color::RGB(20, 20, 30));
+        canvas.fill_rect(Rect::new(panel_x, panel_y, PANEL_WIDTH, PANEL_HEIGHT))?;
+        canvas.set_draw_color(Color::RGB(180, 150, 80));
+        canvas.draw_rect(Rect::new(panel_x, panel_y, PANEL_WIDTH, PANEL_HEIGHT))?;
+        canvas.draw_rect(Rect::new(panel_x + 2, panel_y + 2, PANEL_WIDTH - 4, PANEL_HEIGHT - 4))?;
+
+        draw_simple_text(canvas, "CHARACTER", panel_x + 20, panel_y + 15, Color::RGB(255, 220, 120), 3)?;
+
+        let level_text = format!(
+            "LEVEL {}  XP {}/{}",
+            progression.level,
+            progression.xp,
+            progression.xp_to_next_level()
+        );
+        draw_simple_text(canvas, &level_text, panel_x + 20, panel_y + 50, Color::RGB(220, 220, 230), 2)?;
+
+        let points_color = if progression.unspent_points > 0 {
+            Color::RGB(255, 255, 100)
+        } else {
+            Color::RGB(150, 150, 160)
+        };
+        let points_text = format!("POINTS: {}", progression.unspent_points);
+        draw_simple_text(canvas, &points_text, panel_x + 20, panel_y + 75, points_color, 2)?;
+
+        for (i, &stat) in ALLOCATABLE_STATS.iter().enumerate() {
+            let row_y = panel_y + (ROW_START_Y + i as u32 * ROW_HEIGHT) as i32;
+            let is_selected = i == self.selected_index;
+
+            if is_selected {
+                canvas.set_draw_color(Color::RGB(60, 50, 30));
+                canvas.fill_rect(Rect::new(panel_x + 10, row_y - 4, PANEL_WIDTH - 20, ROW_HEIGHT - 4))?;
+            }
+
+            let text_color = if !progression.can_allocate(stat) && progression.unspent_points > 0 {
+                Color::RGB(120, 120, 130) // Maxed out
+            } else if is_selected {
+                Color::RGB(255, 255, 255)
+            } else {
+                Color::RGB(180, 180, 190)
+            };
+
+            draw_simple_text(canvas, stat_label(stat), panel_x + 20, row_y, text_color, 2)?;
+            draw_simple_text(canvas, &stat_value(stat, stats), panel_x + 230, row_y, text_color, 2)?;
+
+            let spent = progression.allocations.get(stat);
+            if spent > 0 {
+                draw_simple_text(canvas, &format!("+{}", spent), panel_x + 330, row_y, Color::RGB(255, 220, 120), 2)?;
+            }
+        }
+
+        draw_simple_text(
+            canvas,
+            "UP/DOWN SELECT  ENTER SPEND  C CLOSE",
+            panel_x + 20,
+            panel_y + PANEL_HEIGHT as i32 - 25,
+            Color::RGB(120, 140, 160),
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Default for CharacterScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn stat_label(stat: StatType) -> &'static str {
+    match stat {
+        StatType::MaxHealth => "MAX HEALTH",
+        StatType::AttackDamage => "DAMAGE",
+        StatType::AttackSpeed => "ATTACK SPEED",
+        StatType::MovementSpeed => "MOVE SPEED",
+        StatType::Defense => "DEFENSE",
+        _ => "",
+    }
+}
+
+/// The stat's base value, formatted to show a single point's gain
+fn stat_value(stat: StatType, stats: &Stats) -> String {
+    match stat {
+        StatType::MaxHealth => format!("{}", stats.max_health as i32),
+        StatType::AttackDamage => format!("{:.1}", stats.attack_damage),
+        StatType::AttackSpeed => format!("{:.2}", stats.attack_speed),
+        StatType::MovementSpeed => format!("{:.1}", stats.movement_speed),
+        StatType::Defense => format!("{:.2}", stats.defense),
+        _ => String::new(),
+    }
+}
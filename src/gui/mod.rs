@@ -16,6 +16,7 @@
 //!
 //! - [`SaveExitMenu`] - Save and exit confirmation menu
 //! - [`DeathScreen`] - Death screen with respawn timer
+//! - [`CharacterScreen`] - Level, XP and stat point allocation
 //!
 //! # Example Usage
 //!
@@ -48,3 +49,6 @@ pub use death_screen::DeathScreen;
 
 pub mod inventory_ui;
 pub use inventory_ui::InventoryUI;
+
+pub mod character_screen;
+pub use character_screen::CharacterScreen;
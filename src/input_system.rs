@@ -11,10 +11,14 @@ use sdl2::EventPump;
 pub enum GameAction {
     // === Combat ===
     Attack,
+    Dodge,
+    ThrowItem,
 
     // === UI Navigation ===
     OpenInventory,
     CloseInventory,
+    OpenCharacterScreen,
+    CloseCharacterScreen,
     OpenDebugMenu,
     CloseDebugMenu,
     OpenExitMenu,
@@ -37,8 +41,18 @@ pub enum GameAction {
     LoadGame,
     ToggleCollisionBoxes,
     ToggleGridOverlay,
+    ToggleNavOverlay,
     TogglePause,
 
+    // === Map Editor ===
+    ToggleMapEditor,
+    EditorSelectTool(usize), // 0-5 for keys 1-6
+    EditorCycleVariant,      // next tile / pyramid type
+    EditorBrushSize(i32),    // -1 smaller, +1 larger
+    EditorUndo,
+    EditorRedo,
+    ExportMap,
+
     // === World Interaction ===
     SpawnSlime(i32, i32),  // x, y in world coordinates
     UseHoe(i32, i32),       // x, y for tile editing
@@ -62,12 +76,16 @@ pub enum InputContext {
     Playing,
     /// Inventory screen is open
     Inventory,
+    /// Character screen (level and stat points) is open
+    CharacterScreen,
     /// Exit/save menu is open
     ExitMenu,
     /// Death screen is displayed
     DeathScreen,
     /// Debug menu (F3) is open
     DebugMenu,
+    /// Map editor (F4) is open
+    MapEditor,
 }
 
 /// Helper struct to pass UI state without full Game borrow
@@ -76,7 +94,9 @@ pub enum InputContext {
 /// UI state during input processing.
 pub struct UIState {
     pub inventory_open: bool,
+    pub character_screen_open: bool,
     pub debug_menu_open: bool,
+    pub map_editor_open: bool,
     pub exit_menu_open: bool,
     pub death_screen_active: bool,
     pub game_state_dead: bool,
@@ -119,8 +139,10 @@ impl InputSystem {
     /// 1. DeathScreen - blocks all other input
     /// 2. ExitMenu - save/quit menu
     /// 3. Inventory - player inventory
-    /// 4. DebugMenu - F3 debug overlay
-    /// 5. Playing - normal gameplay
+    /// 4. CharacterScreen - level and stat points
+    /// 5. DebugMenu - F3 debug overlay
+    /// 6. MapEditor - F4 map editor
+    /// 7. Playing - normal gameplay
     pub fn update_context(&mut self, ui_state: &UIState) {
         self.context = if ui_state.death_screen_active || ui_state.game_state_dead {
             InputContext::DeathScreen
@@ -128,8 +150,12 @@ impl InputSystem {
             InputContext::ExitMenu
         } else if ui_state.inventory_open {
             InputContext::Inventory
+        } else if ui_state.character_screen_open {
+            InputContext::CharacterScreen
         } else if ui_state.debug_menu_open {
             InputContext::DebugMenu
+        } else if ui_state.map_editor_open {
+            InputContext::MapEditor
         } else {
             InputContext::Playing
         };
@@ -192,9 +218,11 @@ impl InputSystem {
         match self.context {
             InputContext::Playing => self.handle_playing_keys(key, actions),
             InputContext::Inventory => self.handle_inventory_keys(key, actions),
+            InputContext::CharacterScreen => self.handle_character_screen_keys(key, actions),
             InputContext::ExitMenu => self.handle_exit_menu_keys(key, actions),
             InputContext::DeathScreen => self.handle_death_screen_keys(key, actions),
             InputContext::DebugMenu => self.handle_debug_menu_keys(key, keymod, actions),
+            InputContext::MapEditor => self.handle_map_editor_keys(key, keymod, actions),
         }
     }
 
@@ -203,17 +231,22 @@ impl InputSystem {
         match key {
             // Combat
             Keycode::M => actions.push(GameAction::Attack),
+            Keycode::Space => actions.push(GameAction::Dodge),
+            Keycode::T => actions.push(GameAction::ThrowItem),
 
             // UI
             Keycode::I => actions.push(GameAction::OpenInventory),
+            Keycode::C => actions.push(GameAction::OpenCharacterScreen),
             Keycode::Escape => actions.push(GameAction::OpenExitMenu),
             Keycode::F3 => actions.push(GameAction::OpenDebugMenu),
+            Keycode::F4 => actions.push(GameAction::ToggleMapEditor),
 
             // Debug commands
             Keycode::F5 => actions.push(GameAction::SaveGame),
             Keycode::F9 => actions.push(GameAction::LoadGame),
             Keycode::B => actions.push(GameAction::ToggleCollisionBoxes),
             Keycode::G => actions.push(GameAction::ToggleGridOverlay),
+            Keycode::N => actions.push(GameAction::ToggleNavOverlay),
             Keycode::P => actions.push(GameAction::TogglePause),
 
             // Hotbar slot selection
@@ -257,6 +290,27 @@ impl InputSystem {
         }
     }
 
+    /// Handle keys when the character screen is open
+    fn handle_character_screen_keys(&self, key: Keycode, actions: &mut Vec<GameAction>) {
+        match key {
+            Keycode::C | Keycode::Escape => {
+                actions.push(GameAction::CloseCharacterScreen);
+            }
+            Keycode::Up => {
+                actions.push(GameAction::MenuUp);
+            }
+            Keycode::Down => {
+                actions.push(GameAction::MenuDown);
+            }
+            Keycode::Return | Keycode::Space => {
+                actions.push(GameAction::MenuConfirm);
+            }
+            _ => {
+                // Other keys ignored
+            }
+        }
+    }
+
     /// Handle keys when exit menu is open
     fn handle_exit_menu_keys(&self, key: Keycode, actions: &mut Vec<GameAction>) {
         match key {
@@ -314,6 +368,47 @@ impl InputSystem {
         }
     }
 
+    /// Handle keys when the map editor is open
+    ///
+    /// Ctrl+Z undo, Ctrl+Y / Ctrl+Shift+Z redo, Ctrl+S export map
+    fn handle_map_editor_keys(&self, key: Keycode, keymod: sdl2::keyboard::Mod, actions: &mut Vec<GameAction>) {
+        use sdl2::keyboard::Mod;
+
+        let ctrl_held = keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD);
+        let shift_held = keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD);
+
+        match key {
+            Keycode::F4 | Keycode::Escape => actions.push(GameAction::ToggleMapEditor),
+            Keycode::F3 => actions.push(GameAction::OpenDebugMenu),
+
+            // Tools
+            Keycode::Num1 => actions.push(GameAction::EditorSelectTool(0)),
+            Keycode::Num2 => actions.push(GameAction::EditorSelectTool(1)),
+            Keycode::Num3 => actions.push(GameAction::EditorSelectTool(2)),
+            Keycode::Num4 => actions.push(GameAction::EditorSelectTool(3)),
+            Keycode::Num5 => actions.push(GameAction::EditorSelectTool(4)),
+            Keycode::Num6 => actions.push(GameAction::EditorSelectTool(5)),
+            Keycode::T => actions.push(GameAction::EditorCycleVariant),
+            Keycode::LeftBracket => actions.push(GameAction::EditorBrushSize(-1)),
+            Keycode::RightBracket => actions.push(GameAction::EditorBrushSize(1)),
+
+            // History and export
+            Keycode::Z if ctrl_held && shift_held => actions.push(GameAction::EditorRedo),
+            Keycode::Z if ctrl_held => actions.push(GameAction::EditorUndo),
+            Keycode::Y if ctrl_held => actions.push(GameAction::EditorRedo),
+            Keycode::S if ctrl_held => actions.push(GameAction::ExportMap),
+
+            // Debug overlays stay available while editing
+            Keycode::B => actions.push(GameAction::ToggleCollisionBoxes),
+            Keycode::G => actions.push(GameAction::ToggleGridOverlay),
+            Keycode::N => actions.push(GameAction::ToggleNavOverlay),
+
+            _ => {
+                // Other keys ignored
+            }
+        }
+    }
+
     /// Handle mouse button press events
     fn handle_mouse_down(
         &self,
@@ -377,7 +472,9 @@ mod tests {
         // Simulate opening inventory
         let ui_state = UIState {
             inventory_open: true,
+            character_screen_open: false,
             debug_menu_open: false,
+            map_editor_open: false,
             exit_menu_open: false,
             death_screen_active: false,
             game_state_dead: false,
@@ -389,7 +486,9 @@ mod tests {
         // Simulate opening debug menu
         let ui_state = UIState {
             inventory_open: false,
+            character_screen_open: false,
             debug_menu_open: true,
+            map_editor_open: false,
             exit_menu_open: false,
             death_screen_active: false,
             game_state_dead: false,
@@ -397,6 +496,34 @@ mod tests {
         };
         input.update_context(&ui_state);
         assert_eq!(input.context, InputContext::DebugMenu);
+
+        // Simulate opening the map editor
+        let ui_state = UIState {
+            inventory_open: false,
+            character_screen_open: false,
+            debug_menu_open: false,
+            map_editor_open: true,
+            exit_menu_open: false,
+            death_screen_active: false,
+            game_state_dead: false,
+            game_state_exit_menu: false,
+        };
+        input.update_context(&ui_state);
+        assert_eq!(input.context, InputContext::MapEditor);
+
+        // Simulate opening the character screen (above the debug menu)
+        let ui_state = UIState {
+            inventory_open: false,
+            character_screen_open: true,
+            debug_menu_open: true,
+            map_editor_open: false,
+            exit_menu_open: false,
+            death_screen_active: false,
+            game_state_dead: false,
+            game_state_exit_menu: false,
+        };
+        input.update_context(&ui_state);
+        assert_eq!(input.context, InputContext::CharacterScreen);
     }
 
     #[test]
@@ -406,7 +533,9 @@ mod tests {
         // Death screen has highest priority
         let ui_state = UIState {
             inventory_open: true,
+            character_screen_open: false,
             debug_menu_open: true,
+            map_editor_open: false,
             exit_menu_open: true,
             death_screen_active: false,
             game_state_dead: true,
@@ -418,7 +547,9 @@ mod tests {
         // Exit menu has next priority
         let ui_state = UIState {
             inventory_open: true,
+            character_screen_open: false,
             debug_menu_open: true,
+            map_editor_open: false,
             exit_menu_open: true,
             death_screen_active: false,
             game_state_dead: false,
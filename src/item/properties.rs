@@ -33,6 +33,11 @@ pub enum ItemProperties {
     Block {
         block_id: String,  // ID of block type to place
     },
+
+    /// Seed item (plant on tilled soil)
+    Seed {
+        crop_id: String,  // ID of crop type to plant (see farming::CropType)
+    },
 }
 
 /// Effects for consumable items
@@ -74,5 +79,17 @@ pub enum ToolType {
     Axe,
     Shovel,
     Hoe,
+    WateringCan,  // Waters tilled soil so crops can grow
     Sword,  // Weapon tool
 }
+
+impl ToolType {
+    /// The weapon profile (assets/weapons/<id>.json) this tool attacks with, if any
+    pub fn weapon_id(&self) -> Option<&'static str> {
+        match self {
+            ToolType::Hoe => Some("hoe"),
+            ToolType::Sword => Some("sword"),
+            _ => None,
+        }
+    }
+}
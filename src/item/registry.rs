@@ -119,6 +119,78 @@ impl ItemRegistry {
             },
         )).expect("Failed to register hoe");
 
+        // Sword (weapon, dropped by the Slime King)
+        self.register(ItemDefinition::new(
+            "sword",
+            "Sword",
+            "A king's blade, won from the Slime King.",
+            "assets/items/sword.png",
+            1,  // Non-stackable (tools don't stack)
+            ItemProperties::Tool {
+                tool_type: ToolType::Sword,
+                durability: 250,
+                mining_speed: 1.0,
+            },
+        )).expect("Failed to register sword");
+
+        // Watering Can (farming tool, waters tilled soil)
+        self.register(ItemDefinition::new(
+            "watering_can",
+            "Watering Can",
+            "Waters tilled soil so crops can grow.",
+            "assets/items/watering_can.png",
+            1,  // Non-stackable (tools don't stack)
+            ItemProperties::Tool {
+                tool_type: ToolType::WateringCan,
+                durability: 100,
+                mining_speed: 1.0,
+            },
+        )).expect("Failed to register watering_can");
+
+        // Wheat Seeds (plant on tilled soil)
+        self.register(ItemDefinition::new(
+            "wheat_seeds",
+            "Wheat Seeds",
+            "Plant on tilled soil. Grows into wheat.",
+            "assets/items/wheat_seeds.png",
+            64,  // Max stack size
+            ItemProperties::Seed {
+                crop_id: "wheat".to_string(),
+            },
+        )).expect("Failed to register wheat_seeds");
+
+        // Wheat (harvested from mature wheat crops)
+        self.register(ItemDefinition::new(
+            "wheat",
+            "Wheat",
+            "A bundle of golden wheat.",
+            "assets/items/wheat.png",
+            64,  // Max stack size
+            ItemProperties::Material,
+        )).expect("Failed to register wheat");
+
+        // Carrot Seeds (plant on tilled soil)
+        self.register(ItemDefinition::new(
+            "carrot_seeds",
+            "Carrot Seeds",
+            "Plant on tilled soil. Grows into carrots.",
+            "assets/items/carrot_seeds.png",
+            64,  // Max stack size
+            ItemProperties::Seed {
+                crop_id: "carrot".to_string(),
+            },
+        )).expect("Failed to register carrot_seeds");
+
+        // Carrot (harvested from mature carrot crops)
+        self.register(ItemDefinition::new(
+            "carrot",
+            "Carrot",
+            "A crunchy orange carrot.",
+            "assets/items/carrot.png",
+            64,  // Max stack size
+            ItemProperties::Material,
+        )).expect("Failed to register carrot");
+
         // Add more items here as they're created
         // Example: Health Potion
         // self.register(ItemDefinition::new(
@@ -0,0 +1,127 @@
+//! Loot tables for item drops
+//!
+//! A loot table is a list of possible drops. Each entry rolls independently:
+//! first against its drop chance, then for a quantity in `[min, max]`.
+//!
+//! # Example
+//!
+//! ```rust
+//! let table = LootTable::new()
+//!     .with_entry("wheat", 1, 2, 1.0)        // Always 1-2 wheat
+//!     .with_entry("wheat_seeds", 1, 1, 0.5); // 50% chance of a seed
+//!
+//! for (item_id, quantity) in table.roll(&mut rng) {
+//!     world.spawn_dropped_item(x, y, item_id, quantity, texture)?;
+//! }
+//! ```
+
+use crate::rng::SimpleRng;
+use serde::{Deserialize, Serialize};
+
+/// A single possible drop in a loot table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LootEntry {
+    /// Item ID from the ItemRegistry
+    pub item_id: String,
+    /// Minimum quantity dropped (inclusive)
+    pub min: u32,
+    /// Maximum quantity dropped (inclusive)
+    pub max: u32,
+    /// Probability this entry drops at all (0.0-1.0)
+    pub chance: f32,
+}
+
+/// Collection of independent loot entries
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LootTable {
+    pub entries: Vec<LootEntry>,
+}
+
+impl LootTable {
+    /// Creates an empty loot table (drops nothing)
+    pub fn new() -> Self {
+        LootTable { entries: Vec::new() }
+    }
+
+    /// Adds an entry to the table (builder style)
+    pub fn with_entry(mut self, item_id: impl Into<String>, min: u32, max: u32, chance: f32) -> Self {
+        self.entries.push(LootEntry {
+            item_id: item_id.into(),
+            min,
+            max: max.max(min),
+            chance: chance.clamp(0.0, 1.0),
+        });
+        self
+    }
+
+    /// Rolls every entry and returns the resulting drops
+    ///
+    /// Entries that fail their chance roll or roll a quantity of 0 are omitted.
+    pub fn roll(&self, rng: &mut SimpleRng) -> Vec<(String, u32)> {
+        let mut drops = Vec::new();
+
+        for entry in &self.entries {
+            if !rng.chance(entry.chance) {
+                continue;
+            }
+
+            let quantity = rng.range_u32(entry.min, entry.max);
+            if quantity > 0 {
+                drops.push((entry.item_id.clone(), quantity));
+            }
+        }
+
+        drops
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_table_drops_nothing() {
+        let mut rng = SimpleRng::new(1);
+        assert!(LootTable::new().roll(&mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_guaranteed_entry_always_drops() {
+        let table = LootTable::new().with_entry("wheat", 2, 2, 1.0);
+        let mut rng = SimpleRng::new(5);
+        for _ in 0..50 {
+            assert_eq!(table.roll(&mut rng), vec![("wheat".to_string(), 2)]);
+        }
+    }
+
+    #[test]
+    fn test_zero_chance_never_drops() {
+        let table = LootTable::new().with_entry("stone", 1, 5, 0.0);
+        let mut rng = SimpleRng::new(9);
+        for _ in 0..50 {
+            assert!(table.roll(&mut rng).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_quantity_within_range() {
+        let table = LootTable::new().with_entry("slime_ball", 1, 3, 1.0);
+        let mut rng = SimpleRng::new(13);
+        for _ in 0..200 {
+            let drops = table.roll(&mut rng);
+            assert!((1..=3).contains(&drops[0].1));
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_drops() {
+        let table = LootTable::new()
+            .with_entry("wheat", 1, 3, 1.0)
+            .with_entry("wheat_seeds", 1, 2, 0.5);
+        let mut a = SimpleRng::new(77);
+        let mut b = SimpleRng::new(77);
+        for _ in 0..20 {
+            assert_eq!(table.roll(&mut a), table.roll(&mut b));
+        }
+    }
+}
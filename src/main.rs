@@ -4,44 +4,69 @@ use sdl2::rect::Rect;
 
 mod animation;
 mod attack_effect;
+mod buffs;
+mod clock;
 mod collision;
 mod combat;
+mod combo;
 mod dropped_item;
+mod enemy;
+mod farming;
 mod game;
 mod gui;
 mod input_system;
 mod inventory;
 mod item;
+mod loot;
+mod map;
+mod pathfinding;
+mod physics;
 mod player;
+mod progression;
+mod projectile;
+mod pyramid;
 mod render;
+mod rng;
 mod save;
-mod slime;
+mod spatial;
 mod sprite;
 mod stats;
+mod status;
 mod text;
 mod the_entity;
 mod tile;
+mod tiled;
 mod ui;
+mod weapon;
 
 use animation::{AnimationConfig, AnimationController};
+use clock::GameClock;
 use collision::{
-    calculate_overlap, check_collisions_with_collection, check_static_collisions, Collidable,
+    calculate_overlap, detect_collisions, Collidable, Collider, CollisionEvent, CollisionLayer,
     StaticCollidable,
 };
-use combat::{DamageEvent, DamageSource};
+use combat::{DamageEvent, DamageSource, DamageType};
 use dropped_item::DroppedItem;
-use gui::{SaveExitMenu, SaveExitOption, DeathScreen, InventoryUI};
+use farming::{CropType, Farm, FarmPlot};
+use gui::{SaveExitMenu, SaveExitOption, DeathScreen, InventoryUI, CharacterScreen};
 use inventory::PlayerInventory;
 use item::{ItemRegistry, ItemProperties, ToolType};
+use pathfinding::NAV_TILE_SIZE;
 use player::Player;
+use projectile::Projectile;
+use pyramid::{PyramidLink, PyramidRegistry};
 use render::render_with_depth_sorting;
-use save::{SaveManager, SaveFile, SaveMetadata, SaveType, WorldSaveData, EntitySaveData, Saveable, SaveData, CURRENT_SAVE_VERSION};
-use slime::Slime;
+use save::{SaveManager, SaveFile, SaveMetadata, SaveType, WorldSaveData, EntitySaveData, ZoneSaveData, ZoneLayoutSaveData, Saveable, SaveData, CURRENT_SAVE_VERSION};
+use enemy::{Enemy, EnemyRegistry, SpawnerEvent};
+use enemy::boss::BossConfig;
 use sprite::SpriteSheet;
+use stats::{DamageResult, StatType};
+use status::{StatusEffect, StatusKind};
 use text::draw_simple_text;
 use the_entity::{TheEntity, EntityState, EntityType};
-use tile::{TileId, WorldGrid, RenderGrid};
-use ui::{HealthBar, HealthBarStyle, FloatingText, BuffDisplay};
+use tile::{TileId, WorldGrid};
+use ui::{HealthBar, HealthBarStyle, FloatingText, BuffDisplay, format_amount};
+use weapon::WeaponRegistry;
 use std::time::{SystemTime, Instant};
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -49,12 +74,40 @@ use std::collections::HashMap;
 // Import from game module
 use game::{GameState, DebugMenuState, DebugMenuItem, DebugConfig,
            GameTextures, GameWorld, Systems, UIManager};
+use game::editor::MapEditor;
+use game::encounter::BossEncounter;
+use game::zone::{self, Zone, MEADOW_ZONE_ID};
 
 // Game resolution constants
 const GAME_WIDTH: u32 = 640;
 const GAME_HEIGHT: u32 = 360;
 const SPRITE_SCALE: u32 = 2;
 
+// Ambient enemy spawning (seconds between spawns, max alive)
+const DAY_ENEMY_SPAWN_INTERVAL: f32 = 30.0;
+const DAY_MAX_ENEMIES: usize = 3;
+const NIGHT_ENEMY_SPAWN_INTERVAL: f32 = 8.0;
+const NIGHT_MAX_ENEMIES: usize = 8;
+const MIN_ENEMY_SPAWN_DISTANCE: f32 = 120.0;
+
+// Thrown slime balls (T key)
+const THROW_ITEM_ID: &str = "slime_ball";
+const THROW_SPEED: f32 = 260.0;
+const THROW_DAMAGE: f32 = 2.0;
+const THROW_RANGE: f32 = 180.0;
+const THROW_COOLDOWN: f32 = 0.4;
+const THROW_KNOCKBACK: f32 = 140.0;
+/// Sticky slime slows what it hits: (speed fraction lost, seconds)
+const THROW_SLOW: (f32, f32) = (0.4, 2.0);
+
+/// Distance from the boss at which its minions appear
+const BOSS_SUMMON_RADIUS: f32 = 56.0;
+
+// Boss health bar (screen-wide, just above the hotbar)
+const BOSS_BAR_MARGIN: u32 = 40;
+const BOSS_BAR_HEIGHT: u32 = 8;
+const BOSS_BAR_Y: i32 = 292;
+
 // Extracted to game/types.rs
 // GameWorld struct and impl extracted to game/world.rs
 // Systems struct and impl extracted to game/systems.rs
@@ -88,7 +141,9 @@ impl<'a> Game<'a> {
         // Build UI state for input system
         let ui_state = input_system::UIState {
             inventory_open: self.ui.inventory_ui.is_open,
+            character_screen_open: self.ui.character_screen.is_open,
             debug_menu_open: matches!(self.ui.debug_menu_state, DebugMenuState::Open { .. }),
+            map_editor_open: self.ui.map_editor.is_open,
             exit_menu_open: self.game_state == GameState::ExitMenu,
             death_screen_active: self.game_state == GameState::Dead,
             game_state_dead: self.game_state == GameState::Dead,
@@ -130,15 +185,7 @@ impl<'a> Game<'a> {
             }
 
             GameAction::SaveAndExit => {
-                if let Err(e) = save_game(
-                    &mut self.save_manager,
-                    &self.world.player,
-                    &self.world.slimes,
-                    &self.world.world_grid,
-                    &self.world.entities,
-                    &self.world.player_inventory,
-                    &self.world.dropped_items,
-                ) {
+                if let Err(e) = save_game(&mut self.save_manager, &self.world, &self.systems.clock) {
                     eprintln!("Failed to save: {}", e);
                 }
                 return Ok(true);
@@ -153,10 +200,20 @@ impl<'a> Game<'a> {
                 self.ui.inventory_ui.is_open = false;
             }
 
+            GameAction::OpenCharacterScreen => {
+                self.ui.character_screen.is_open = true;
+            }
+
+            GameAction::CloseCharacterScreen => {
+                self.ui.character_screen.is_open = false;
+            }
+
             GameAction::OpenExitMenu => {
                 // Close other UIs first
                 if self.ui.inventory_ui.is_open {
                     self.ui.inventory_ui.is_open = false;
+                } else if self.ui.character_screen.is_open {
+                    self.ui.character_screen.is_open = false;
                 } else if matches!(self.ui.debug_menu_state, DebugMenuState::Open { .. }) {
                     self.ui.debug_menu_state = DebugMenuState::Closed;
                 } else {
@@ -182,6 +239,8 @@ impl<'a> Game<'a> {
             GameAction::MenuUp => {
                 if self.game_state == GameState::ExitMenu {
                     self.ui.save_exit_menu.navigate_up();
+                } else if self.ui.character_screen.is_open {
+                    self.ui.character_screen.navigate_up();
                 } else if let DebugMenuState::Open { selected_index } = self.ui.debug_menu_state {
                     let items = DebugMenuItem::all();
                     let new_index = if selected_index == 0 {
@@ -198,6 +257,8 @@ impl<'a> Game<'a> {
             GameAction::MenuDown => {
                 if self.game_state == GameState::ExitMenu {
                     self.ui.save_exit_menu.navigate_down();
+                } else if self.ui.character_screen.is_open {
+                    self.ui.character_screen.navigate_down();
                 } else if let DebugMenuState::Open { selected_index } = self.ui.debug_menu_state {
                     let items = DebugMenuItem::all();
                     let new_index = (selected_index + 1) % items.len();
@@ -225,15 +286,7 @@ impl<'a> Game<'a> {
                 if self.game_state == GameState::ExitMenu {
                     match self.ui.save_exit_menu.selected_option() {
                         SaveExitOption::SaveAndExit => {
-                            if let Err(e) = save_game(
-                                &mut self.save_manager,
-                                &self.world.player,
-                                &self.world.slimes,
-                                &self.world.world_grid,
-                                &self.world.entities,
-                                &self.world.player_inventory,
-                                &self.world.dropped_items,
-                            ) {
+                            if let Err(e) = save_game(&mut self.save_manager, &self.world, &self.systems.clock) {
                                 eprintln!("Failed to save: {}", e);
                             }
                             return Ok(true);
@@ -242,6 +295,10 @@ impl<'a> Game<'a> {
                             self.game_state = GameState::Playing;
                         }
                     }
+                } else if self.ui.character_screen.is_open {
+                    let stat = self.ui.character_screen.selected_stat();
+                    let player = &mut self.world.player;
+                    player.progression.allocate(stat, &mut player.stats);
                 } else if let DebugMenuState::Open { selected_index } = self.ui.debug_menu_state {
                     let items = DebugMenuItem::all();
                     if items[selected_index] == DebugMenuItem::ClearInventory {
@@ -255,16 +312,21 @@ impl<'a> Game<'a> {
             GameAction::Attack => {
                 if self.game_state == GameState::Playing {
                     if self.world.active_attack.is_none() {
-                        if let Some(attack_event) = self.world.player.start_attack() {
+                        let held_item = self.world.player_inventory.get_selected_hotbar()
+                            .and_then(|selected_item| self.item_registry.get(&selected_item.item_id));
+                        let weapon = self.systems.weapon_registry.for_item(held_item);
+
+                        if let Some(attack_event) = self.world.player.start_attack(weapon) {
                             self.world.active_attack = Some(attack_event.clone());
 
-                            // Create visual punch effect
+                            // Create the weapon's visual effect
                             // Calculate player's visual center from anchor
-                            let player_center_y = self.world.player.y
+                            let (player_x, player_y) = self.world.player.position();
+                            let player_center_y = player_y
                                 - (self.world.player.height * SPRITE_SCALE) as i32 / 2;
 
                             // Directional offset from player center
-                            let offset = 20;
+                            let offset = weapon.effect.offset;
                             let (offset_x, offset_y) = match self.world.player.direction {
                                 crate::animation::Direction::North => (0, -offset),
                                 crate::animation::Direction::NorthEast => (offset, -offset),
@@ -277,30 +339,32 @@ impl<'a> Game<'a> {
                             };
 
                             // Calculate effect center position
-                            let effect_center_x = self.world.player.x + offset_x;
+                            let effect_center_x = player_x + offset_x;
                             let effect_center_y = player_center_y + offset_y;
 
                             // Convert to top-left (AttackEffect uses top-left positioning)
-                            let effect_size = 32 * SPRITE_SCALE as i32;
+                            let frame_size = weapon.effect.frame_size;
+                            let effect_size = (frame_size * SPRITE_SCALE) as i32;
                             let effect_x = effect_center_x - effect_size / 2;
                             let effect_y = effect_center_y - effect_size / 2;
 
-                            match self.systems.punch_config.create_controller(
-                                self.textures.punch,
-                                &["punch"],
+                            match self.systems.weapon_registry.create_effect_controller(
+                                weapon,
+                                attack_event.combo_step,
+                                self.textures.weapon_effects,
                             ) {
-                                Ok(punch_animation_controller) => {
+                                Ok(effect_animation_controller) => {
                                     self.world.spawn_attack_effect(
                                         effect_x,
                                         effect_y,
-                                        32,
-                                        32,
-                                        self.world.player.direction,
-                                        punch_animation_controller,
+                                        frame_size,
+                                        frame_size,
+                                        &attack_event,
+                                        effect_animation_controller,
                                     );
                                 }
                                 Err(e) => {
-                                    eprintln!("ERROR: Failed to create punch controller: {}", e);
+                                    eprintln!("ERROR: Failed to create attack effect controller: {}", e);
                                 }
                             }
                         }
@@ -308,48 +372,26 @@ impl<'a> Game<'a> {
                 }
             }
 
+            GameAction::Dodge if self.game_state == GameState::Playing => {
+                self.world.player.start_dodge();
+            }
+
+            GameAction::ThrowItem if self.game_state == GameState::Playing => {
+                self.throw_item();
+            }
+
             // === Debug Commands ===
             GameAction::SaveGame => {
-                if let Err(e) = save_game(
-                    &mut self.save_manager,
-                    &self.world.player,
-                    &self.world.slimes,
-                    &self.world.world_grid,
-                    &self.world.entities,
-                    &self.world.player_inventory,
-                    &self.world.dropped_items,
-                ) {
+                if let Err(e) = save_game(&mut self.save_manager, &self.world, &self.systems.clock) {
                     eprintln!("Failed to save: {}", e);
                 }
             }
 
             GameAction::LoadGame => {
-                match load_game(
-                    &self.save_manager,
-                    &self.systems.player_config,
-                    &self.systems.slime_config,
-                    self.textures.character,
-                    self.textures.slime,
-                    self.textures.entity,
-                    self.textures.items,
-                ) {
-                    Ok((
-                        loaded_player,
-                        loaded_slimes,
-                        loaded_world,
-                        loaded_entities,
-                        loaded_inventory,
-                        loaded_items,
-                    )) => {
-                        self.world.player = loaded_player;
-                        self.world.slimes = loaded_slimes;
-                        self.world.world_grid = loaded_world;
-                        self.world.render_grid = RenderGrid::new(&self.world.world_grid);
-                        self.world.entities = loaded_entities;
-                        self.world.player_inventory = loaded_inventory;
-                        self.world.dropped_items = loaded_items;
-                        self.world.attack_effects.clear();
-                        self.world.active_attack = None;
+                match load_game(&self.save_manager, &self.systems, &self.textures) {
+                    Ok((loaded_world, loaded_clock)) => {
+                        self.world = loaded_world;
+                        self.systems.clock = loaded_clock;
                         println!("✓ Game loaded successfully!");
                     }
                     Err(e) => {
@@ -382,9 +424,58 @@ impl<'a> Game<'a> {
                 );
             }
 
+            GameAction::ToggleNavOverlay => {
+                self.ui.show_nav_paths = !self.ui.show_nav_paths;
+                println!(
+                    "Navigation overlay: {}",
+                    if self.ui.show_nav_paths {
+                        "ON"
+                    } else {
+                        "OFF"
+                    }
+                );
+            }
+
             GameAction::TogglePause => {
-                // Pause functionality not yet implemented
-                println!("Pause toggle not yet implemented");
+                let paused = self.systems.clock.toggle_pause();
+                println!("Game clock: {}", if paused { "PAUSED" } else { "RUNNING" });
+            }
+
+            // === Map Editor ===
+            GameAction::ToggleMapEditor => {
+                let open = self.ui.map_editor.toggle();
+                self.ui.is_tilling = false;
+                self.ui.last_tilled_tile = None;
+                self.ui.inventory_ui.is_open = false;
+                println!("Map editor: {}", if open { "ON" } else { "OFF" });
+            }
+
+            GameAction::EditorSelectTool(index) => {
+                self.ui.map_editor.select_tool(index);
+            }
+
+            GameAction::EditorCycleVariant => {
+                self.ui.map_editor.cycle_variant();
+            }
+
+            GameAction::EditorBrushSize(delta) => {
+                self.ui.map_editor.change_brush_size(delta);
+            }
+
+            GameAction::EditorUndo => {
+                self.ui.map_editor.undo(&mut self.world, &self.systems.pyramid_registry, self.textures.entity);
+            }
+
+            GameAction::EditorRedo => {
+                self.ui.map_editor.redo(&mut self.world, &self.systems.pyramid_registry, self.textures.entity);
+            }
+
+            GameAction::ExportMap => {
+                let map_path = map::map_path(&self.world.zone_id);
+                match self.world.to_map_file().save(&map_path) {
+                    Ok(()) => println!("✓ Map exported to {}", map_path.display()),
+                    Err(e) => eprintln!("Failed to export map: {}", e),
+                }
             }
 
             // === Mouse Clicks ===
@@ -393,9 +484,10 @@ impl<'a> Game<'a> {
             }
 
             GameAction::LeftClickRelease => {
-                // Stop tilling when mouse button is released
+                // Stop tilling (or finish an editor paint stroke) when mouse button is released
                 self.ui.is_tilling = false;
                 self.ui.last_tilled_tile = None;
+                self.ui.map_editor.end_stroke();
             }
 
             GameAction::RightClick(x, y) => {
@@ -407,9 +499,14 @@ impl<'a> Game<'a> {
                 self.ui.mouse_x = x;
                 self.ui.mouse_y = y;
 
+                if self.ui.map_editor.is_open {
+                    self.ui.map_editor.drag(&mut self.world, x, y);
+                }
+
                 // Handle tilling while dragging with hoe equipped
                 if self.ui.is_tilling && self.game_state == GameState::Playing {
                     let is_ui_active = self.ui.inventory_ui.is_open
+                        || self.ui.character_screen.is_open
                         || matches!(self.ui.debug_menu_state, DebugMenuState::Open { .. })
                         || self.game_state == GameState::ExitMenu;
 
@@ -417,13 +514,10 @@ impl<'a> Game<'a> {
                         let tile_x = x / 32;
                         let tile_y = y / 32;
 
-                        if self.ui.last_tilled_tile != Some((tile_x, tile_y)) {
-                            if self.world.world_grid.get_tile(tile_x, tile_y) == Some(TileId::Grass) {
-                                if self.world.world_grid.set_tile(tile_x, tile_y, TileId::Dirt) {
-                                    self.world.render_grid.update_tile_and_neighbors(&self.world.world_grid, tile_x, tile_y);
-                                    self.ui.last_tilled_tile = Some((tile_x, tile_y));
-                                }
-                            }
+                        if self.ui.last_tilled_tile != Some((tile_x, tile_y))
+                            && self.world.till_tile(tile_x, tile_y)
+                        {
+                            self.ui.last_tilled_tile = Some((tile_x, tile_y));
                         }
                     }
                 }
@@ -462,13 +556,36 @@ impl<'a> Game<'a> {
                 self.world.player.stats.attack_speed =
                     (self.world.player.stats.attack_speed + delta).max(0.1);
             }
-            DebugMenuItem::SlimeHealth => {
-                self.systems.debug_config.slime_base_health =
-                    (self.systems.debug_config.slime_base_health as f32 + delta).max(1.0) as i32;
+            DebugMenuItem::EnemyHealthScale => {
+                self.systems.debug_config.enemy_health_scale =
+                    (self.systems.debug_config.enemy_health_scale + delta * 0.25).max(0.25);
+            }
+            DebugMenuItem::EnemyDamageScale => {
+                self.systems.debug_config.enemy_damage_scale =
+                    (self.systems.debug_config.enemy_damage_scale + delta * 0.25).max(0.0);
             }
-            DebugMenuItem::SlimeContactDamage => {
-                self.systems.debug_config.slime_contact_damage =
-                    (self.systems.debug_config.slime_contact_damage + delta).max(0.0);
+            DebugMenuItem::SpawnEnemyType => {
+                // Cycle through the registered enemy types
+                let ids = self.systems.enemy_registry.ids();
+                let current = ids.iter()
+                    .position(|id| *id == self.systems.debug_config.spawn_enemy_type)
+                    .unwrap_or(0);
+                let next = (current as i32 + delta.signum() as i32).rem_euclid(ids.len() as i32) as usize;
+                self.systems.debug_config.spawn_enemy_type = ids[next].to_string();
+            }
+            DebugMenuItem::TimeScale => {
+                let new_scale = self.systems.clock.time_scale + delta;
+                self.systems.clock.set_time_scale(new_scale);
+            }
+            DebugMenuItem::TimeOfDay => {
+                let hour = (self.systems.clock.hour() as i32 + delta as i32).rem_euclid(24);
+                self.systems.clock.set_time_of_day(hour as u32, 0);
+            }
+            DebugMenuItem::GrantXp => {
+                // XP only goes up: right grants 10 (shift: 100)
+                if delta > 0.0 {
+                    self.award_xp(delta as u32 * 10);
+                }
             }
             DebugMenuItem::ClearInventory => {
                 // This is an action, not a value
@@ -478,6 +595,16 @@ impl<'a> Game<'a> {
 
     /// Helper: Handle left mouse click
     fn handle_left_click(&mut self, x: i32, y: i32, shift_held: bool) -> Result<(), String> {
+        // Map editor takes over the mouse while open
+        if self.ui.map_editor.is_open {
+            if self.game_state == GameState::Playing
+                && !matches!(self.ui.debug_menu_state, DebugMenuState::Open { .. })
+            {
+                self.ui.map_editor.primary_click(&mut self.world, x, y, &self.systems.pyramid_registry, self.textures.entity);
+            }
+            return Ok(());
+        }
+
         // Handle inventory/hotbar clicks (using existing UI system)
         if self.game_state == GameState::Playing {
             let (screen_width, screen_height) = self.canvas.logical_size();
@@ -491,31 +618,64 @@ impl<'a> Game<'a> {
                 sdl2::mouse::MouseButton::Left,
             )?;
 
-            // Check if player has a hoe selected and is clicking in the world (not UI)
+            // Check if player is clicking in the world (not UI) with a farming item
             let is_ui_active = self.ui.inventory_ui.is_open
+                || self.ui.character_screen.is_open
                 || matches!(self.ui.debug_menu_state, DebugMenuState::Open { .. })
-                || self.game_state == GameState::ExitMenu;
+                || self.game_state == GameState::ExitMenu
+                || self.ui.inventory_ui.is_mouse_over_any_inventory(x, y, screen_width, screen_height);
 
             if !is_ui_active {
-                if let Some(selected_item) = self.world.player_inventory.get_selected_hotbar() {
-                    if let Some(item_def) = self.item_registry.get(&selected_item.item_id) {
-                        if let ItemProperties::Tool { tool_type: ToolType::Hoe, .. } = item_def.properties {
-                            // Player has a hoe selected, start tilling
-                            self.ui.is_tilling = true;
-                            let tile_x = x / 32;
-                            let tile_y = y / 32;
-
-                            // Only allow grass -> dirt conversion
-                            if self.world.world_grid.get_tile(tile_x, tile_y) == Some(TileId::Grass) {
-                                if self.world.world_grid.set_tile(tile_x, tile_y, TileId::Dirt) {
-                                    self.world.render_grid.update_tile_and_neighbors(&self.world.world_grid, tile_x, tile_y);
-                                    self.ui.last_tilled_tile = Some((tile_x, tile_y));
-                                }
-                            }
-                        }
-                    }
+                self.handle_world_click(x / 32, y / 32)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Helper: Handle a left click on a world tile (farming interactions)
+    ///
+    /// - Mature crops are harvested no matter what is selected
+    /// - Hoe: tills grass into dirt (and starts drag-tilling)
+    /// - Watering can: waters a tilled plot
+    /// - Seeds: plants a crop on an empty plot (consumes one seed)
+    fn handle_world_click(&mut self, tile_x: i32, tile_y: i32) -> Result<(), String> {
+        if let Some(crop_type) = self.world.farm.harvest(tile_x, tile_y) {
+            let drop_x = tile_x * 32 + 16;
+            let drop_y = tile_y * 32 + 16;
+
+            for (item_id, quantity) in crop_type.loot_table().roll(&mut self.systems.rng) {
+                let item_texture = self.textures.items.get(&item_id)
+                    .ok_or_else(|| format!("Missing {} texture in item_textures map", item_id))?;
+                self.world.spawn_dropped_item(drop_x, drop_y, item_id, quantity, item_texture)?;
+            }
+            return Ok(());
+        }
+
+        let selected_properties = self.world.player_inventory.get_selected_hotbar()
+            .and_then(|selected_item| self.item_registry.get(&selected_item.item_id))
+            .map(|item_def| item_def.properties.clone());
+
+        match selected_properties {
+            Some(ItemProperties::Tool { tool_type: ToolType::Hoe, .. }) => {
+                // Player has a hoe selected, start tilling (only grass -> dirt)
+                self.ui.is_tilling = true;
+                if self.world.till_tile(tile_x, tile_y) {
+                    self.ui.last_tilled_tile = Some((tile_x, tile_y));
                 }
             }
+            Some(ItemProperties::Tool { tool_type: ToolType::WateringCan, .. }) => {
+                self.world.farm.water(tile_x, tile_y);
+            }
+            Some(ItemProperties::Seed { crop_id }) => {
+                let planted = CropType::from_id(&crop_id)
+                    .is_some_and(|crop_type| self.world.farm.plant(tile_x, tile_y, crop_type));
+                if planted {
+                    let slot = self.world.player_inventory.selected_hotbar_slot;
+                    self.world.player_inventory.inventory.take_from_slot(slot, 1);
+                }
+            }
+            _ => {}
         }
 
         Ok(())
@@ -523,6 +683,16 @@ impl<'a> Game<'a> {
 
     /// Helper: Handle right mouse click
     fn handle_right_click(&mut self, x: i32, y: i32) -> Result<(), String> {
+        // Map editor: right click deletes objects
+        if self.ui.map_editor.is_open {
+            if self.game_state == GameState::Playing
+                && !matches!(self.ui.debug_menu_state, DebugMenuState::Open { .. })
+            {
+                self.ui.map_editor.delete_at(&mut self.world, x, y, &self.systems.pyramid_registry, self.textures.entity);
+            }
+            return Ok(());
+        }
+
         // Handle inventory right-clicks first (if playing and inventory is accessible)
         if self.game_state == GameState::Playing {
             let (screen_width, screen_height) = self.canvas.logical_size();
@@ -543,26 +713,21 @@ impl<'a> Game<'a> {
             }
         }
 
-        // Debug feature: spawn slime on right-click (only if not over inventory)
+        // Debug feature: spawn an enemy on right-click (only if not over inventory)
+        // The type is picked in the debug menu (F3)
         if self.game_state == GameState::Playing
             && !matches!(self.ui.debug_menu_state, DebugMenuState::Open { .. })
         {
-            let slime_animation_controller = self.systems.slime_config.create_controller(
-                self.textures.slime,
-                &["slime_idle", "jump", "slime_damage", "slime_death"],
+            let enemy_type = self.systems.debug_config.spawn_enemy_type.clone();
+            self.world.spawn_enemy(
+                &self.systems.enemy_registry,
+                &enemy_type,
+                x,
+                y,
+                self.textures.enemies,
+                self.systems.debug_config.enemy_health_scale,
             )?;
-
-            // Spawn slime so that click position = collision box center
-            let temp_slime = Slime::new(0, 0, AnimationController::new());
-            let anchor_x = x - (temp_slime.hitbox_offset_x * SPRITE_SCALE as i32)
-                - (temp_slime.hitbox_width * SPRITE_SCALE / 2) as i32;
-            let anchor_y = y - (temp_slime.hitbox_offset_y * SPRITE_SCALE as i32)
-                - (temp_slime.hitbox_height * SPRITE_SCALE / 2) as i32;
-
-            let mut new_slime = Slime::new(anchor_x, anchor_y, slime_animation_controller);
-            new_slime.health = self.systems.debug_config.slime_base_health;
-            self.world.slimes.push(new_slime);
-            println!("Spawned slime at ({}, {})", x, y);
+            println!("Spawned {} at ({}, {})", enemy_type, x, y);
         }
 
         Ok(())
@@ -572,64 +737,351 @@ impl<'a> Game<'a> {
     ///
     /// This method orchestrates all game logic updates in clear phases.
     /// Note: player movement is updated separately in run() to avoid borrow issues.
-    pub fn update(&mut self) -> Result<(), String> {
-        let delta_time = 1.0 / 60.0;
+    pub fn update(&mut self, delta_time: f32) -> Result<(), String> {
 
-        // Phase 1: Combat resolution
+        // Phase 1: Combat resolution (the enemy spatial hash is rebuilt for this tick first)
+        self.world.rebuild_spatial_index();
         self.resolve_attacks()?;
 
-        // Phase 2: Entity updates (slimes, pyramids, effects, floating text)
+        // Phase 2: Game clock (crops grow once per in-game day)
+        let days_passed = self.systems.clock.update(delta_time);
+        for _ in 0..days_passed {
+            self.world.advance_day();
+        }
+
+        // Zones: portal checks, fade transitions, low-frequency inactive zone ticks
+        self.world.check_portals();
+        self.world.update_zone_transition(delta_time)?;
+        self.world.tick_inactive_zones(delta_time);
+
+        // Phase 3: Entity updates (enemies, pyramids, effects, floating text)
+        // Navigation is re-synced first so enemies path around this frame's walls
+        self.world.sync_navigation(&self.systems.static_objects);
         self.world.update_entities(delta_time);
+        self.world.update_farm(delta_time);
+        self.spawn_ambient_enemies(delta_time)?;
+        self.update_spawners(delta_time);
 
-        // Phase 3: Buff application
+        // Phase 4: Buff application, then the boss fight the pyramids can summon
         let has_regen = self.world.apply_pyramid_buffs();
         self.systems.has_regen = has_regen;
+        self.reward_pyramid_groups();
+        self.update_boss_encounter()?;
 
-        // Phase 4: Regeneration
+        // Phase 5: Regeneration
         if self.systems.has_regen && self.systems.regen_timer.elapsed().as_secs_f32() >= self.systems.regen_interval {
             self.world.handle_regeneration();
             self.systems.regen_timer = Instant::now();
         }
 
-        // Phase 5: Collision handling (player-slime, player-static)
+        // Status effects on the player (enemies tick theirs in update_entities)
+        self.update_player_status_effects(delta_time)?;
+
+        // Phase 6: Collision handling (player-enemy, enemy attacks, projectiles, player-static)
         self.handle_collisions()?;
 
-        // Phase 6: Loot drops (slime death drops)
+        // Phase 7: Loot drops (enemy death drops)
         self.handle_loot_drops()?;
 
-        // Phase 7: Item pickup
+        // Phase 8: Item pickup
         let picked_up = self.world.update_dropped_items(&self.item_registry);
         for (item_id, qty) in picked_up {
             println!("✓ Picked up {} x{}", item_id, qty);
         }
 
-        // Phase 8: Cleanup dead entities
+        // Phase 9: Cleanup dead entities
         self.world.cleanup_dead_entities();
 
         Ok(())
     }
 
+    /// Spawn enemies around the world over time
+    ///
+    /// Enemies spawn faster and in greater numbers at night (and always underground), and the
+    /// type is picked by each definition's day/night spawn weight. Spawns are placed away from
+    /// the player so they never pop in on top of them.
+    fn spawn_ambient_enemies(&mut self, delta_time: f32) -> Result<(), String> {
+        let is_dark = self.systems.clock.is_night() || self.world.zone_theme.is_underground();
+        let (spawn_interval, max_enemies) = if is_dark {
+            (NIGHT_ENEMY_SPAWN_INTERVAL, NIGHT_MAX_ENEMIES)
+        } else {
+            (DAY_ENEMY_SPAWN_INTERVAL, DAY_MAX_ENEMIES)
+        };
+
+        self.systems.enemy_spawn_timer += delta_time;
+        if self.systems.enemy_spawn_timer < spawn_interval {
+            return Ok(());
+        }
+        self.systems.enemy_spawn_timer = 0.0;
+
+        if self.world.enemies.len() >= max_enemies {
+            return Ok(());
+        }
+
+        let Some(enemy_type) = self.systems.enemy_registry.pick_ambient(is_dark, &mut self.systems.rng) else {
+            return Ok(());
+        };
+        let enemy_type = enemy_type.to_string();
+
+        // Try a few random positions (or the map's spawn points, if it has any),
+        // skipping any too close to the player
+        let (player_x, player_y) = self.world.player.position();
+        for _ in 0..5 {
+            let (x, y) = if self.world.spawn_points.is_empty() {
+                (
+                    self.systems.rng.range_u32(32, GAME_WIDTH - 32) as i32,
+                    self.systems.rng.range_u32(32, GAME_HEIGHT - 32) as i32,
+                )
+            } else {
+                let index = self.systems.rng.range_u32(0, self.world.spawn_points.len() as u32 - 1) as usize;
+                let point = self.world.spawn_points[index];
+                (point.x, point.y)
+            };
+            let (dx, dy) = ((x - player_x) as f32, (y - player_y) as f32);
+            if (dx * dx + dy * dy).sqrt() < MIN_ENEMY_SPAWN_DISTANCE {
+                continue;
+            }
+
+            self.world.spawn_enemy(
+                &self.systems.enemy_registry,
+                &enemy_type,
+                x,
+                y,
+                self.textures.enemies,
+                self.systems.debug_config.enemy_health_scale,
+            )?;
+            break;
+        }
+
+        Ok(())
+    }
+
+    /// Start, run and end the boss fight
+    ///
+    /// Waking every pyramid in the meadow at once drains them to summon the
+    /// first boss in the registry, and the arena locks around the player.
+    /// While the boss lives its summons are spawned around it. Killing the
+    /// boss unlocks the arena; dying makes the boss retreat.
+    fn update_boss_encounter(&mut self) -> Result<(), String> {
+        if self.world.boss_encounter.is_some() {
+            if !self.world.player.is_alive() {
+                self.world.enemies.retain(|enemy| !enemy.is_boss());
+                self.world.boss_encounter = None;
+                println!("The boss retreats. Wake every meadow pyramid to challenge it again.");
+            } else if self.world.boss().is_some() {
+                self.spawn_boss_summons()?;
+            } else {
+                self.world.boss_encounter = None;
+                println!("★ The boss is defeated! The arena opens.");
+            }
+            return Ok(());
+        }
+
+        if self.world.boss().is_none()
+            && self.world.zone_theme.summons_boss()
+            && self.world.pyramids_all_awake()
+            && let Some(definition) = self.systems.enemy_registry.first_boss().and_then(|id| self.systems.enemy_registry.get(id))
+            && let Some(boss) = &definition.boss
+        {
+            self.world.drain_pyramids();
+            let encounter = self.arena_around_player(boss);
+
+            // The boss lands in the top half of the arena, above the player
+            let arena = encounter.arena;
+            let (boss_x, boss_y) = (arena.center().x(), arena.top() + arena.height() as i32 / 4);
+            self.world.boss_encounter = Some(encounter);
+            self.world.spawn_enemy(
+                &self.systems.enemy_registry,
+                &definition.id,
+                boss_x,
+                boss_y,
+                self.textures.enemies,
+                self.systems.debug_config.enemy_health_scale,
+            )?;
+            println!("The pyramids' power summons the {}!", definition.name);
+            return Ok(());
+        }
+
+        // A boss that arrived some other way (the debug spawner) gets an arena
+        // too, big enough to hold both it and the player wherever it landed
+        if let Some(enemy) = self.world.boss()
+            && let Some(boss) = enemy.boss()
+        {
+            let fighters = [self.world.player.get_bounds(), enemy.get_bounds()];
+            let encounter = BossEncounter::enclosing(&fighters, boss.arena_width, boss.arena_height, self.world.zone_bounds());
+            self.world.boss_encounter = Some(encounter);
+        }
+        Ok(())
+    }
+
+    /// A boss arena centered on the player, kept inside the zone
+    fn arena_around_player(&self, boss: &BossConfig) -> BossEncounter {
+        let player_center = self.world.player.get_bounds().center();
+        BossEncounter::new(
+            (player_center.x(), player_center.y()),
+            boss.arena_width,
+            boss.arena_height,
+            self.world.zone_bounds(),
+        )
+    }
+
+    /// Spawn the minions the boss has called, in a ring around it
+    ///
+    /// Only enough are spawned to bring the arena up to the summon's `max_alive`.
+    fn spawn_boss_summons(&mut self) -> Result<(), String> {
+        let Some(arena) = self.world.boss_encounter.as_ref().map(|encounter| encounter.arena) else {
+            return Ok(());
+        };
+        let Some(boss) = self.world.enemies.iter_mut().find(|enemy| enemy.is_boss() && enemy.is_alive && !enemy.is_dying()) else {
+            return Ok(());
+        };
+        let Some(summon) = boss.take_pending_summon() else {
+            return Ok(());
+        };
+        let center = boss.get_bounds().center();
+
+        let alive = self.world.enemies.iter()
+            .filter(|enemy| enemy.enemy_type == summon.enemy_id && enemy.is_alive && !enemy.is_dying())
+            .filter(|enemy| arena.contains_point(enemy.get_bounds().center()))
+            .count() as u32;
+        let count = summon.count.min(summon.max_alive.saturating_sub(alive));
+        for i in 0..count {
+            let angle = i as f32 / count as f32 * std::f32::consts::TAU;
+            let x = (center.x() + (angle.cos() * BOSS_SUMMON_RADIUS) as i32).clamp(arena.left() + 16, arena.right() - 16);
+            let y = (center.y() + (angle.sin() * BOSS_SUMMON_RADIUS) as i32).clamp(arena.top() + 16, arena.bottom() - 16);
+            self.world.spawn_enemy(
+                &self.systems.enemy_registry,
+                &summon.enemy_id,
+                x,
+                y,
+                self.textures.enemies,
+                self.systems.debug_config.enemy_health_scale,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Throw a slime ball from the inventory in the direction the player faces
+    ///
+    /// The ball is a player-owned projectile: it hits the first enemy in its
+    /// path and breaks on walls, or drops out of the air after `THROW_RANGE`.
+    fn throw_item(&mut self) {
+        let player = &self.world.player;
+        if !player.is_alive()
+            || self.systems.throw_timer.elapsed().as_secs_f32() < THROW_COOLDOWN
+            || !self.world.player_inventory.has_item(THROW_ITEM_ID, 1)
+        {
+            return;
+        }
+        let Some(texture) = self.textures.items.get(THROW_ITEM_ID) else {
+            return;
+        };
+
+        let mut animation_controller = AnimationController::new();
+        let frames = vec![sprite::Frame::new(0, 0, 32, 32, 100)];
+        animation_controller.add_animation("flying".to_string(), SpriteSheet::new(texture, frames));
+        animation_controller.set_state("flying".to_string());
+
+        // Thrown from the player's visual center, like the punch
+        let origin = (player.x, player.y - (player.height * SPRITE_SCALE / 2) as f32);
+        let (dir_x, dir_y) = player.direction.unit_vector();
+        let target = (origin.0 + dir_x, origin.1 + dir_y);
+        let projectile = Projectile::aimed(origin, target, THROW_SPEED, THROW_DAMAGE, CollisionLayer::Player)
+            .with_lifetime(THROW_RANGE / THROW_SPEED)
+            .with_knockback(THROW_KNOCKBACK)
+            .with_status_effect(StatusEffect::new(StatusKind::Slow, THROW_SLOW.0, THROW_SLOW.1))
+            .with_animation(animation_controller, 16, 16);
+
+        self.world.player_inventory.inventory.remove_item(THROW_ITEM_ID, 1);
+        self.world.projectiles.push(projectile);
+        self.systems.throw_timer = Instant::now();
+    }
+
+    /// Tick the active zone's enemy spawners and act on their events
+    ///
+    /// Each spawner only counts the living enemies it created itself. Wave
+    /// announcements appear as floating text above the player. An enemy type
+    /// missing from the registry is reported and skipped rather than stopping
+    /// the game.
+    fn update_spawners(&mut self, delta_time: f32) {
+        let is_dark = self.systems.clock.is_night() || self.world.zone_theme.is_underground();
+        let player_center = self.world.player.get_bounds().center();
+        let player_position = (player_center.x(), player_center.y());
+
+        let mut events = Vec::new();
+        for spawner in self.world.spawners.iter_mut() {
+            let alive = self.world.enemies.iter()
+                .filter(|enemy| enemy.is_alive && !enemy.is_dying())
+                .filter(|enemy| enemy.spawner_id.as_deref() == Some(spawner.id()))
+                .count();
+            let spawner_events = spawner.update(delta_time, alive, is_dark, player_position, &mut self.systems.rng);
+            events.extend(spawner_events.into_iter().map(|event| (spawner.id().to_string(), event)));
+        }
+
+        for (spawner_id, event) in events {
+            match event {
+                SpawnerEvent::Spawn { enemy_type, x, y, health_scale } => {
+                    let spawned = self.world.spawn_enemy(
+                        &self.systems.enemy_registry,
+                        &enemy_type,
+                        x,
+                        y,
+                        self.textures.enemies,
+                        health_scale * self.systems.debug_config.enemy_health_scale,
+                    );
+                    match spawned {
+                        Ok(enemy) => enemy.spawner_id = Some(spawner_id),
+                        Err(e) => eprintln!("Warning: spawner '{}' failed: {}", spawner_id, e),
+                    }
+                }
+                SpawnerEvent::WaveStarted(wave) => {
+                    self.spawn_announcement(format!("WAVE {}", wave), Color::RGB(255, 200, 60));
+                }
+                SpawnerEvent::WavesCompleted => {
+                    self.spawn_announcement("WAVES CLEARED!".to_string(), Color::RGB(120, 255, 120));
+                }
+            }
+        }
+    }
+
+    /// Floating text above the player's head (wave announcements)
+    fn spawn_announcement(&mut self, text: String, color: Color) {
+        let player = &self.world.player;
+        let x = player.x;
+        let y = player.y - (player.height * SPRITE_SCALE) as f32 - 16.0;
+        self.world.spawn_floating_text(text, x, y, color, 2.5);
+    }
+
     /// Resolve active attack against enemies and entities
     ///
     /// This helper method handles combat resolution:
-    /// - Checks attack hitbox against slimes and pyramids
+    /// - Checks attack hitbox against enemies and pyramids
     /// - Applies damage to hit entities
     /// - Spawns loot when pyramids are awakened
     fn resolve_attacks(&mut self) -> Result<(), String> {
-        if let Some(ref attack) = self.world.active_attack {
+        if let Some(attack) = self.world.active_attack.take() {
             let attack_hitbox = attack.get_hitbox();
 
-            // Check attack vs slimes
-            for slime in self.world.slimes.iter_mut() {
-                let slime_bounds = slime.get_bounds();
-                if collision::aabb_intersect(&attack_hitbox, &slime_bounds) {
-                    slime.take_damage(attack.damage as i32);
+            // Check attack vs enemies (each enemy hit rolls its own variance and crit)
+            // The spatial query uses the shape's bounding box; `hits` checks the shape itself
+            for index in self.world.enemies_in_rect(&attack_hitbox) {
+                if !attack.hits(self.world.enemies[index].get_bounds()) {
+                    continue;
+                }
+                let damage = attack.roll_damage_event(DamageType::Physical, DamageSource::Player, &mut self.systems.rng);
+                let result = self.world.enemies[index].take_damage_event(&damage);
+                if result.damage_dealt > 0.0 {
+                    let bounds = self.world.enemies[index].get_bounds();
+                    self.spawn_damage_number(&result, bounds.center().x() as f32, bounds.top() as f32 - 8.0);
                 }
             }
 
             // Check attack vs entities (pyramids) and collect drop positions
             let mut drops: Vec<(i32, i32)> = Vec::new();
             for entity in self.world.entities.iter_mut() {
+                if !attack.hits(entity.get_bounds()) {
+                    continue;
+                }
                 if let Some(state_before_hit) = entity.check_hit(&attack_hitbox) {
                     // Drop stone only if entity was not in Awake state
                     if state_before_hit != the_entity::EntityState::Awake {
@@ -652,40 +1104,63 @@ impl<'a> Game<'a> {
                     )?;
                 }
             }
-
-            self.world.active_attack = None;
         }
 
         Ok(())
     }
 
-    /// Check for dying slimes and spawn their loot
+    /// Damage number for a player hit: white, or large and orange with a "!" for a crit
+    fn spawn_damage_number(&mut self, result: &DamageResult, x: f32, y: f32) {
+        let amount = result.damage_dealt.round() as i32;
+        if result.is_crit {
+            self.world.spawn_large_floating_text(format!("{}!", amount), x, y, Color::RGB(255, 150, 30), 1.2);
+        } else {
+            self.world.spawn_floating_text(amount.to_string(), x, y, Color::RGB(255, 255, 255), 0.8);
+        }
+    }
+
+    /// Check for dying enemies and spawn their loot
     ///
-    /// This helper method handles slime death loot drops, creating a slime_ball
-    /// item at the slime's position when it dies.
+    /// Each enemy rolls its definition's loot table once, dropping the items
+    /// at the center of its collision box, and awards its definition's XP.
     fn handle_loot_drops(&mut self) -> Result<(), String> {
-        // Collect drop positions first to avoid borrow conflicts
-        let mut drops: Vec<(i32, i32)> = Vec::new();
-        for slime in self.world.slimes.iter_mut() {
-            if slime.is_dying() && !slime.has_dropped_loot {
-                slime.has_dropped_loot = true;
-
-                let drop_x = slime.x + (slime.hitbox_offset_x * SPRITE_SCALE as i32) + (slime.hitbox_width * SPRITE_SCALE / 2) as i32;
-                let drop_y = slime.y + (slime.hitbox_offset_y * SPRITE_SCALE as i32) + (slime.hitbox_height * SPRITE_SCALE / 2) as i32;
-                drops.push((drop_x, drop_y));
+        // Collect drops first to avoid borrow conflicts
+        let mut drops: Vec<(i32, i32, String, u32)> = Vec::new();
+        let mut rewards: Vec<(u32, f32, f32)> = Vec::new();
+        for enemy in self.world.enemies.iter_mut() {
+            if enemy.is_dying() && !enemy.has_dropped_loot {
+                enemy.has_dropped_loot = true;
+
+                let Some(definition) = self.systems.enemy_registry.get(&enemy.enemy_type) else {
+                    continue;
+                };
+                let drop = enemy.get_bounds().center();
+                let (drop_x, drop_y) = (drop.x(), drop.y());
+                for (item_id, quantity) in definition.loot.roll(&mut self.systems.rng) {
+                    drops.push((drop_x, drop_y, item_id, quantity));
+                }
+                if definition.xp > 0 {
+                    rewards.push((definition.xp, drop_x as f32, (enemy.get_bounds().top() - 8) as f32));
+                }
             }
         }
 
+        for (xp, x, y) in rewards {
+            let xp = self.xp_with_gain(xp);
+            self.world.spawn_floating_text(format!("+{}XP", xp), x, y, Color::RGB(170, 130, 255), 1.0);
+            self.award_xp(xp);
+        }
+
         // Spawn drops after iteration completes
-        let item_texture = self.textures.items.get("slime_ball")
-            .ok_or("Missing slime_ball texture in item_textures map")?;
+        for (drop_x, drop_y, item_id, quantity) in drops {
+            let item_texture = self.textures.items.get(&item_id)
+                .ok_or_else(|| format!("Missing {} texture in item_textures map", item_id))?;
 
-        for (drop_x, drop_y) in drops {
             self.world.spawn_dropped_item(
                 drop_x,
                 drop_y,
-                "slime_ball".to_string(),
-                1,
+                item_id,
+                quantity,
                 item_texture,
             )?;
         }
@@ -693,88 +1168,208 @@ impl<'a> Game<'a> {
         Ok(())
     }
 
-    /// Handle all collision physics (player-slime, player-static)
-    ///
-    /// This helper method handles:
-    /// 1. Player-slime collisions with push physics
-    /// 2. Contact damage from slimes
-    /// 3. Death loot drops when player dies
-    /// 4. Player-static collisions (walls, entities)
-    fn handle_collisions(&mut self) -> Result<(), String> {
-        // Handle player-slime collisions (push physics + contact damage)
-        let colliding_slime_indices = check_collisions_with_collection(&self.world.player, &self.world.slimes);
+    /// XP earned from `amount`, scaled by the player's XP gain buffs
+    fn xp_with_gain(&self, amount: u32) -> u32 {
+        let player = &self.world.player;
+        let gain = player.stats.effective_stat(StatType::XpGain, &player.modifiers()).max(0.0);
+        (amount as f32 * gain).round() as u32
+    }
 
-        for slime_index in colliding_slime_indices {
-            let player_bounds = self.world.player.get_bounds();
-            let slime_bounds = self.world.slimes[slime_index].get_bounds();
+    /// Hand out the rewards of linked pyramid groups woken in order this frame
+    fn reward_pyramid_groups(&mut self) {
+        for group in self.world.update_pyramid_groups() {
+            println!("★ The pyramids were woken in order: {}!", group.name);
+            if let Some(effect) = group.reward_effect() {
+                self.world.player.active_effects.apply(effect);
+            }
+            if group.reward.xp > 0 {
+                let xp = self.xp_with_gain(group.reward.xp);
+                let player = &self.world.player;
+                let (x, y) = (player.x, player.y - (player.height * SPRITE_SCALE) as f32 - 24.0);
+                self.world.spawn_floating_text(format!("+{}XP", xp), x, y, Color::RGB(170, 130, 255), 1.5);
+                self.award_xp(xp);
+            }
+        }
+    }
 
-            let (overlap_x, overlap_y) = calculate_overlap(&player_bounds, &slime_bounds);
+    /// Give the player XP, announcing any level-up above their head
+    fn award_xp(&mut self, amount: u32) {
+        let levels_gained = self.world.player.progression.add_xp(amount);
+        if levels_gained > 0 {
+            let player = &self.world.player;
+            println!(
+                "Level up! Now level {} with {} stat point(s) to spend (C)",
+                player.progression.level, player.progression.unspent_points
+            );
+            let (x, y) = (player.x, player.y - (player.height * SPRITE_SCALE) as f32 - 8.0);
+            self.world.spawn_large_floating_text("LEVEL UP!".to_string(), x, y, Color::RGB(255, 220, 60), 2.0);
+        }
+    }
 
-            // Push-apart physics (30% player, 70% slime)
-            if overlap_x.abs() < overlap_y.abs() {
-                self.world.player.apply_push(-overlap_x * 3 / 10, 0);
-                self.world.slimes[slime_index].apply_push(overlap_x * 7 / 10, 0);
-            } else {
-                self.world.player.apply_push(0, -overlap_y * 3 / 10);
-                self.world.slimes[slime_index].apply_push(0, overlap_y * 7 / 10);
-            }
-
-            // Contact damage
-            if !self.world.player.is_attacking && !self.world.slimes[slime_index].is_invulnerable() {
-                let damage = DamageEvent::physical(self.systems.debug_config.slime_contact_damage, DamageSource::Enemy);
-                let damage_result = self.world.player.take_damage(damage);
-                if damage_result.is_fatal {
-                    // Collect items to drop (avoiding borrow conflicts)
-                    let mut items_to_drop: Vec<(String, u32)> = Vec::new();
-                    for item_stack_option in self.world.player_inventory.inventory.slots.iter_mut() {
-                        if let Some(item_stack) = item_stack_option.take() {
-                            items_to_drop.push((item_stack.item_id.clone(), item_stack.quantity));
-                        }
-                    }
+    /// Deal damage to the player, dropping their whole inventory if it kills them
+    fn damage_player(&mut self, damage: DamageEvent) -> Result<(), String> {
+        let damage_result = self.world.player.take_damage(damage);
+        if damage_result.is_fatal {
+            self.drop_player_items()?;
+        }
+        Ok(())
+    }
 
-                    // Drop all collected items
-                    for (item_id, quantity) in items_to_drop {
-                        let item_texture = self.textures.items.get(&item_id)
-                            .ok_or(format!("Missing texture for item {}", item_id))?;
-
-                        self.world.spawn_dropped_item(
-                            self.world.player.x,
-                            self.world.player.y,
-                            item_id,
-                            quantity,
-                            item_texture,
-                        )?;
-                    }
-                    println!("Player died and dropped all items.");
-                }
+    /// Tick the player's status effects: poison and burn damage shows as a
+    /// number in the effect's color, and can kill like any other damage
+    fn update_player_status_effects(&mut self, delta_time: f32) -> Result<(), String> {
+        for (kind, mut damage) in self.world.player.status_effects.update(delta_time) {
+            damage.amount *= self.systems.debug_config.enemy_damage_scale;
+            let damage_result = self.world.player.take_status_damage(damage);
+            if damage_result.damage_dealt > 0.0 {
+                let player = &self.world.player;
+                let (x, y) = (player.x, player.y - (player.height * SPRITE_SCALE) as f32);
+                self.world.spawn_floating_text(format!("-{}", format_amount(damage_result.damage_dealt)), x, y, kind.color(), 1.5);
+            }
+            if damage_result.is_fatal {
+                self.drop_player_items()?;
             }
         }
+        Ok(())
+    }
 
-        // Handle player-static collisions (walls, entities)
-        let mut all_static_collidables: Vec<&dyn StaticCollidable> = Vec::new();
-        for obj in &self.systems.static_objects {
-            all_static_collidables.push(obj);
-        }
-        for entity in self.world.entities.iter() {
-            all_static_collidables.push(entity);
+    /// Drop the whole inventory where the player died
+    fn drop_player_items(&mut self) -> Result<(), String> {
+        // Collect items to drop (avoiding borrow conflicts)
+        let mut items_to_drop: Vec<(String, u32)> = Vec::new();
+        for item_stack_option in self.world.player_inventory.inventory.slots.iter_mut() {
+            if let Some(item_stack) = item_stack_option.take() {
+                items_to_drop.push((item_stack.item_id.clone(), item_stack.quantity));
+            }
         }
 
-        let static_collisions = check_static_collisions(&self.world.player, &all_static_collidables);
+        // Drop all collected items
+        for (item_id, quantity) in items_to_drop {
+            let item_texture = self.textures.items.get(&item_id)
+                .ok_or(format!("Missing texture for item {}", item_id))?;
+
+            let (drop_x, drop_y) = self.world.player.position();
+            self.world.spawn_dropped_item(
+                drop_x,
+                drop_y,
+                item_id,
+                quantity,
+                item_texture,
+            )?;
+        }
+        println!("Player died and dropped all items.");
+        Ok(())
+    }
 
-        for obj_index in static_collisions {
+    /// Handle all collision physics (enemy attacks, then the generic collision pass)
+    ///
+    /// This helper method handles:
+    /// 1. Enemy melee attacks landing on the player
+    /// 2. One `detect_collisions` pass over the player, enemies, projectiles and
+    ///    static objects, filtered by `Systems::collision_matrix`
+    /// 3. Collide events: player-enemy push physics, player/enemy-static push-out
+    /// 4. Trigger events: projectiles hitting the player or enemies, and stopping
+    ///    at static objects
+    fn handle_collisions(&mut self) -> Result<(), String> {
+        // Enemy melee attacks (touching an enemy is harmless; only the active
+        // hitbox after a telegraphed windup hurts, scaled by the debug damage scale)
+        if self.world.player.is_alive() {
             let player_bounds = self.world.player.get_bounds();
-            let obj_bounds = all_static_collidables[obj_index].get_bounds();
+            let hits: Vec<_> = self.world.enemies.iter_mut()
+                .filter_map(|enemy| enemy.try_melee_hit(&player_bounds))
+                .collect();
+            for attack in hits {
+                let mut damage = attack.to_damage_event(DamageType::Physical, DamageSource::Enemy);
+                damage.amount *= self.systems.debug_config.enemy_damage_scale;
+                self.damage_player(damage)?;
+            }
+        }
 
-            let (overlap_x, overlap_y) = calculate_overlap(&player_bounds, &obj_bounds);
+        // One collider list for everything that moves or blocks; the matrix
+        // decides which pairs are tested. Static colliders index into the
+        // combined list of walls, placed blocks, boss arena walls and pyramids.
+        let mut colliders = vec![Collider::of(&self.world.player, 0)];
+        colliders.extend(self.world.enemies.iter().enumerate().map(|(i, enemy)| Collider::of(enemy, i)));
+        colliders.extend(self.world.projectiles.iter().enumerate().map(|(i, projectile)| Collider::of(projectile, i)));
+        let static_objects = self.systems.static_objects.iter().map(|obj| obj as &dyn StaticCollidable)
+            .chain(self.world.static_objects.iter().map(|obj| obj as &dyn StaticCollidable))
+            .chain(self.world.boss_encounter.iter().flat_map(|encounter| &encounter.walls).map(|obj| obj as &dyn StaticCollidable))
+            .chain(self.world.entities.iter().map(|entity| entity as &dyn StaticCollidable));
+        colliders.extend(static_objects.enumerate().map(|(i, obj)| Collider::fixed(obj, i)));
+
+        let mut player_hits = Vec::new();
+        for event in detect_collisions(&colliders, &self.systems.collision_matrix) {
+            match event {
+                // Push-apart physics (30% player, 70% enemy), kept sub-pixel
+                CollisionEvent::Collide(player, enemy) if (player.layer, enemy.layer) == (CollisionLayer::Player, CollisionLayer::Enemy) => {
+                    let enemy = &mut self.world.enemies[enemy.index];
+                    let (overlap_x, overlap_y) = calculate_overlap(&self.world.player.get_bounds(), &enemy.get_bounds());
+                    if overlap_x.abs() < overlap_y.abs() {
+                        self.world.player.apply_push(-overlap_x as f32 * 0.3, 0.0);
+                        enemy.apply_push(overlap_x as f32 * 0.7, 0.0);
+                    } else {
+                        self.world.player.apply_push(0.0, -overlap_y as f32 * 0.3);
+                        enemy.apply_push(0.0, overlap_y as f32 * 0.7);
+                    }
+                }
 
-            // Push player out completely (100% push on player, 0% on static object)
-            if overlap_x.abs() < overlap_y.abs() {
-                self.world.player.apply_push(-overlap_x, 0);
-            } else {
-                self.world.player.apply_push(0, -overlap_y);
+                // Walls, blocks and pyramids push the player and hopping enemies out
+                // completely, and stop any knockback slide into them
+                CollisionEvent::Collide(mover, wall) if wall.layer == CollisionLayer::Static => {
+                    let push = |bounds: Rect| match calculate_overlap(&bounds, &wall.bounds) {
+                        (overlap_x, overlap_y) if overlap_x.abs() < overlap_y.abs() => (-overlap_x as f32, 0.0),
+                        (_, overlap_y) => (0.0, -overlap_y as f32),
+                    };
+                    match mover.layer {
+                        CollisionLayer::Player => {
+                            let (push_x, push_y) = push(self.world.player.get_bounds());
+                            self.world.player.apply_push(push_x, push_y);
+                            self.world.player.knockback.block(push_x, push_y);
+                        }
+                        CollisionLayer::Enemy => {
+                            let enemy = &mut self.world.enemies[mover.index];
+                            let (push_x, push_y) = push(enemy.get_bounds());
+                            enemy.apply_push(push_x, push_y);
+                            enemy.block_knockback(push_x, push_y);
+                        }
+                        _ => {}
+                    }
+                }
+
+                // Projectiles hitting their targets. Invulnerable targets are passed
+                // over, so a piercing projectile can't spend its pierce on one enemy
+                CollisionEvent::Trigger(target, shot) if shot.layer == CollisionLayer::Projectile => {
+                    let projectile = &mut self.world.projectiles[shot.index];
+                    match target.layer {
+                        CollisionLayer::Player => {
+                            let player = &self.world.player;
+                            if projectile.can_hit(CollisionLayer::Player) && player.is_alive() && !player.is_invulnerable {
+                                let mut damage = projectile.register_hit();
+                                damage.amount *= self.systems.debug_config.enemy_damage_scale;
+                                player_hits.push(damage);
+                            }
+                        }
+                        CollisionLayer::Enemy => {
+                            let enemy = &mut self.world.enemies[target.index];
+                            if projectile.can_hit(CollisionLayer::Enemy) && !enemy.is_invulnerable() {
+                                enemy.take_damage_event(&projectile.register_hit());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                CollisionEvent::Trigger(shot, wall) if (shot.layer, wall.layer) == (CollisionLayer::Projectile, CollisionLayer::Static) => {
+                    self.world.projectiles[shot.index].is_alive = false;
+                }
+
+                _ => {}
             }
         }
 
+        for damage in player_hits {
+            self.damage_player(damage)?;
+        }
+
         Ok(())
     }
 
@@ -785,19 +1380,50 @@ impl<'a> Game<'a> {
         self.canvas.set_draw_color(sdl2::pixels::Color::RGB(0, 0, 0));
         self.canvas.clear();
 
-        self.world.render_grid.render(&mut self.canvas, self.textures.grass_tile)?;
+        let tileset = if self.world.zone_theme.is_underground() {
+            self.textures.cave_tile
+        } else {
+            self.textures.grass_tile
+        };
+        self.world.render_grid.render(&mut self.canvas, tileset)?;
+        self.world.farm.render(&mut self.canvas, self.textures.crops)?;
+        for portal in &self.world.portals {
+            portal.render(&mut self.canvas, self.textures.portals)?;
+        }
 
-        render_with_depth_sorting(&mut self.canvas, &self.world.player, &self.world.slimes, &self.systems.static_objects, &self.world.entities, &self.world.dropped_items)?;
+        render_with_depth_sorting(
+            &mut self.canvas,
+            &self.world.player,
+            &self.world.enemies,
+            &self.world.static_objects,
+            &self.world.entities,
+            &self.world.dropped_items,
+            &self.world.projectiles,
+        )?;
+
+        if let Some(encounter) = &self.world.boss_encounter {
+            encounter.render(&mut self.canvas)?;
+        }
 
         for effect in &self.world.attack_effects {
             effect.render(&mut self.canvas, SPRITE_SCALE)?;
         }
 
+        // Day/night tint over the world layer (UI renders on top, untinted)
+        let tint = self.world.zone_theme.fixed_tint().unwrap_or_else(|| self.systems.clock.tint());
+        if tint.a > 0 {
+            self.canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+            self.canvas.set_draw_color(tint);
+            self.canvas.fill_rect(None)?;
+            self.canvas.set_blend_mode(sdl2::render::BlendMode::None);
+        }
+
         if self.world.player.state.is_alive() {
             // Health bar expects top-left coordinates, but player uses anchor (bottom-center)
             // Calculate top-left from anchor for health bar rendering
-            let player_top_left_x = self.world.player.x - ((self.world.player.width * SPRITE_SCALE) / 2) as i32;
-            let player_top_left_y = self.world.player.y - (self.world.player.height * SPRITE_SCALE) as i32;
+            let (player_x, player_y) = self.world.player.position();
+            let player_top_left_x = player_x - ((self.world.player.width * SPRITE_SCALE) / 2) as i32;
+            let player_top_left_y = player_y - (self.world.player.height * SPRITE_SCALE) as i32;
 
             self.ui.player_health_bar.render(
                 &mut self.canvas,
@@ -807,29 +1433,42 @@ impl<'a> Game<'a> {
                 self.world.player.height * SPRITE_SCALE,
                 self.world.player.stats.health.percentage(),
             )?;
+            self.ui.player_stamina_bar.render(
+                &mut self.canvas,
+                player_top_left_x,
+                player_top_left_y,
+                self.world.player.width * SPRITE_SCALE,
+                self.world.player.height * SPRITE_SCALE,
+                self.world.player.stats.stamina.percentage(),
+            )?;
         }
 
-        for slime in &self.world.slimes {
-            if slime.is_alive {
-                // Health bar expects top-left coordinates, but slime uses anchor (bottom-center)
+        // Bosses get the screen-wide bar instead (drawn with the HUD)
+        for enemy in &self.world.enemies {
+            if enemy.is_alive && !enemy.is_boss() {
+                // Health bar expects top-left coordinates, but enemy uses anchor (bottom-center)
                 // Calculate top-left from anchor for health bar rendering
-                let slime_top_left_x = slime.x - ((slime.width * SPRITE_SCALE) / 2) as i32;
-                let slime_top_left_y = slime.y - (slime.height * SPRITE_SCALE) as i32;
+                let (enemy_x, enemy_y) = enemy.position();
+                let enemy_top_left_x = enemy_x - ((enemy.width * enemy.scale) / 2) as i32;
+                let enemy_top_left_y = enemy_y - (enemy.height * enemy.scale) as i32;
 
                 self.ui.enemy_health_bar.render(
                     &mut self.canvas,
-                    slime_top_left_x,
-                    slime_top_left_y,
-                    slime.width * SPRITE_SCALE,
-                    slime.height * SPRITE_SCALE,
-                    slime.health as f32 / 8.0, // Slimes have max 8 HP
+                    enemy_top_left_x,
+                    enemy_top_left_y,
+                    enemy.width * enemy.scale,
+                    enemy.height * enemy.scale,
+                    enemy.health_fraction(),
                 )?;
             }
         }
 
         for text in &self.world.floating_texts {
             let alpha = ((1.0 - text.lifetime / text.max_lifetime) * 255.0) as u8;
-            self.ui.floating_text_renderer.render(
+            let renderer = &self.ui.floating_text_renderer;
+            let render = if text.large { FloatingText::render_large } else { FloatingText::render };
+            render(
+                renderer,
                 &mut self.canvas,
                 text.x as i32,
                 text.y as i32,
@@ -842,9 +1481,26 @@ impl<'a> Game<'a> {
         if self.game_state == GameState::Playing {
             self.ui.buff_display.render(
                 &mut self.canvas,
-                &self.world.player.active_modifiers,
+                &self.world.player.active_effects,
                 self.systems.has_regen,
+                &self.world.player.status_effects,
             )?;
+
+            // HUD clock (top-right), greyed out while paused
+            let time_text = self.systems.clock.time_text();
+            let clock_color = if self.systems.clock.paused {
+                Color::RGB(140, 140, 150)
+            } else {
+                Color::RGB(255, 255, 255)
+            };
+            let text_width = (time_text.len() * 12) as i32;
+            draw_simple_text(&mut self.canvas, &time_text, GAME_WIDTH as i32 - text_width - 8, 8, clock_color, 2)?;
+
+            if let Some(boss) = self.world.boss()
+                && let Some(config) = boss.boss()
+            {
+                render_boss_health_bar(&mut self.canvas, &self.ui.boss_health_bar, config, boss.health_fraction())?;
+            }
         }
 
         if self.ui.show_collision_boxes {
@@ -854,19 +1510,20 @@ impl<'a> Game<'a> {
             let player_collision = self.world.player.get_bounds();
             self.canvas.draw_rect(player_collision).map_err(|e| e.to_string())?;
 
-            for slime in &self.world.slimes {
-                let slime_bounds = slime.get_bounds();
-                self.canvas.draw_rect(slime_bounds).map_err(|e| e.to_string())?;
+            for enemy in &self.world.enemies {
+                let enemy_bounds = enemy.get_bounds();
+                self.canvas.draw_rect(enemy_bounds).map_err(|e| e.to_string())?;
 
                 // YELLOW: Show where sprite SHOULD render (anchor visualization)
                 self.canvas.set_draw_color(sdl2::pixels::Color::RGBA(255, 255, 0, 200));
-                let sprite_render_x = slime.x - ((slime.width * SPRITE_SCALE) / 2) as i32;
-                let sprite_render_y = slime.y - (slime.height * SPRITE_SCALE) as i32;
+                let (enemy_x, enemy_y) = enemy.position();
+                let sprite_render_x = enemy_x - ((enemy.width * enemy.scale) / 2) as i32;
+                let sprite_render_y = enemy_y - (enemy.height * enemy.scale) as i32;
                 let sprite_rect = Rect::new(
                     sprite_render_x,
                     sprite_render_y,
-                    slime.width * SPRITE_SCALE,
-                    slime.height * SPRITE_SCALE
+                    enemy.width * enemy.scale,
+                    enemy.height * enemy.scale
                 );
                 self.canvas.draw_rect(sprite_rect).map_err(|e| e.to_string())?;
 
@@ -874,8 +1531,8 @@ impl<'a> Game<'a> {
                 self.canvas.set_draw_color(sdl2::pixels::Color::RGBA(255, 255, 255, 255));
                 let anchor_size: u32 = 4;
                 let anchor_rect = Rect::new(
-                    slime.x - (anchor_size as i32) / 2,
-                    slime.y - (anchor_size as i32) / 2,
+                    enemy_x - (anchor_size as i32) / 2,
+                    enemy_y - (anchor_size as i32) / 2,
                     anchor_size,
                     anchor_size
                 );
@@ -904,7 +1561,35 @@ impl<'a> Game<'a> {
             }
         }
 
-        if self.ui.show_tile_grid {
+        if self.ui.show_nav_paths {
+            // RED: Cells enemies can't walk through
+            let nav_grid = self.world.navigation.grid();
+            self.canvas.set_draw_color(sdl2::pixels::Color::RGBA(255, 0, 0, 60));
+            for y in 0..nav_grid.height {
+                for x in 0..nav_grid.width {
+                    if !nav_grid.is_walkable((x, y)) {
+                        let cell = Rect::new(x * NAV_TILE_SIZE, y * NAV_TILE_SIZE, NAV_TILE_SIZE as u32, NAV_TILE_SIZE as u32);
+                        self.canvas.fill_rect(cell).map_err(|e| e.to_string())?;
+                    }
+                }
+            }
+
+            // CYAN: Each enemy's current path, from its collision center through cell centers
+            self.canvas.set_draw_color(sdl2::pixels::Color::RGBA(0, 255, 255, 220));
+            for enemy in &self.world.enemies {
+                let (start_x, start_y) = enemy.nav_position();
+                let mut previous = sdl2::rect::Point::new(start_x as i32, start_y as i32);
+                for &cell in enemy.nav_path() {
+                    let (cell_x, cell_y) = pathfinding::cell_center(cell);
+                    let point = sdl2::rect::Point::new(cell_x as i32, cell_y as i32);
+                    self.canvas.draw_line(previous, point).map_err(|e| e.to_string())?;
+                    self.canvas.fill_rect(Rect::new(point.x() - 2, point.y() - 2, 4, 4)).map_err(|e| e.to_string())?;
+                    previous = point;
+                }
+            }
+        }
+
+        if self.ui.show_tile_grid || self.ui.map_editor.is_open {
             self.canvas.set_draw_color(sdl2::pixels::Color::RGBA(255, 255, 0, 128));
             for x in 0..=self.world.world_grid.width {
                 let line_x = (x * 32) as i32;
@@ -952,10 +1637,24 @@ impl<'a> Game<'a> {
             );
         }
 
-        if self.game_state == GameState::Playing {
+        if self.ui.map_editor.is_open {
+            self.ui.map_editor.render(&mut self.canvas, &self.world, self.ui.mouse_x, self.ui.mouse_y)?;
+        } else if self.game_state == GameState::Playing {
             self.ui.inventory_ui.render(&mut self.canvas, &self.world.player_inventory, self.world.player_inventory.selected_hotbar_slot, self.ui.mouse_x, self.ui.mouse_y)?;
         }
 
+        // Zone transition fade (covers the world and HUD, menus stay visible)
+        if let Some(transition) = &self.world.zone_transition {
+            self.canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+            self.canvas.set_draw_color(Color::RGBA(0, 0, 0, transition.fade_alpha()));
+            self.canvas.fill_rect(None)?;
+            self.canvas.set_blend_mode(sdl2::render::BlendMode::None);
+        }
+
+        if self.ui.character_screen.is_open {
+            self.ui.character_screen.render(&mut self.canvas, &self.world.player.progression, &self.world.player.stats)?;
+        }
+
         if self.game_state == GameState::Dead {
             self.ui.death_screen.render(&mut self.canvas)?;
         }
@@ -965,7 +1664,7 @@ impl<'a> Game<'a> {
         }
 
         if let DebugMenuState::Open { selected_index } = self.ui.debug_menu_state {
-            render_debug_menu(&mut self.canvas, &self.world.player, &self.systems.debug_config, selected_index)?;
+            render_debug_menu(&mut self.canvas, &self.world.player, &self.systems.debug_config, &self.systems.clock, selected_index)?;
         }
 
         // Present to screen
@@ -976,7 +1675,12 @@ impl<'a> Game<'a> {
     /// Run the main game loop
     /// This is the entry point that orchestrates input, update, and render
     pub fn run(&mut self) -> Result<(), String> {
+        let mut last_frame = Instant::now();
         'running: loop {
+            // Real time since the last frame (capped so a stall doesn't teleport anything)
+            let delta_time = last_frame.elapsed().as_secs_f32().min(physics::MAX_FRAME_DELTA);
+            last_frame = Instant::now();
+
             // PHASE 1: Handle input events
             if self.handle_events()? {
                 break 'running; // Quit requested
@@ -984,7 +1688,9 @@ impl<'a> Game<'a> {
 
             // Check if UI is blocking gameplay
             let is_ui_active = self.ui.inventory_ui.is_open ||
+                               self.ui.character_screen.is_open ||
                                matches!(self.ui.debug_menu_state, DebugMenuState::Open { .. }) ||
+                               self.ui.map_editor.is_open ||
                                self.game_state == GameState::ExitMenu ||
                                self.game_state == GameState::Dead;
 
@@ -998,13 +1704,14 @@ impl<'a> Game<'a> {
                 }
 
                 // Update player movement first (separate to avoid borrow issues)
-                {
+                // Movement is frozen while fading between zones
+                if self.world.zone_transition.is_none() {
                     let keyboard_state = self.event_pump.keyboard_state();
-                    self.world.player.update(&keyboard_state);
+                    self.world.player.update(&keyboard_state, delta_time);
                 }
 
                 // Now update the rest of the game world
-                self.update()?;
+                self.update(delta_time)?;
             }
 
             // Handle death screen respawn
@@ -1033,53 +1740,41 @@ impl<'a> Game<'a> {
         texture_creator: &'a sdl2::render::TextureCreator<sdl2::video::WindowContext>,
         canvas: sdl2::render::Canvas<sdl2::video::Window>,
         event_pump: sdl2::EventPump,
-        player_config: AnimationConfig,
-        slime_config: AnimationConfig,
-        punch_config: AnimationConfig,
-        character_texture: &'a sdl2::render::Texture<'a>,
-        slime_texture: &'a sdl2::render::Texture<'a>,
-        entity_texture: &'a sdl2::render::Texture<'a>,
-        punch_texture: &'a sdl2::render::Texture<'a>,
-        grass_tile_texture: &'a sdl2::render::Texture<'a>,
-        item_textures: &'a HashMap<String, sdl2::render::Texture<'a>>,
+        systems: Systems,
+        textures: GameTextures<'a>,
         item_registry: &'a ItemRegistry,
         save_manager: SaveManager,
     ) -> Result<Self, String> {
-        // Create systems with animation configs
-        let systems = Systems::new(player_config.clone(), slime_config, punch_config);
+        // Create every zone in its new-game state (player starts in the meadow)
+        let mut zones = Vec::new();
+        for zone_id in zone::zone_ids() {
+            let new_zone = zone::create_zone(zone_id, &systems.pyramid_registry, textures.entity)?;
+            zones.push(new_zone);
+        }
+        let meadow_index = zones.iter().position(|z| z.id == MEADOW_ZONE_ID)
+            .ok_or("Missing meadow zone")?;
+        let meadow = zones.remove(meadow_index);
 
-        // Create fresh player at spawn position
-        let animation_controller = player_config.create_controller(
-            character_texture,
+        // Create fresh player at the map's start position (or the default spawn)
+        let animation_controller = systems.player_config.create_controller(
+            textures.character,
             &["idle", "running", "attack", "damage", "death"],
         )?;
-        let mut new_player = Player::new(300, 200, 32, 32, 3);
+        let (spawn_x, spawn_y) = meadow.player_spawn.map_or((300, 200), |spawn| (spawn.x, spawn.y));
+        let mut new_player = Player::new(spawn_x, spawn_y, 32, 32, 3);
         new_player.set_animation_controller(animation_controller);
 
-        // Create fresh world grid
-        let new_world_grid = WorldGrid::new(40, 24, TileId::Grass);
-        let new_render_grid = RenderGrid::new(&new_world_grid);
-
-        // Create pyramid entities at their spawn positions
-        let entity_spawn_data = [
-            (160, 120, EntityType::Attack),
-            (480, 120, EntityType::Defense),
-            (160, 240, EntityType::Speed),
-            (480, 240, EntityType::Regeneration),
-        ];
-
-        let mut new_entities: Vec<TheEntity> = Vec::new();
-        for (id, (x, y, entity_type)) in entity_spawn_data.iter().enumerate() {
-            let mut frames = Vec::new();
-            for i in 0..13 {
-                frames.push(sprite::Frame::new(i * 32, 0, 32, 32, 100));
-            }
-            let sprite_sheet = sprite::SpriteSheet::new(entity_texture, frames);
-            new_entities.push(TheEntity::new(id, *x, *y, *entity_type, sprite_sheet));
+        // Starter kit: farming tools and a few seeds
+        let mut player_inventory = PlayerInventory::new();
+        for (item_id, quantity) in [("hoe", 1), ("watering_can", 1), ("wheat_seeds", 5), ("carrot_seeds", 3)] {
+            player_inventory.quick_add(item_id, quantity, item_registry)
+                .map_err(|e| format!("Failed to add starter item {}: {:?}", item_id, e))?;
         }
 
         // Create UI components
         let player_health_bar = HealthBar::new();
+        let player_stamina_bar = stamina_bar();
+        let boss_health_bar = boss_health_bar();
         let enemy_health_bar = HealthBar::with_style(HealthBarStyle {
             health_color: Color::RGB(150, 0, 150),
             low_health_color: Color::RGB(200, 0, 0),
@@ -1089,19 +1784,24 @@ impl<'a> Game<'a> {
         let buff_display = BuffDisplay::new(texture_creator)?;
         let save_exit_menu = SaveExitMenu::new();
         let death_screen = DeathScreen::new();
-        let inventory_ui = InventoryUI::new(item_textures, item_registry);
+        let inventory_ui = InventoryUI::new(textures.items, item_registry);
 
         let ui = UIManager {
             save_exit_menu,
             death_screen,
             inventory_ui,
+            character_screen: CharacterScreen::new(),
             player_health_bar,
+            player_stamina_bar,
             enemy_health_bar,
+            boss_health_bar,
             floating_text_renderer,
             buff_display,
             debug_menu_state: DebugMenuState::Closed,
             show_collision_boxes: false,
             show_tile_grid: false,
+            show_nav_paths: false,
+            map_editor: MapEditor::new(),
             is_tilling: false,
             last_tilled_tile: None,
             mouse_x: 0,
@@ -1109,28 +1809,7 @@ impl<'a> Game<'a> {
         };
 
         // Assemble game world
-        let world = GameWorld {
-            player: new_player,
-            slimes: Vec::new(),
-            entities: new_entities,
-            dropped_items: Vec::new(),
-            world_grid: new_world_grid,
-            render_grid: new_render_grid,
-            player_inventory: PlayerInventory::new(),
-            attack_effects: Vec::new(),
-            floating_texts: Vec::new(),
-            active_attack: None,
-        };
-
-        // Create texture references struct
-        let textures = GameTextures {
-            character: character_texture,
-            slime: slime_texture,
-            entity: entity_texture,
-            punch: punch_texture,
-            grass_tile: grass_tile_texture,
-            items: item_textures,
-        };
+        let world = GameWorld::new(new_player, player_inventory, meadow, zones);
 
         // Assemble and return complete Game struct
         Ok(Game {
@@ -1154,30 +1833,19 @@ impl<'a> Game<'a> {
         texture_creator: &'a sdl2::render::TextureCreator<sdl2::video::WindowContext>,
         canvas: sdl2::render::Canvas<sdl2::video::Window>,
         event_pump: sdl2::EventPump,
-        player_config: AnimationConfig,
-        slime_config: AnimationConfig,
-        punch_config: AnimationConfig,
-        character_texture: &'a sdl2::render::Texture<'a>,
-        slime_texture: &'a sdl2::render::Texture<'a>,
-        entity_texture: &'a sdl2::render::Texture<'a>,
-        punch_texture: &'a sdl2::render::Texture<'a>,
-        grass_tile_texture: &'a sdl2::render::Texture<'a>,
-        item_textures: &'a HashMap<String, sdl2::render::Texture<'a>>,
+        mut systems: Systems,
+        textures: GameTextures<'a>,
         item_registry: &'a ItemRegistry,
         save_manager: SaveManager,
     ) -> Result<Self, String> {
         // Try to load game data from save file
-        let (player, slimes, world_grid, entities, player_inventory, dropped_items) =
-            Self::load_game_data(&save_manager, &player_config, &slime_config, character_texture, slime_texture, entity_texture, item_textures)?;
-
-        // Create render grid from loaded world
-        let render_grid = RenderGrid::new(&world_grid);
-
-        // Create systems with animation configs
-        let systems = Systems::new(player_config.clone(), slime_config, punch_config);
+        let (world, clock) = load_game(&save_manager, &systems, &textures)?;
+        systems.clock = clock;
 
         // Create UI components
         let player_health_bar = HealthBar::new();
+        let player_stamina_bar = stamina_bar();
+        let boss_health_bar = boss_health_bar();
         let enemy_health_bar = HealthBar::with_style(HealthBarStyle {
             health_color: Color::RGB(150, 0, 150),
             low_health_color: Color::RGB(200, 0, 0),
@@ -1187,49 +1855,30 @@ impl<'a> Game<'a> {
         let buff_display = BuffDisplay::new(texture_creator)?;
         let save_exit_menu = SaveExitMenu::new();
         let death_screen = DeathScreen::new();
-        let inventory_ui = InventoryUI::new(item_textures, item_registry);
+        let inventory_ui = InventoryUI::new(textures.items, item_registry);
 
         let ui = UIManager {
             save_exit_menu,
             death_screen,
             inventory_ui,
+            character_screen: CharacterScreen::new(),
             player_health_bar,
+            player_stamina_bar,
             enemy_health_bar,
+            boss_health_bar,
             floating_text_renderer,
             buff_display,
             debug_menu_state: DebugMenuState::Closed,
             show_collision_boxes: false,
             show_tile_grid: false,
+            show_nav_paths: false,
+            map_editor: MapEditor::new(),
             is_tilling: false,
             last_tilled_tile: None,
             mouse_x: 0,
             mouse_y: 0,
         };
 
-        // Assemble game world from loaded data
-        let world = GameWorld {
-            player,
-            slimes,
-            entities,
-            dropped_items,
-            world_grid,
-            render_grid,
-            player_inventory,
-            attack_effects: Vec::new(),
-            floating_texts: Vec::new(),
-            active_attack: None,
-        };
-
-        // Create texture references struct
-        let textures = GameTextures {
-            character: character_texture,
-            slime: slime_texture,
-            entity: entity_texture,
-            punch: punch_texture,
-            grass_tile: grass_tile_texture,
-            items: item_textures,
-        };
-
         // Assemble and return complete Game struct
         Ok(Game {
             world,
@@ -1245,145 +1894,57 @@ impl<'a> Game<'a> {
             input_system: input_system::InputSystem::new(),
         })
     }
+}
 
-    /// Helper method to load game data from save file
-    /// Returns all game entities loaded from the save file
-    fn load_game_data(
-        save_manager: &SaveManager,
-        player_config: &AnimationConfig,
-        slime_config: &AnimationConfig,
-        character_texture: &'a sdl2::render::Texture<'a>,
-        slime_texture: &'a sdl2::render::Texture<'a>,
-        entity_texture: &'a sdl2::render::Texture<'a>,
-        item_textures: &'a HashMap<String, sdl2::render::Texture<'a>>,
-    ) -> Result<(Player<'a>, Vec<Slime<'a>>, WorldGrid, Vec<TheEntity<'a>>, PlayerInventory, Vec<DroppedItem<'a>>), String> {
-        // Load save file from slot 1
-        let save_file = save_manager.load_game(1)
-            .map_err(|e| format!("Failed to load save: {}", e))?;
-
-        println!("Loading game...");
-        println!("  - Save version: {}", save_file.version);
-        println!("  - Saved: {:?}", save_file.timestamp);
-
-        // Load world
-        let world_grid = WorldGrid::from_save_data(
-            save_file.world_state.width,
-            save_file.world_state.height,
-            save_file.world_state.tiles,
-        ).ok_or_else(|| "Failed to load world grid".to_string())?;
-
-        println!("  - Loaded world: {}x{} tiles", world_grid.width, world_grid.height);
-
-        // Load entities
-        let mut player: Option<Player> = None;
-        let mut slimes: Vec<Slime> = Vec::new();
-        let mut loaded_entities: Vec<TheEntity> = Vec::new();
-        let mut player_inventory = PlayerInventory::new();
-        let mut dropped_items = Vec::new();
-
-        for entity_data in save_file.entities {
-            match entity_data.entity_type.as_str() {
-                "player" => {
-                    let save_data = SaveData {
-                        data_type: "player".to_string(),
-                        json_data: entity_data.data,
-                    };
-
-                    let mut loaded_player = Player::from_save_data(&save_data)
-                        .map_err(|e| format!("Failed to load player: {}", e))?;
-
-                    let animation_controller = player_config.create_controller(
-                        character_texture,
-                        &["idle", "running", "attack", "damage", "death"],
-                    ).map_err(|e| format!("Failed to create player animations: {}", e))?;
-
-                    loaded_player.set_animation_controller(animation_controller);
-                    player = Some(loaded_player);
-                    println!("  - Loaded player at ({}, {})", entity_data.position.0, entity_data.position.1);
-                }
-                "slime" => {
-                    let save_data = SaveData {
-                        data_type: "slime".to_string(),
-                        json_data: entity_data.data,
-                    };
-
-                    let mut loaded_slime = Slime::from_save_data(&save_data)
-                        .map_err(|e| format!("Failed to load slime: {}", e))?;
-
-                    let slime_animation_controller = slime_config.create_controller(
-                        slime_texture,
-                        &["slime_idle", "jump", "slime_damage", "slime_death"],
-                    ).map_err(|e| format!("Failed to create slime animations: {}", e))?;
-
-                    loaded_slime.set_animation_controller(slime_animation_controller);
-                    slimes.push(loaded_slime);
-                }
-                "the_entity" => {
-                    #[derive(Deserialize)]
-                    struct EntitySaveData {
-                        id: usize,
-                        x: i32,
-                        y: i32,
-                        state: EntityState,
-                        awakening_frame: usize,
-                        inactivity_timer: f32,
-                        entity_type: EntityType,
-                    }
-
-                    let saved_entity: EntitySaveData = serde_json::from_str(&entity_data.data)
-                        .map_err(|e| format!("Failed to deserialize entity: {}", e))?;
-
-                    let mut frames = Vec::new();
-                    for i in 0..13 {
-                        frames.push(sprite::Frame::new(i * 32, 0, 32, 32, 100));
-                    }
-                    let sprite_sheet = sprite::SpriteSheet::new(entity_texture, frames);
 
-                    let mut loaded_entity = TheEntity::new(saved_entity.id, saved_entity.x, saved_entity.y, saved_entity.entity_type, sprite_sheet);
-                    loaded_entity.state = saved_entity.state;
-                    loaded_entity.awakening_frame = saved_entity.awakening_frame;
-                    loaded_entity.inactivity_timer = saved_entity.inactivity_timer;
+/// The player's stamina bar: a thin yellow bar just under their health bar
+fn stamina_bar() -> HealthBar {
+    let health_style = HealthBarStyle::default();
+    HealthBar::with_style(HealthBarStyle {
+        height: 3,
+        offset_y: health_style.offset_y + health_style.height as i32 + 1,
+        health_color: Color::RGB(230, 200, 40),
+        low_health_color: Color::RGB(200, 110, 30),
+        ..health_style
+    })
+}
 
-                    loaded_entity.update_sprite_frame();
+/// Screen-wide bar for the boss being fought, just above the hotbar
+fn boss_health_bar() -> HealthBar {
+    HealthBar::with_style(HealthBarStyle {
+        width: GAME_WIDTH - 2 * BOSS_BAR_MARGIN,
+        height: BOSS_BAR_HEIGHT,
+        offset_y: 0,
+        background_color: Color::RGB(40, 25, 40),
+        health_color: Color::RGB(230, 170, 40),
+        low_health_color: Color::RGB(210, 40, 40),
+        border_color: Color::RGB(255, 230, 150),
+        border_thickness: 1,
+        show_when_full: true,
+    })
+}
 
-                    loaded_entities.push(loaded_entity);
-                }
-                "player_inventory" => {
-                    player_inventory = serde_json::from_str(&entity_data.data).map_err(|e| format!("Failed to load player inventory: {}", e))?;
-                }
-                "dropped_item" => {
-                    let save_data = SaveData {
-                        data_type: "dropped_item".to_string(),
-                        json_data: entity_data.data,
-                    };
-                    let mut item = DroppedItem::from_save_data(&save_data).map_err(|e| e.to_string())?;
-                    let mut item_animation_controller = animation::AnimationController::new();
-                    let item_frames = vec![
-                        sprite::Frame::new(0, 0, 32, 32, 300),
-                    ];
-                    let item_texture = item_textures.get(&item.item_id).ok_or(format!("Missing texture for item {}", item.item_id))?;
-                    let item_sprite_sheet = SpriteSheet::new(item_texture, item_frames);
-                    item_animation_controller.add_animation("item_idle".to_string(), item_sprite_sheet);
-                    item_animation_controller.set_state("item_idle".to_string());
-                    item.set_animation_controller(item_animation_controller);
-                    dropped_items.push(item);
-                }
-                unknown => {
-                    eprintln!("Warning: Unknown entity type '{}', skipping", unknown);
-                }
-            }
-        }
+/// Draw the boss bar with the boss's title above it and a notch where each later phase begins
+fn render_boss_health_bar(
+    canvas: &mut sdl2::render::Canvas<sdl2::video::Window>,
+    health_bar: &HealthBar,
+    boss: &BossConfig,
+    health_fraction: f32,
+) -> Result<(), String> {
+    health_bar.render(canvas, 0, BOSS_BAR_Y, GAME_WIDTH, BOSS_BAR_HEIGHT, health_fraction)?;
 
-        let player = player.ok_or_else(|| "No player found in save file".to_string())?;
-        println!("  - Loaded {} slimes", slimes.len());
-        println!("  - Loaded {} entities", loaded_entities.len());
-        println!("✓ Game loaded successfully!");
+    let title_width = (boss.title.len() * 12) as i32;
+    draw_simple_text(canvas, &boss.title, (GAME_WIDTH as i32 - title_width) / 2, BOSS_BAR_Y - 18, Color::RGB(255, 230, 150), 2)?;
 
-        Ok((player, slimes, world_grid, loaded_entities, player_inventory, dropped_items))
+    canvas.set_draw_color(Color::RGB(255, 230, 150));
+    let bar_width = (GAME_WIDTH - 2 * BOSS_BAR_MARGIN) as f32;
+    for phase in boss.phases.iter().skip(1) {
+        let x = BOSS_BAR_MARGIN as i32 + (bar_width * phase.health_fraction) as i32;
+        canvas.draw_line((x, BOSS_BAR_Y - 2), (x, BOSS_BAR_Y + BOSS_BAR_HEIGHT as i32 + 1))?;
     }
+    Ok(())
 }
 
-
 /// Generic texture loading helper
 ///
 /// Loads a texture from the given path with consistent error handling
@@ -1434,15 +1995,15 @@ fn calculate_window_scale(video_subsystem: &sdl2::VideoSubsystem) -> u32 {
 }
 
 /// Load game state from save file
+///
+/// Returns the assembled world (player, inventory and every zone) plus the game clock.
+/// Saves from before zones existed are loaded into the meadow; zones missing from
+/// the save start in their new-game state.
 fn load_game<'a>(
     save_manager: &SaveManager,
-    player_config: &AnimationConfig,
-    slime_config: &AnimationConfig,
-    character_texture: &'a sdl2::render::Texture<'a>,
-    slime_texture: &'a sdl2::render::Texture<'a>,
-    entity_texture: &'a sdl2::render::Texture<'a>,
-    item_textures: &'a HashMap<String, sdl2::render::Texture<'a>>,
-) -> Result<(Player<'a>, Vec<Slime<'a>>, WorldGrid, Vec<TheEntity<'a>>, PlayerInventory, Vec<DroppedItem<'a>>), String> {
+    systems: &Systems,
+    textures: &GameTextures<'a>,
+) -> Result<(GameWorld<'a>, GameClock), String> {
     // Load save file from slot 1
     let save_file = save_manager.load_game(1)
         .map_err(|e| format!("Failed to load save: {}", e))?;
@@ -1451,21 +2012,17 @@ fn load_game<'a>(
     println!("  - Save version: {}", save_file.version);
     println!("  - Saved: {:?}", save_file.timestamp);
 
-    // Load world
-    let world_grid = WorldGrid::from_save_data(
-        save_file.world_state.width,
-        save_file.world_state.height,
-        save_file.world_state.tiles,
-    ).ok_or_else(|| "Failed to load world grid".to_string())?;
-
-    println!("  - Loaded world: {}x{} tiles", world_grid.width, world_grid.height);
+    // Start every zone in its new-game state, then overwrite from the save
+    let mut zones: HashMap<String, Zone> = HashMap::new();
+    for zone_id in zone::zone_ids() {
+        zones.insert(zone_id.to_string(), zone::create_zone(zone_id, &systems.pyramid_registry, textures.entity)?);
+    }
 
-    // Load entities
+    // Load global entities (player, inventory, clock)
     let mut player: Option<Player> = None;
-    let mut slimes: Vec<Slime> = Vec::new();
-    let mut loaded_entities: Vec<TheEntity> = Vec::new();
     let mut player_inventory = PlayerInventory::new();
-    let mut dropped_items = Vec::new();
+    let mut clock = GameClock::new();
+    let mut legacy_zone_entities = Vec::new();
 
     for entity_data in save_file.entities {
         match entity_data.entity_type.as_str() {
@@ -1478,8 +2035,8 @@ fn load_game<'a>(
                 let mut loaded_player = Player::from_save_data(&save_data)
                     .map_err(|e| format!("Failed to load player: {}", e))?;
 
-                let animation_controller = player_config.create_controller(
-                    character_texture,
+                let animation_controller = systems.player_config.create_controller(
+                    textures.character,
                     &["idle", "running", "attack", "damage", "death"],
                 ).map_err(|e| format!("Failed to create player animations: {}", e))?;
 
@@ -1487,22 +2044,107 @@ fn load_game<'a>(
                 player = Some(loaded_player);
                 println!("  - Loaded player at ({}, {})", entity_data.position.0, entity_data.position.1);
             }
-            "slime" => {
+            "player_inventory" => {
+                player_inventory = serde_json::from_str(&entity_data.data).map_err(|e| format!("Failed to load player inventory: {}", e))?;
+            }
+            "game_clock" => {
+                clock = serde_json::from_str(&entity_data.data).map_err(|e| format!("Failed to load game clock: {}", e))?;
+            }
+            _ => {
+                // Pre-zone saves keep zone entities at the top level
+                legacy_zone_entities.push(entity_data);
+            }
+        }
+    }
+
+    let zone_saves = if save_file.zones.is_empty() {
+        vec![ZoneSaveData {
+            zone_id: MEADOW_ZONE_ID.to_string(),
+            world_state: save_file.world_state,
+            entities: legacy_zone_entities,
+            layout: None,
+        }]
+    } else {
+        save_file.zones
+    };
+
+    for zone_save in zone_saves {
+        let Some(zone) = zones.get_mut(&zone_save.zone_id) else {
+            eprintln!("Warning: Unknown zone '{}', skipping", zone_save.zone_id);
+            continue;
+        };
+        load_zone(zone, zone_save, systems, textures)?;
+    }
+
+    let active_zone_id = if zones.contains_key(&save_file.active_zone) {
+        save_file.active_zone
+    } else {
+        MEADOW_ZONE_ID.to_string()
+    };
+    let active_zone = zones.remove(&active_zone_id)
+        .ok_or_else(|| format!("Missing zone '{}'", active_zone_id))?;
+
+    let player = player.ok_or_else(|| "No player found in save file".to_string())?;
+    println!("  - Active zone: {}", active_zone.name);
+    println!("✓ Game loaded successfully!");
+
+    let world = GameWorld::new(player, player_inventory, active_zone, zones.into_values().collect());
+    Ok((world, clock))
+}
+
+/// Load one zone's tiles and entities from its save data
+///
+/// Replaces the zone's new-game contents entirely (including its pyramids).
+fn load_zone<'a>(
+    zone: &mut Zone<'a>,
+    zone_save: ZoneSaveData,
+    systems: &Systems,
+    textures: &GameTextures<'a>,
+) -> Result<(), String> {
+    let world_grid = WorldGrid::from_save_data(
+        zone_save.world_state.width,
+        zone_save.world_state.height,
+        zone_save.world_state.tiles,
+    ).ok_or_else(|| format!("Failed to load world grid for zone '{}'", zone.id))?;
+
+    println!("  - Loaded zone '{}': {}x{} tiles", zone.id, world_grid.width, world_grid.height);
+    zone.set_world_grid(world_grid);
+    zone.enemies.clear();
+    zone.entities.clear();
+    zone.dropped_items.clear();
+    zone.farm = Farm::new();
+
+    if let Some(layout) = zone_save.layout {
+        zone.static_objects = layout.static_objects.iter().map(|obj| obj.to_static_object()).collect();
+        zone.spawn_points = layout.spawn_points;
+        if layout.player_spawn.is_some() {
+            zone.player_spawn = layout.player_spawn;
+        }
+        if let Some(spawners) = layout.spawners {
+            zone.spawners = spawners;
+        }
+        if let Some(pyramid_groups) = layout.pyramid_groups {
+            zone.pyramid_groups = pyramid_groups;
+        }
+    }
+
+    for entity_data in zone_save.entities {
+        match entity_data.entity_type.as_str() {
+            // "slime" is the pre-definition format (always a slime)
+            "enemy" | "slime" => {
                 let save_data = SaveData {
-                    data_type: "slime".to_string(),
+                    data_type: entity_data.entity_type.clone(),
                     json_data: entity_data.data,
                 };
 
-                let mut loaded_slime = Slime::from_save_data(&save_data)
-                    .map_err(|e| format!("Failed to load slime: {}", e))?;
-
-                let slime_animation_controller = slime_config.create_controller(
-                    slime_texture,
-                    &["slime_idle", "jump", "slime_damage", "slime_death"],
-                ).map_err(|e| format!("Failed to create slime animations: {}", e))?;
+                let mut loaded_enemy = Enemy::from_save_data(&save_data)
+                    .map_err(|e| format!("Failed to load enemy: {}", e))?;
 
-                loaded_slime.set_animation_controller(slime_animation_controller);
-                slimes.push(loaded_slime);
+                if let Err(e) = systems.enemy_registry.restore(&mut loaded_enemy, textures.enemies) {
+                    eprintln!("Warning: {}, skipping", e);
+                    continue;
+                }
+                zone.enemies.push(loaded_enemy);
             }
             "the_entity" => {
                 #[derive(Deserialize)]
@@ -1512,30 +2154,29 @@ fn load_game<'a>(
                     y: i32,
                     state: EntityState,
                     awakening_frame: usize,
+                    // Missing in saves from before pyramid definitions
+                    #[serde(default)]
+                    hits: Option<u32>,
                     inactivity_timer: f32,
                     entity_type: EntityType,
+                    #[serde(default)]
+                    link: Option<PyramidLink>,
                 }
 
                 let saved_entity: EntitySaveData = serde_json::from_str(&entity_data.data)
                     .map_err(|e| format!("Failed to deserialize entity: {}", e))?;
 
-                let mut frames = Vec::new();
-                for i in 0..13 {
-                    frames.push(sprite::Frame::new(i * 32, 0, 32, 32, 100));
-                }
-                let sprite_sheet = sprite::SpriteSheet::new(entity_texture, frames);
-
-                let mut loaded_entity = TheEntity::new(saved_entity.id, saved_entity.x, saved_entity.y, saved_entity.entity_type, sprite_sheet);
-                loaded_entity.state = saved_entity.state;
-                loaded_entity.awakening_frame = saved_entity.awakening_frame;
-                loaded_entity.inactivity_timer = saved_entity.inactivity_timer;
-
-                loaded_entity.update_sprite_frame();
+                let definition = systems.pyramid_registry.get(saved_entity.entity_type);
+                let mut loaded_entity = zone::create_pyramid(saved_entity.id, saved_entity.x, saved_entity.y, definition, textures.entity);
+                loaded_entity.link = saved_entity.link;
+                loaded_entity.restore_progress(
+                    saved_entity.state,
+                    saved_entity.awakening_frame,
+                    saved_entity.hits,
+                    saved_entity.inactivity_timer,
+                );
 
-                loaded_entities.push(loaded_entity);
-            }
-            "player_inventory" => {
-                player_inventory = serde_json::from_str(&entity_data.data).map_err(|e| format!("Failed to load player inventory: {}", e))?;
+                zone.entities.push(loaded_entity);
             }
             "dropped_item" => {
                 let save_data = SaveData {
@@ -1547,12 +2188,21 @@ fn load_game<'a>(
                 let item_frames = vec![
                     sprite::Frame::new(0, 0, 32, 32, 300),
                 ];
-                let item_texture = item_textures.get(&item.item_id).ok_or(format!("Missing texture for item {}", item.item_id))?;
+                let item_texture = textures.items.get(&item.item_id).ok_or(format!("Missing texture for item {}", item.item_id))?;
                 let item_sprite_sheet = SpriteSheet::new(item_texture, item_frames);
                 item_animation_controller.add_animation("item_idle".to_string(), item_sprite_sheet);
                 item_animation_controller.set_state("item_idle".to_string());
                 item.set_animation_controller(item_animation_controller);
-                dropped_items.push(item);
+                zone.dropped_items.push(item);
+            }
+            "farm_plot" => {
+                let save_data = SaveData {
+                    data_type: "farm_plot".to_string(),
+                    json_data: entity_data.data,
+                };
+                let plot = FarmPlot::from_save_data(&save_data)
+                    .map_err(|e| format!("Failed to load farm plot: {}", e))?;
+                zone.farm.insert_plot(plot);
             }
             unknown => {
                 eprintln!("Warning: Unknown entity type '{}', skipping", unknown);
@@ -1560,49 +2210,120 @@ fn load_game<'a>(
         }
     }
 
-    let player = player.ok_or_else(|| "No player found in save file".to_string())?;
-    println!("  - Loaded {} slimes", slimes.len());
-    println!("  - Loaded {} entities", loaded_entities.len());
-    println!("✓ Game loaded successfully!");
-
-    Ok((player, slimes, world_grid, loaded_entities, player_inventory, dropped_items))
+    println!("    {} enemies, {} entities, {} items", zone.enemies.len(), zone.entities.len(), zone.dropped_items.len());
+    Ok(())
 }
 
 /// Save the current game state
-fn save_game(
-    save_manager: &mut SaveManager,
-    player: &Player,
-    slimes: &[Slime],
-    world_grid: &WorldGrid,
-    the_entities: &[TheEntity],
-    player_inventory: &PlayerInventory,
-    dropped_items: &[DroppedItem],
-) -> Result<(), String> {
+///
+/// Global state (player, inventory, clock) goes in the top-level entity list.
+/// Every zone, active or not, is written to `zones`.
+fn save_game(save_manager: &mut SaveManager, world: &GameWorld, clock: &GameClock) -> Result<(), String> {
     let mut entities_vec = Vec::new();
 
-    let player_save_data = player.to_save_data()
+    let player_save_data = world.player.to_save_data()
         .map_err(|e| format!("Failed to save player: {}", e))?;
 
     entities_vec.push(EntitySaveData {
         entity_id: 0,
         entity_type: "player".to_string(),
-        position: player.position(),
+        position: world.player.position(),
         data: player_save_data.json_data,
     });
 
+    let inventory_data = serde_json::to_string(&world.player_inventory).map_err(|e| format!("Failed to serialize player inventory: {}", e))?;
+    entities_vec.push(EntitySaveData {
+        entity_id: u64::MAX - 1, // Special ID for inventory
+        entity_type: "player_inventory".to_string(),
+        position: (0, 0),
+        data: inventory_data,
+    });
+
+    let clock_data = serde_json::to_string(clock).map_err(|e| format!("Failed to serialize game clock: {}", e))?;
+    entities_vec.push(EntitySaveData {
+        entity_id: u64::MAX - 2, // Special ID for game clock
+        entity_type: "game_clock".to_string(),
+        position: (0, 0),
+        data: clock_data,
+    });
+
+    // Active zone lives in the world's fields, inactive zones in their own structs
+    let mut zones = vec![save_zone(
+        &world.zone_id,
+        &world.world_grid,
+        &world.enemies,
+        &world.entities,
+        &world.dropped_items,
+        &world.farm,
+        ZoneLayoutSaveData::capture(&world.static_objects, &world.spawn_points, world.player_spawn, &world.spawners, &world.pyramid_groups),
+    )?];
+    for zone in world.inactive_zones.values() {
+        let layout = ZoneLayoutSaveData::capture(&zone.static_objects, &zone.spawn_points, zone.player_spawn, &zone.spawners, &zone.pyramid_groups);
+        zones.push(save_zone(&zone.id, &zone.world_grid, &zone.enemies, &zone.entities, &zone.dropped_items, &zone.farm, layout)?);
+    }
+
+    // Top-level world state mirrors the active zone
+    let world_state = WorldSaveData {
+        width: world.world_grid.width,
+        height: world.world_grid.height,
+        tiles: world.world_grid.to_save_data(),
+    };
+
+    let zone_count = zones.len();
+    let zone_entity_count: usize = zones.iter().map(|zone| zone.entities.len()).sum();
+
+    let save_file = SaveFile {
+        version: CURRENT_SAVE_VERSION,
+        timestamp: SystemTime::now(),
+        metadata: SaveMetadata {
+            game_version: env!("CARGO_PKG_VERSION").to_string(),
+            player_name: None,
+            playtime_seconds: 0, // TODO: track playtime
+            save_type: SaveType::Manual,
+            save_slot: save_manager.get_save_slot(),
+        },
+        world_state,
+        entities: entities_vec,
+        active_zone: world.zone_id.clone(),
+        zones,
+    };
+
+    save_manager.save_game(&save_file)
+        .map_err(|e| format!("Save failed: {}", e))?;
+
+    println!("✓ Game saved successfully!");
+    println!("  - Saved {} zones ({} zone entities)", zone_count, zone_entity_count);
+    println!("  - Active zone: {}", world.zone_name);
+    Ok(())
+}
+
+/// Build the save data for one zone
+fn save_zone(
+    zone_id: &str,
+    world_grid: &WorldGrid,
+    enemies: &[Enemy],
+    the_entities: &[TheEntity],
+    dropped_items: &[DroppedItem],
+    farm: &Farm,
+    layout: ZoneLayoutSaveData,
+) -> Result<ZoneSaveData, String> {
+    let mut entities_vec = Vec::new();
+
+    // Bosses aren't saved: their arena doesn't survive a reload, so the fight
+    // starts over from the pyramids
     let mut next_id = 1;
-    for (i, slime) in slimes.iter().enumerate() {
-        let slime_save_data = slime.to_save_data()
-            .map_err(|e| format!("Failed to save slime {}: {}", i, e))?;
+    for (i, enemy) in enemies.iter().enumerate().filter(|(_, enemy)| !enemy.is_boss()) {
+        let enemy_save_data = enemy.to_save_data()
+            .map_err(|e| format!("Failed to save enemy {}: {}", i, e))?;
 
         entities_vec.push(EntitySaveData {
             entity_id: (next_id + i) as u64,
-            entity_type: "slime".to_string(),
-            position: (slime.x, slime.y),
-            data: slime_save_data.json_data,
+            entity_type: "enemy".to_string(),
+            position: enemy.position(),
+            data: enemy_save_data.json_data,
         });
     }
-    next_id += slimes.len();
+    next_id += enemies.len();
 
     for entity in the_entities.iter() {
         let entity_save_data = entity.to_save_data()
@@ -1617,14 +2338,6 @@ fn save_game(
     }
     next_id += the_entities.len();
 
-    let inventory_data = serde_json::to_string(player_inventory).map_err(|e| format!("Failed to serialize player inventory: {}", e))?;
-    entities_vec.push(EntitySaveData {
-        entity_id: u64::MAX - 1, // Special ID for inventory
-        entity_type: "player_inventory".to_string(),
-        position: (0, 0),
-        data: inventory_data,
-    });
-
     for (i, item) in dropped_items.iter().enumerate() {
         let item_save_data = item.to_save_data().map_err(|e| format!("Failed to save dropped item: {}", e))?;
         entities_vec.push(EntitySaveData {
@@ -1634,40 +2347,28 @@ fn save_game(
             data: item_save_data.json_data,
         });
     }
+    next_id += dropped_items.len();
 
-    let world_state = WorldSaveData {
-        width: world_grid.width,
-        height: world_grid.height,
-        tiles: world_grid.to_save_data(),
-    };
-
-    let entity_count = entities_vec.len();
-    let slime_count = slimes.len();
-    let entity_pyramid_count = the_entities.len();
-    let world_width = world_state.width;
-    let world_height = world_state.height;
+    for (i, plot) in farm.plots().enumerate() {
+        let plot_save_data = plot.to_save_data().map_err(|e| format!("Failed to save farm plot: {}", e))?;
+        entities_vec.push(EntitySaveData {
+            entity_id: (next_id + i) as u64,
+            entity_type: "farm_plot".to_string(),
+            position: (plot.tile_x, plot.tile_y),
+            data: plot_save_data.json_data,
+        });
+    }
 
-    let save_file = SaveFile {
-        version: CURRENT_SAVE_VERSION,
-        timestamp: SystemTime::now(),
-        metadata: SaveMetadata {
-            game_version: env!("CARGO_PKG_VERSION").to_string(),
-            player_name: None,
-            playtime_seconds: 0, // TODO: track playtime
-            save_type: SaveType::Manual,
-            save_slot: save_manager.get_save_slot(),
+    Ok(ZoneSaveData {
+        zone_id: zone_id.to_string(),
+        world_state: WorldSaveData {
+            width: world_grid.width,
+            height: world_grid.height,
+            tiles: world_grid.to_save_data(),
         },
-        world_state,
         entities: entities_vec,
-    };
-
-    save_manager.save_game(&save_file)
-        .map_err(|e| format!("Save failed: {}", e))?;
-
-    println!("✓ Game saved successfully!");
-    println!("  - Saved {} entities ({} slimes, {} pyramids)", entity_count, slime_count, entity_pyramid_count);
-    println!("  - Saved world: {}x{} tiles", world_width, world_height);
-    Ok(())
+        layout: Some(layout),
+    })
 }
 
 /// Render the debug stats menu overlay
@@ -1675,6 +2376,7 @@ fn render_debug_menu(
     canvas: &mut sdl2::render::Canvas<sdl2::video::Window>,
     player: &Player,
     debug_config: &DebugConfig,
+    clock: &GameClock,
     selected_index: usize,
 ) -> Result<(), String> {
     canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
@@ -1682,8 +2384,12 @@ fn render_debug_menu(
     canvas.fill_rect(None)?;
     canvas.set_blend_mode(sdl2::render::BlendMode::None);
 
+    let items = DebugMenuItem::all();
+    let item_y_start = 60;
+    let item_height = 24;
+
     let menu_width = 380;
-    let menu_height = 260;
+    let menu_height = item_y_start + items.len() as u32 * item_height + 30;
     let menu_x = (GAME_WIDTH - menu_width) / 2;
     let menu_y = (GAME_HEIGHT - menu_height) / 2;
 
@@ -1727,9 +2433,7 @@ fn render_debug_menu(
         1,
     )?;
 
-    let items = DebugMenuItem::all();
-    let item_y_start = menu_y + 60;
-    let item_height = 35;
+    let item_y_start = menu_y + item_y_start;
 
     for (i, item) in items.iter().enumerate() {
         let item_y = item_y_start + (i as u32 * item_height);
@@ -1739,9 +2443,9 @@ fn render_debug_menu(
             canvas.set_draw_color(sdl2::pixels::Color::RGB(40, 60, 100));
             canvas.fill_rect(sdl2::rect::Rect::new(
                 (menu_x + 10) as i32,
-                item_y as i32 - 2,
+                item_y as i32 - 4,
                 menu_width - 20,
-                30,
+                item_height - 2,
             ))?;
         }
 
@@ -1764,8 +2468,15 @@ fn render_debug_menu(
             DebugMenuItem::PlayerMaxHealth => format!("{}", player.stats.max_health as i32),
             DebugMenuItem::PlayerAttackDamage => format!("{}", player.stats.attack_damage as i32),
             DebugMenuItem::PlayerAttackSpeed => format!("{:.1}", player.stats.attack_speed),
-            DebugMenuItem::SlimeHealth => format!("{}", debug_config.slime_base_health),
-            DebugMenuItem::SlimeContactDamage => format!("{:.1}", debug_config.slime_contact_damage),
+            DebugMenuItem::EnemyHealthScale => format!("{:.2}X", debug_config.enemy_health_scale),
+            DebugMenuItem::EnemyDamageScale => format!("{:.2}X", debug_config.enemy_damage_scale),
+            DebugMenuItem::SpawnEnemyType => debug_config.spawn_enemy_type.replace('_', " ").to_uppercase(),
+            DebugMenuItem::TimeScale => format!("{:.0}X", clock.time_scale),
+            DebugMenuItem::TimeOfDay => format!("{:02}:{:02}", clock.hour(), clock.minute()),
+            DebugMenuItem::GrantXp => {
+                let progression = &player.progression;
+                format!("LV {} {}/{}", progression.level, progression.xp, progression.xp_to_next_level())
+            }
             DebugMenuItem::ClearInventory => "".to_string(), // No value to display
         };
 
@@ -1802,7 +2513,7 @@ fn render_debug_menu(
         canvas,
         "ARROWS   NAVIGATE",
         (menu_x + 40) as i32,
-        (menu_y + 245) as i32,
+        (menu_y + menu_height - 20) as i32,
         sdl2::pixels::Color::RGB(140, 140, 150),
         1,
     )?;
@@ -1810,7 +2521,7 @@ fn render_debug_menu(
         canvas,
         "SHIFT   10",
         (menu_x + 220) as i32,
-        (menu_y + 245) as i32,
+        (menu_y + menu_height - 20) as i32,
         sdl2::pixels::Color::RGB(140, 140, 150),
         1,
     )?;
@@ -1844,16 +2555,13 @@ fn main() -> Result<(), String> {
 
     let player_config = AnimationConfig::load_from_file("assets/config/player_animations.json")
         .map_err(|e| format!("Failed to load player animation config: {}", e))?;
-    let slime_config = AnimationConfig::load_from_file("assets/config/slime_animations.json")
-        .map_err(|e| format!("Failed to load slime animation config: {}", e))?;
-    let punch_config = AnimationConfig::load_from_file("assets/config/punch_effect.json")
-        .map_err(|e| format!("Failed to load punch effect config: {}", e))?;
 
     let character_texture = load_texture(&texture_creator, "assets/sprites/new_player/Character-Base.png")?;
-    let slime_texture = load_texture(&texture_creator, "assets/sprites/slime/Slime.png")?;
     let _background_texture = load_texture(&texture_creator, "assets/backgrounds/background_meadow.png")?;
-    let punch_texture = load_texture(&texture_creator, "assets/sprites/new_player/punch_effect.png")?;
     let grass_tile_texture = load_texture(&texture_creator, "assets/backgrounds/tileable/grass_tile.png")?;
+    let cave_tile_texture = load_texture(&texture_creator, "assets/backgrounds/tileable/cave_tile.png")?;
+    let portal_texture = load_texture(&texture_creator, "assets/sprites/portals.png")?;
+    let crop_texture = load_texture(&texture_creator, "assets/sprites/crops/crops.png")?;
 
     let item_registry = ItemRegistry::create_default();
     println!("✓ Item registry initialized");
@@ -1865,6 +2573,26 @@ fn main() -> Result<(), String> {
     }
     println!("✓ Loaded {} item textures", item_textures.len());
 
+    let enemy_registry = EnemyRegistry::load_from_directory("assets/enemies")?;
+    let mut enemy_textures = HashMap::new();
+    for enemy_id in enemy_registry.ids() {
+        let definition = enemy_registry.get(enemy_id).ok_or("Enemy registry lost a definition")?;
+        // Each type gets its own texture, so a tint only colors that type
+        let mut texture = load_texture(&texture_creator, &definition.sprite_path)?;
+        if let Some((r, g, b)) = definition.tint {
+            texture.set_color_mod(r, g, b);
+        }
+        enemy_textures.insert(enemy_id.to_string(), texture);
+    }
+    println!("✓ Loaded {} enemy types: {}", enemy_textures.len(), enemy_registry.ids().join(", "));
+
+    let weapon_registry = WeaponRegistry::load_from_directory("assets/weapons")?;
+    let mut weapon_textures = HashMap::new();
+    for profile in weapon_registry.all() {
+        weapon_textures.insert(profile.id.clone(), load_texture(&texture_creator, &profile.effect.sprite_path)?);
+    }
+    println!("✓ Loaded {} weapon profiles", weapon_textures.len());
+
     let save_dir = dirs::home_dir()
         .map(|p| p.join(".game1/saves"))
         .unwrap_or_else(|| std::path::PathBuf::from("./saves"));
@@ -1872,68 +2600,75 @@ fn main() -> Result<(), String> {
         .map_err(|e| format!("Failed to create save manager: {}", e))?;
 
     let entity_texture = load_texture(&texture_creator, "assets/sprites/the_entity/entity_awaken.png")?;
+    let pyramid_registry = PyramidRegistry::load_from_directory(pyramid::PYRAMID_DIRECTORY)?;
+    println!("✓ Loaded {} pyramid types", pyramid_registry.len());
 
     println!("Controls:");
     println!("WASD - Move player");
-    println!("M Key - Attack");
+    println!("M Key - Attack (press again in rhythm to combo)");
+    println!("Space - Dodge roll (costs stamina, brief invulnerability)");
+    println!("T Key - Throw a slime ball");
+    println!("C Key - Character screen (spend level-up stat points)");
     println!("F3 - Debug Stats Menu (adjust combat values!)");
+    println!("F4 - Map Editor");
     println!("F5 - Quick Save");
     println!("F9 - Load Game");
     println!("ESC - Exit Menu (Save & Exit, Exit Without Saving, Cancel)");
     println!("B Key - Toggle collision debug boxes");
     println!("G Key - Toggle tile grid debug view");
+    println!("N Key - Toggle enemy pathfinding debug view");
     println!("1 Key - Select Grass tile");
     println!("2 Key - Select Dirt tile");
     println!("Left Click - Place selected tile");
-    println!("Right Click - Spawn slime");
+    println!("Right Click - Spawn enemy (pick the type in the F3 menu)");
+    println!("P Key - Pause/resume the day-night clock");
+    println!("Walk into the cave entrance (top-right) to explore the cave");
+    println!("Wake all four meadow pyramids at once to summon the Slime King");
+    println!("Wake linked pyramids in the right order for a reward (numbers show your progress)");
+    println!("\n=== Farming ===");
+    println!("- Hoe: left click/drag to till grass into soil");
+    println!("- Seeds: left click tilled soil to plant");
+    println!("- Watering Can: left click soil to water (crops grow one stage per watered day)");
+    println!("- Left click a fully grown crop to harvest it");
+    println!("- Empty, dry soil turns back into grass after a while");
+    println!("\n=== Map Editor (F4) ===");
+    println!("- 1 Paint, 2 Fill, 3 Pyramid, 4 Block, 5 Spawner, 6 Player Start");
+    println!("- T: cycle tile / pyramid type, [ and ]: brush size");
+    println!("- Left click: use tool, Right click: delete object");
+    println!("- Ctrl+Z undo, Ctrl+Y redo, Ctrl+S export map to assets/maps/");
     println!("\n=== NEW: Tile Placement System ===");
     println!("- Select tiles with 1 (Grass) or 2 (Dirt)");
     println!("- Left click to place tiles in the world");
-    println!("- Right click to spawn slimes for testing");
+    println!("- Right click to spawn enemies for testing");
     println!("\n=== Collision System ===");
     println!("- Push-apart physics prevents overlap");
-    println!("- Touching slimes without attacking damages player (10 HP total)");
+    println!("- Enemies telegraph their melee attacks: step out of the marked area before it lands");
+    println!("- Spitters keep their distance and spit at you; rock slimes hit hard");
     println!("- 1 second invulnerability after taking damage");
 
+    // Registries and textures shared by a new or loaded game
+    let systems = Systems::new(player_config, enemy_registry, weapon_registry, pyramid_registry);
+    let textures = GameTextures {
+        character: &character_texture,
+        enemies: &enemy_textures,
+        entity: &entity_texture,
+        weapon_effects: &weapon_textures,
+        grass_tile: &grass_tile_texture,
+        cave_tile: &cave_tile_texture,
+        portals: &portal_texture,
+        crops: &crop_texture,
+        items: &item_textures,
+    };
+
     // Try loading existing save, otherwise create new game
-    let mut game = match load_game(&save_manager, &player_config, &slime_config, &character_texture, &slime_texture, &entity_texture, &item_textures) {
+    let mut game = match load_game(&save_manager, &systems, &textures) {
         Ok(_) => {
             println!("✓ Loaded existing save!");
-            Game::load(
-                &texture_creator,
-                canvas,
-                event_pump,
-                player_config,
-                slime_config,
-                punch_config,
-                &character_texture,
-                &slime_texture,
-                &entity_texture,
-                &punch_texture,
-                &grass_tile_texture,
-                &item_textures,
-                &item_registry,
-                save_manager,
-            )?
+            Game::load(&texture_creator, canvas, event_pump, systems, textures, &item_registry, save_manager)?
         }
         Err(_) => {
             println!("No existing save found, starting new game");
-            Game::new(
-                &texture_creator,
-                canvas,
-                event_pump,
-                player_config,
-                slime_config,
-                punch_config,
-                &character_texture,
-                &slime_texture,
-                &entity_texture,
-                &punch_texture,
-                &grass_tile_texture,
-                &item_textures,
-                &item_registry,
-                save_manager,
-            )?
+            Game::new(&texture_creator, canvas, event_pump, systems, textures, &item_registry, save_manager)?
         }
     };
 
@@ -0,0 +1,330 @@
+//! Map files - reusable zone layouts stored as JSON
+//!
+//! A map file describes the static layout of a zone: its tiles, pyramids,
+//! solid static objects, ambient spawn points, enemy spawners and (optionally)
+//! where the player starts. It does not contain anything that changes during
+//! play (enemies, dropped items, farm plots, spawner timers) - that belongs in
+//! save files.
+//!
+//! Map files are produced by the in-game map editor (F4) or imported from Tiled
+//! (see tiled.rs), and are loaded by `zone::create_zone` when a file exists for
+//! the zone, replacing the built-in default layout.
+//!
+//! # Format
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "name": "Meadow",
+//!   "width": 3,
+//!   "height": 2,
+//!   "tiles": [["grass", "grass", "dirt"], ["grass", "dirt", "dirt"]],
+//!   "pyramids": [
+//!     { "x": 160, "y": 120, "entity_type": "Attack", "link": { "group": "pair", "order": 1 } },
+//!     { "x": 480, "y": 120, "entity_type": "Defense", "link": { "group": "pair", "order": 2 } }
+//!   ],
+//!   "pyramid_groups": [{ "id": "pair", "name": "The Pair", "reward": { "xp": 40 } }],
+//!   "static_objects": [{ "x": 64, "y": 32, "width": 32, "height": 32 }],
+//!   "spawn_points": [{ "x": 300, "y": 80 }],
+//!   "spawners": [{
+//!     "id": "north_slimes", "enemy_type": "slime",
+//!     "area": { "x": 200, "y": 40, "width": 96, "height": 64 },
+//!     "max_alive": 3, "respawn_interval": 20.0, "active_time": "Night"
+//!   }],
+//!   "player_spawn": { "x": 300, "y": 200 }
+//! }
+//! ```
+//!
+//! All positions are in world pixels. Pyramids, static objects and spawner
+//! areas use their top-left corner; spawn points are the anchor an enemy is
+//! spawned at. See enemy/spawner.rs for every spawner field, and pyramid.rs
+//! for linked pyramid groups and their rewards.
+
+use crate::collision::StaticObject;
+use crate::enemy::SpawnerDefinition;
+use crate::pyramid::{self, PyramidGroupDefinition, PyramidLink};
+use crate::the_entity::EntityType;
+use crate::tile::{TileId, WorldGrid};
+use crate::tiled::{self, TileMapping};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Current map file format version
+pub const CURRENT_MAP_VERSION: u32 = 1;
+
+/// Directory map files are exported to and loaded from
+pub const MAP_DIRECTORY: &str = "assets/maps";
+
+/// Path of the map file for a zone (e.g. `assets/maps/meadow.json`)
+pub fn map_path(zone_id: &str) -> PathBuf {
+    Path::new(MAP_DIRECTORY).join(format!("{}.json", zone_id))
+}
+
+/// Path of a Tiled map for a zone (e.g. `assets/maps/meadow.tmj`)
+pub fn tiled_map_path(zone_id: &str) -> PathBuf {
+    Path::new(MAP_DIRECTORY).join(format!("{}.tmj", zone_id))
+}
+
+/// Loads the map for a zone, if it has one
+///
+/// A map file exported by the editor takes priority over a Tiled map, so
+/// tweaks made in-game win until the exported file is deleted.
+///
+/// # Returns
+/// Ok(None) when neither file exists, Err if the file that exists is invalid
+pub fn load_zone_map(zone_id: &str) -> Result<Option<(MapFile, PathBuf)>, String> {
+    let path = map_path(zone_id);
+    if path.exists() {
+        return MapFile::load(&path).map(|map_file| Some((map_file, path)));
+    }
+
+    let path = tiled_map_path(zone_id);
+    if path.exists() {
+        return tiled::load_tiled_map(&path, &TileMapping::standard()).map(|map_file| Some((map_file, path)));
+    }
+
+    Ok(None)
+}
+
+/// A point in world pixels (ambient spawn points, player spawn)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpawnPoint {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl SpawnPoint {
+    pub fn new(x: i32, y: i32) -> Self {
+        SpawnPoint { x, y }
+    }
+}
+
+/// A pyramid placement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapPyramid {
+    pub x: i32,
+    pub y: i32,
+    pub entity_type: EntityType,
+    /// Its place in one of the map's `pyramid_groups`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<PyramidLink>,
+}
+
+/// A solid, visible static object (rock/wall block)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MapObject {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl MapObject {
+    /// Builds the collidable object for this placement
+    pub fn to_static_object(self) -> StaticObject {
+        StaticObject::solid(self.x, self.y, self.width, self.height)
+    }
+
+    /// Captures a static object's placement
+    pub fn from_static_object(obj: &StaticObject) -> Self {
+        MapObject {
+            x: obj.x,
+            y: obj.y,
+            width: obj.width,
+            height: obj.height,
+        }
+    }
+}
+
+/// Top-level map file structure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapFile {
+    pub version: u32,
+    pub name: String,
+    pub width: usize,
+    pub height: usize,
+    /// Tile names, row by row (same strings as save files: "grass", "dirt")
+    pub tiles: Vec<Vec<String>>,
+    #[serde(default)]
+    pub pyramids: Vec<MapPyramid>,
+    #[serde(default)]
+    pub pyramid_groups: Vec<PyramidGroupDefinition>,
+    #[serde(default)]
+    pub static_objects: Vec<MapObject>,
+    #[serde(default)]
+    pub spawn_points: Vec<SpawnPoint>,
+    #[serde(default)]
+    pub spawners: Vec<SpawnerDefinition>,
+    #[serde(default)]
+    pub player_spawn: Option<SpawnPoint>,
+}
+
+impl MapFile {
+    /// Creates a map with every tile set to `default_tile` and nothing placed
+    pub fn new(name: &str, width: usize, height: usize, default_tile: TileId) -> Self {
+        MapFile {
+            version: CURRENT_MAP_VERSION,
+            name: name.to_string(),
+            width,
+            height,
+            tiles: vec![vec![default_tile.to_string(); width]; height],
+            pyramids: Vec::new(),
+            pyramid_groups: Vec::new(),
+            static_objects: Vec::new(),
+            spawn_points: Vec::new(),
+            spawners: Vec::new(),
+            player_spawn: None,
+        }
+    }
+
+    /// Reads and validates a map file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read map {}: {}", path.display(), e))?;
+        let map: MapFile = serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse map {}: {}", path.display(), e))?;
+        map.validate()
+            .map_err(|e| format!("Invalid map {}: {}", path.display(), e))?;
+        Ok(map)
+    }
+
+    /// Writes the map as pretty JSON, creating the parent directory if needed
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize map: {}", e))?;
+        fs::write(path, json)
+            .map_err(|e| format!("Failed to write map {}: {}", path.display(), e))
+    }
+
+    /// Checks the version, grid dimensions, tile names, spawner IDs and pyramid groups
+    pub fn validate(&self) -> Result<(), String> {
+        if self.version > CURRENT_MAP_VERSION {
+            return Err(format!("unsupported map version {}", self.version));
+        }
+        if self.width == 0 || self.height == 0 {
+            return Err("map must be at least 1x1".to_string());
+        }
+        if self.tiles.len() != self.height {
+            return Err(format!("expected {} tile rows, found {}", self.height, self.tiles.len()));
+        }
+        for (y, row) in self.tiles.iter().enumerate() {
+            if row.len() != self.width {
+                return Err(format!("row {} has {} tiles, expected {}", y, row.len(), self.width));
+            }
+            if let Some((x, name)) = row.iter().enumerate().find(|(_, name)| TileId::from_string(name).is_none()) {
+                return Err(format!("unknown tile '{}' at ({}, {})", name, x, y));
+            }
+        }
+        for (index, spawner) in self.spawners.iter().enumerate() {
+            if spawner.id.is_empty() {
+                return Err(format!("spawner {} has no id", index));
+            }
+            if self.spawners[..index].iter().any(|other| other.id == spawner.id) {
+                return Err(format!("duplicate spawner id '{}'", spawner.id));
+            }
+        }
+        pyramid::validate_groups(&self.pyramid_groups, self.pyramids.iter().filter_map(|pyramid| pyramid.link.as_ref()))
+    }
+
+    /// Builds the tile grid described by the map
+    pub fn to_world_grid(&self) -> Result<WorldGrid, String> {
+        self.validate()?;
+        WorldGrid::from_save_data(self.width, self.height, self.tiles.clone())
+            .ok_or_else(|| format!("Map '{}' has an invalid tile grid", self.name))
+    }
+
+    /// Replaces the map's tiles with the contents of a grid
+    pub fn set_tiles(&mut self, world_grid: &WorldGrid) {
+        self.width = world_grid.width;
+        self.height = world_grid.height;
+        self.tiles = world_grid.to_save_data();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_map_is_valid() {
+        let map = MapFile::new("Test", 4, 3, TileId::Grass);
+        assert!(map.validate().is_ok());
+
+        let grid = map.to_world_grid().unwrap();
+        assert_eq!(grid.width, 4);
+        assert_eq!(grid.height, 3);
+        assert_eq!(grid.get_tile(3, 2), Some(TileId::Grass));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut map = MapFile::new("Test", 2, 2, TileId::Grass);
+        map.tiles[1][0] = "dirt".to_string();
+        map.pyramids.push(MapPyramid { x: 64, y: 32, entity_type: EntityType::Speed, link: None });
+        map.pyramids.push(MapPyramid {
+            x: 0,
+            y: 32,
+            entity_type: EntityType::XpGain,
+            link: Some(PyramidLink { group: "solo".to_string(), order: 1 }),
+        });
+        map.pyramid_groups.push(serde_json::from_str(r#"{"id":"solo","name":"Solo","reward":{"xp":5}}"#).unwrap());
+        map.static_objects.push(MapObject { x: 0, y: 0, width: 32, height: 32 });
+        map.spawn_points.push(SpawnPoint::new(10, 20));
+        map.spawners.push(serde_json::from_str(r#"{"id":"a","enemy_type":"slime","area":{"x":1,"y":2}}"#).unwrap());
+        map.player_spawn = Some(SpawnPoint::new(40, 40));
+
+        let json = serde_json::to_string(&map).unwrap();
+        let loaded: MapFile = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.tiles, map.tiles);
+        assert_eq!(loaded.pyramids, map.pyramids);
+        assert_eq!(loaded.pyramid_groups, map.pyramid_groups);
+        assert!(loaded.validate().is_ok());
+        assert_eq!(loaded.static_objects, map.static_objects);
+        assert_eq!(loaded.spawn_points, map.spawn_points);
+        assert_eq!(loaded.spawners, map.spawners);
+        assert_eq!(loaded.player_spawn, map.player_spawn);
+    }
+
+    #[test]
+    fn test_optional_sections_default_to_empty() {
+        let json = r#"{"version":1,"name":"Bare","width":1,"height":1,"tiles":[["dirt"]]}"#;
+        let map: MapFile = serde_json::from_str(json).unwrap();
+        assert!(map.pyramids.is_empty());
+        assert!(map.spawn_points.is_empty());
+        assert!(map.spawners.is_empty());
+        assert_eq!(map.player_spawn, None);
+        assert_eq!(map.to_world_grid().unwrap().get_tile(0, 0), Some(TileId::Dirt));
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_grids() {
+        let mut map = MapFile::new("Test", 2, 2, TileId::Grass);
+        map.tiles[0][1] = "lava".to_string();
+        assert!(map.validate().unwrap_err().contains("unknown tile 'lava' at (1, 0)"));
+
+        let mut map = MapFile::new("Test", 2, 2, TileId::Grass);
+        map.tiles.pop();
+        assert!(map.validate().is_err());
+
+        let mut map = MapFile::new("Test", 2, 2, TileId::Grass);
+        map.version = CURRENT_MAP_VERSION + 1;
+        assert!(map.validate().is_err());
+
+        let mut map = MapFile::new("Test", 2, 2, TileId::Grass);
+        map.pyramids.push(MapPyramid {
+            x: 0,
+            y: 0,
+            entity_type: EntityType::Crit,
+            link: Some(PyramidLink { group: "missing".to_string(), order: 1 }),
+        });
+        assert!(map.validate().unwrap_err().contains("unknown group 'missing'"));
+    }
+}
@@ -0,0 +1,321 @@
+//! A* pathfinding over the tile grid
+//!
+//! Enemies navigate on a grid of 32px cells built from the zone's `WorldGrid`:
+//! a cell is walkable when its tile is walkable and no static collidable
+//! (boundary walls, solid map objects, pyramids) overlaps it.
+//!
+//! Movement is 8-directional. A diagonal step is only allowed when both
+//! orthogonal cells it passes between are walkable, so paths never clip the
+//! corner of a wall.
+//!
+//! `Pathfinder` owns the navigation grid plus a cache of recent paths. It is
+//! synced once per frame; whenever a tile or obstacle change alters the grid,
+//! the grid is replaced and every cached path is dropped.
+
+use crate::tile::WorldGrid;
+use sdl2::rect::Rect;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Size of a navigation cell in world pixels (same as a tile)
+pub const NAV_TILE_SIZE: i32 = 32;
+
+/// Cost of an orthogonal step (diagonals cost 14, roughly 10 * sqrt(2))
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+/// Cached paths are dropped wholesale once this many are stored
+const MAX_CACHED_PATHS: usize = 512;
+
+/// A navigation cell (tile coordinates)
+pub type GridPos = (i32, i32);
+
+/// Navigation cell containing a world pixel position
+pub fn world_to_cell(x: f32, y: f32) -> GridPos {
+    ((x / NAV_TILE_SIZE as f32).floor() as i32, (y / NAV_TILE_SIZE as f32).floor() as i32)
+}
+
+/// World pixel position of a cell's center
+pub fn cell_center(cell: GridPos) -> (f32, f32) {
+    (
+        (cell.0 * NAV_TILE_SIZE + NAV_TILE_SIZE / 2) as f32,
+        (cell.1 * NAV_TILE_SIZE + NAV_TILE_SIZE / 2) as f32,
+    )
+}
+
+/// Walkability of every cell in a zone
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NavGrid {
+    pub width: i32,
+    pub height: i32,
+    walkable: Vec<bool>,
+}
+
+impl NavGrid {
+    /// Builds the grid from the zone's tiles and static collidable bounds
+    pub fn from_world(world_grid: &WorldGrid, obstacles: &[Rect]) -> Self {
+        let width = world_grid.width as i32;
+        let height = world_grid.height as i32;
+        let mut walkable = Vec::with_capacity((width * height) as usize);
+
+        for y in 0..height {
+            for x in 0..width {
+                let tile_walkable = world_grid.get_tile(x, y).is_some_and(|tile| tile.is_walkable());
+                let (left, top) = (x * NAV_TILE_SIZE, y * NAV_TILE_SIZE);
+                let blocked = obstacles.iter().any(|obstacle| {
+                    obstacle.x() < left + NAV_TILE_SIZE
+                        && obstacle.x() + obstacle.width() as i32 > left
+                        && obstacle.y() < top + NAV_TILE_SIZE
+                        && obstacle.y() + obstacle.height() as i32 > top
+                });
+                walkable.push(tile_walkable && !blocked);
+            }
+        }
+
+        NavGrid { width, height, walkable }
+    }
+
+    /// True if the cell is inside the grid and can be walked on
+    pub fn is_walkable(&self, cell: GridPos) -> bool {
+        let (x, y) = cell;
+        x >= 0 && y >= 0 && x < self.width && y < self.height
+            && self.walkable[(y * self.width + x) as usize]
+    }
+
+    fn index(&self, cell: GridPos) -> usize {
+        (cell.1 * self.width + cell.0) as usize
+    }
+
+    fn in_bounds(&self, cell: GridPos) -> bool {
+        cell.0 >= 0 && cell.1 >= 0 && cell.0 < self.width && cell.1 < self.height
+    }
+}
+
+/// Octile distance: exact cost of the shortest path on an empty grid
+fn heuristic(a: GridPos, b: GridPos) -> u32 {
+    let dx = (a.0 - b.0).unsigned_abs();
+    let dy = (a.1 - b.1).unsigned_abs();
+    STRAIGHT_COST * dx.max(dy) + (DIAGONAL_COST - STRAIGHT_COST) * dx.min(dy)
+}
+
+/// Finds the cheapest path between two cells
+///
+/// The start and goal cells are always treated as walkable, so an enemy
+/// overlapping a wall (or a player standing against one) can still be routed.
+///
+/// # Returns
+/// The cells to visit after `start`, ending with `goal` (empty when start == goal),
+/// or None if the goal can't be reached
+pub fn find_path(grid: &NavGrid, start: GridPos, goal: GridPos) -> Option<Vec<GridPos>> {
+    if !grid.in_bounds(start) || !grid.in_bounds(goal) {
+        return None;
+    }
+    if start == goal {
+        return Some(Vec::new());
+    }
+
+    let passable = |cell: GridPos| cell == start || cell == goal || grid.is_walkable(cell);
+
+    let cell_count = (grid.width * grid.height) as usize;
+    let mut best_cost = vec![u32::MAX; cell_count];
+    let mut came_from: Vec<Option<GridPos>> = vec![None; cell_count];
+    let mut open = BinaryHeap::new();
+
+    best_cost[grid.index(start)] = 0;
+    open.push(Reverse((heuristic(start, goal), 0, start)));
+
+    while let Some(Reverse((_, cost, cell))) = open.pop() {
+        if cell == goal {
+            let mut path = vec![goal];
+            let mut current = goal;
+            while let Some(previous) = came_from[grid.index(current)] {
+                if previous == start {
+                    break;
+                }
+                path.push(previous);
+                current = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        if cost > best_cost[grid.index(cell)] {
+            continue; // Stale heap entry
+        }
+
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                let next = (cell.0 + dx, cell.1 + dy);
+                if !grid.in_bounds(next) || !passable(next) {
+                    continue;
+                }
+
+                let diagonal = dx != 0 && dy != 0;
+                // No corner cutting: both cells beside a diagonal step must be open
+                if diagonal && !(passable((cell.0 + dx, cell.1)) && passable((cell.0, cell.1 + dy))) {
+                    continue;
+                }
+
+                let next_cost = cost + if diagonal { DIAGONAL_COST } else { STRAIGHT_COST };
+                let next_index = grid.index(next);
+                if next_cost < best_cost[next_index] {
+                    best_cost[next_index] = next_cost;
+                    came_from[next_index] = Some(cell);
+                    open.push(Reverse((next_cost + heuristic(next, goal), next_cost, next)));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Navigation grid plus a cache of recently found paths
+#[derive(Debug, Default)]
+pub struct Pathfinder {
+    grid: NavGrid,
+    cache: HashMap<(GridPos, GridPos), Option<Vec<GridPos>>>,
+}
+
+impl Pathfinder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rebuilds the grid from the current tiles and obstacles
+    ///
+    /// Cached paths are only invalidated when the grid actually changed.
+    ///
+    /// # Returns
+    /// true if the grid changed
+    pub fn sync(&mut self, world_grid: &WorldGrid, obstacles: &[Rect]) -> bool {
+        let grid = NavGrid::from_world(world_grid, obstacles);
+        if grid == self.grid {
+            return false;
+        }
+        self.grid = grid;
+        self.cache.clear();
+        true
+    }
+
+    pub fn grid(&self) -> &NavGrid {
+        &self.grid
+    }
+
+    /// Cached A* search between two cells (see `find_path`)
+    pub fn find_path(&mut self, start: GridPos, goal: GridPos) -> Option<Vec<GridPos>> {
+        if let Some(path) = self.cache.get(&(start, goal)) {
+            return path.clone();
+        }
+
+        if self.cache.len() >= MAX_CACHED_PATHS {
+            self.cache.clear();
+        }
+        let path = find_path(&self.grid, start, goal);
+        self.cache.insert((start, goal), path.clone());
+        path
+    }
+
+    /// Number of cached paths (including cached failures)
+    #[allow(dead_code)] // Reserved for debug stats
+    pub fn cached_path_count(&self) -> usize {
+        self.cache.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tile::TileId;
+
+    fn open_grid(width: usize, height: usize) -> NavGrid {
+        NavGrid::from_world(&WorldGrid::new(width, height, TileId::Grass), &[])
+    }
+
+    /// Blocks whole cells with 32x32 obstacles
+    fn grid_with_walls(width: usize, height: usize, walls: &[GridPos]) -> NavGrid {
+        let obstacles: Vec<Rect> = walls.iter()
+            .map(|&(x, y)| Rect::new(x * NAV_TILE_SIZE, y * NAV_TILE_SIZE, 32, 32))
+            .collect();
+        NavGrid::from_world(&WorldGrid::new(width, height, TileId::Grass), &obstacles)
+    }
+
+    #[test]
+    fn test_obstacles_block_overlapped_cells_only() {
+        // Touching a cell's edge doesn't block it
+        let grid = NavGrid::from_world(&WorldGrid::new(3, 3, TileId::Grass), &[Rect::new(32, 32, 32, 32), Rect::new(0, -10, 96, 10)]);
+        assert!(!grid.is_walkable((1, 1)));
+        assert!(grid.is_walkable((0, 0)));
+        assert!(grid.is_walkable((2, 1)));
+        assert!(!grid.is_walkable((3, 0)), "out of bounds is never walkable");
+    }
+
+    #[test]
+    fn test_straight_and_diagonal_paths() {
+        let grid = open_grid(5, 5);
+        assert_eq!(find_path(&grid, (0, 0), (3, 0)), Some(vec![(1, 0), (2, 0), (3, 0)]));
+        assert_eq!(find_path(&grid, (0, 0), (2, 2)), Some(vec![(1, 1), (2, 2)]));
+        assert_eq!(find_path(&grid, (1, 1), (1, 1)), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_path_goes_around_wall() {
+        // Vertical wall at x=2 with a gap at the bottom
+        let grid = grid_with_walls(5, 4, &[(2, 0), (2, 1), (2, 2)]);
+        let path = find_path(&grid, (0, 0), (4, 0)).unwrap();
+
+        assert_eq!(path.last(), Some(&(4, 0)));
+        assert!(path.contains(&(2, 3)), "must pass through the gap: {:?}", path);
+        assert!(path.iter().all(|&cell| grid.is_walkable(cell)));
+    }
+
+    #[test]
+    fn test_diagonal_never_cuts_corners() {
+        // Wall at (1,0): going from (0,0) to (1,1) diagonally would clip it
+        let grid = grid_with_walls(3, 3, &[(1, 0)]);
+        assert_eq!(find_path(&grid, (0, 0), (1, 1)), Some(vec![(0, 1), (1, 1)]));
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        // Goal boxed in on all sides (including diagonals)
+        let walls = [(3, 2), (4, 2), (5, 2), (3, 3), (5, 3), (3, 4), (4, 4), (5, 4)];
+        let grid = grid_with_walls(7, 7, &walls);
+        assert_eq!(find_path(&grid, (0, 0), (4, 3)), None);
+        assert_eq!(find_path(&grid, (0, 0), (10, 10)), None);
+    }
+
+    #[test]
+    fn test_blocked_start_and_goal_are_allowed() {
+        let grid = grid_with_walls(4, 1, &[(0, 0), (3, 0)]);
+        assert_eq!(find_path(&grid, (0, 0), (3, 0)), Some(vec![(1, 0), (2, 0), (3, 0)]));
+    }
+
+    #[test]
+    fn test_cache_invalidated_when_grid_changes() {
+        let mut world_grid = WorldGrid::new(5, 3, TileId::Grass);
+        let mut pathfinder = Pathfinder::new();
+        assert!(pathfinder.sync(&world_grid, &[]));
+
+        let direct = pathfinder.find_path((0, 1), (4, 1)).unwrap();
+        assert_eq!(direct.len(), 4);
+        assert_eq!(pathfinder.cached_path_count(), 1);
+
+        // Same inputs: grid and cache kept
+        assert!(!pathfinder.sync(&world_grid, &[]));
+        assert_eq!(pathfinder.cached_path_count(), 1);
+
+        // A wall appears across the middle row
+        let wall = [Rect::new(64, 32, 32, 32)];
+        assert!(pathfinder.sync(&world_grid, &wall));
+        assert_eq!(pathfinder.cached_path_count(), 0);
+        let detour = pathfinder.find_path((0, 1), (4, 1)).unwrap();
+        assert!(!detour.contains(&(2, 1)));
+
+        // Tile edits that keep every cell walkable (grass -> dirt) keep the cache
+        world_grid.set_tile(0, 0, TileId::Dirt);
+        assert!(!pathfinder.sync(&world_grid, &wall));
+    }
+}
@@ -0,0 +1,213 @@
+//! Velocity-based movement physics
+//!
+//! Moving things keep an `f32` position and velocity. Each update the velocity
+//! accelerates toward the direction being pushed (up to a max speed) or slows
+//! down under friction when there is no input, and the position integrates the
+//! velocity over the frame's delta time. Positions are only rounded to whole
+//! pixels when drawing or building collision rects.
+//!
+//! Knockback from hits is a separate `Knockback` velocity layered on top of
+//! movement: it slides the target and decays under its own friction, so input
+//! can't cancel it early. Walls stop it on the axis they push back on.
+//!
+//! Movement speed stats are stored in pixels per 60 Hz frame (the unit they
+//! had before movement used delta time); `SPEED_UNITS_PER_SECOND` converts them.
+//!
+//! # Example
+//!
+//! ```rust
+//! let (input_x, input_y) = physics::normalize(1.0, 1.0); // exact diagonal
+//! let max_speed = player_speed_stat * physics::SPEED_UNITS_PER_SECOND;
+//! velocity = MovementPhysics::player().step(velocity, (input_x, input_y), max_speed, delta_time);
+//! x += velocity.0 * delta_time;
+//! ```
+
+/// Converts a movement speed stat (pixels per 60 Hz frame) into pixels per second
+pub const SPEED_UNITS_PER_SECOND: f32 = 60.0;
+
+/// Longest frame the game simulates in one step; a stall (window drag, breakpoint)
+/// is treated as this long instead of teleporting everything
+pub const MAX_FRAME_DELTA: f32 = 0.1;
+
+/// Pixels per second² a knockback slide loses
+pub const KNOCKBACK_FRICTION: f32 = 1200.0;
+
+/// How quickly something speeds up and slows down
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MovementPhysics {
+    /// Pixels per second² gained toward the input direction
+    pub acceleration: f32,
+    /// Pixels per second² lost when there is no input
+    pub friction: f32,
+}
+
+impl MovementPhysics {
+    /// Snappy player movement: full speed in ~0.08s, stops in ~0.1s
+    pub fn player() -> Self {
+        MovementPhysics {
+            acceleration: 2400.0,
+            friction: 1800.0,
+        }
+    }
+
+    /// Returns the velocity after one step
+    ///
+    /// `direction` is a unit vector (or zero for no input). With input, the
+    /// velocity moves toward `direction * max_speed` by at most
+    /// `acceleration * delta_time`, which also turns it and sheds any speed
+    /// above the max. Without input it slows toward zero by friction.
+    pub fn step(&self, velocity: (f32, f32), direction: (f32, f32), max_speed: f32, delta_time: f32) -> (f32, f32) {
+        if direction == (0.0, 0.0) {
+            move_toward(velocity, (0.0, 0.0), self.friction * delta_time)
+        } else {
+            let target = (direction.0 * max_speed, direction.1 * max_speed);
+            move_toward(velocity, target, self.acceleration * delta_time)
+        }
+    }
+}
+
+/// The slide left over from being hit
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Knockback {
+    /// Pixels per second
+    pub velocity: (f32, f32),
+}
+
+impl Knockback {
+    /// Starts a new slide (replacing any current one), reduced by `resistance`
+    ///
+    /// `resistance` is 0.0 (full knockback) to 1.0 (immune).
+    pub fn apply(&mut self, impulse: (f32, f32), resistance: f32) {
+        let scale = 1.0 - resistance.clamp(0.0, 1.0);
+        self.velocity = (impulse.0 * scale, impulse.1 * scale);
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.velocity != (0.0, 0.0)
+    }
+
+    /// Returns how far the slide moves this frame, then decays it
+    pub fn step(&mut self, delta_time: f32) -> (f32, f32) {
+        let displacement = (self.velocity.0 * delta_time, self.velocity.1 * delta_time);
+        self.velocity = move_toward(self.velocity, (0.0, 0.0), KNOCKBACK_FRICTION * delta_time);
+        displacement
+    }
+
+    /// Stops the slide on each axis a wall pushed back on
+    pub fn block(&mut self, push_x: f32, push_y: f32) {
+        if push_x != 0.0 {
+            self.velocity.0 = 0.0;
+        }
+        if push_y != 0.0 {
+            self.velocity.1 = 0.0;
+        }
+    }
+}
+
+/// Scales (x, y) to length 1, leaving a zero vector alone
+pub fn normalize(x: f32, y: f32) -> (f32, f32) {
+    let length = (x * x + y * y).sqrt();
+    if length <= f32::EPSILON {
+        (0.0, 0.0)
+    } else {
+        (x / length, y / length)
+    }
+}
+
+/// Moves `current` toward `target` by at most `max_delta`
+fn move_toward(current: (f32, f32), target: (f32, f32), max_delta: f32) -> (f32, f32) {
+    let (dx, dy) = (target.0 - current.0, target.1 - current.1);
+    let distance = (dx * dx + dy * dy).sqrt();
+    if distance <= max_delta || distance <= f32::EPSILON {
+        target
+    } else {
+        (current.0 + dx / distance * max_delta, current.1 + dy / distance * max_delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn speed(velocity: (f32, f32)) -> f32 {
+        (velocity.0 * velocity.0 + velocity.1 * velocity.1).sqrt()
+    }
+
+    #[test]
+    fn test_accelerates_to_max_speed_then_holds() {
+        let physics = MovementPhysics { acceleration: 600.0, friction: 600.0 };
+        let mut velocity = (0.0, 0.0);
+
+        velocity = physics.step(velocity, (1.0, 0.0), 180.0, 0.1);
+        assert!((velocity.0 - 60.0).abs() < 0.001);
+
+        for _ in 0..10 {
+            velocity = physics.step(velocity, (1.0, 0.0), 180.0, 0.1);
+        }
+        assert!((velocity.0 - 180.0).abs() < 0.001);
+        assert_eq!(velocity.1, 0.0);
+    }
+
+    #[test]
+    fn test_friction_stops_without_overshooting() {
+        let physics = MovementPhysics { acceleration: 600.0, friction: 500.0 };
+        let mut velocity = physics.step((120.0, 0.0), (0.0, 0.0), 180.0, 0.1);
+        assert!((velocity.0 - 70.0).abs() < 0.001);
+
+        velocity = physics.step(velocity, (0.0, 0.0), 180.0, 0.5);
+        assert_eq!(velocity, (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_diagonal_speed_matches_straight_speed() {
+        let physics = MovementPhysics::player();
+        let diagonal = normalize(-1.0, 1.0);
+        let mut velocity = (0.0, 0.0);
+        for _ in 0..60 {
+            velocity = physics.step(velocity, diagonal, 180.0, 1.0 / 60.0);
+        }
+        assert!((speed(velocity) - 180.0).abs() < 0.001);
+        assert!(velocity.0 < 0.0 && velocity.1 > 0.0);
+        assert_eq!(normalize(0.0, 0.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_knockback_slides_decays_and_is_resisted() {
+        let mut knockback = Knockback::default();
+        knockback.apply((300.0, 0.0), 0.5);
+        assert_eq!(knockback.velocity, (150.0, 0.0));
+
+        let (mut x, mut frames) = (0.0, 0);
+        while knockback.is_active() {
+            x += knockback.step(1.0 / 60.0).0;
+            frames += 1;
+        }
+        // About v² / 2a = 150² / 2400 ≈ 9.4px (a little more in whole frames), in 0.125s
+        assert!(x > 9.0 && x < 11.0, "slid {}", x);
+        assert!(frames <= 8);
+
+        knockback.apply((200.0, -200.0), 1.0);
+        assert!(!knockback.is_active());
+    }
+
+    #[test]
+    fn test_walls_block_knockback_per_axis() {
+        let mut knockback = Knockback::default();
+        knockback.apply((100.0, -80.0), 0.0);
+        knockback.block(-3.0, 0.0);
+        assert_eq!(knockback.velocity, (0.0, -80.0));
+    }
+
+    #[test]
+    fn test_slow_speeds_still_move() {
+        // 0.3 px per frame used to round down to nothing
+        let physics = MovementPhysics::player();
+        let max_speed = 0.3 * SPEED_UNITS_PER_SECOND;
+        let (mut x, mut velocity) = (0.0, (0.0, 0.0));
+        for _ in 0..60 {
+            velocity = physics.step(velocity, (1.0, 0.0), max_speed, 1.0 / 60.0);
+            x += velocity.0 / 60.0;
+        }
+        assert!((x - 18.0).abs() < 0.01);
+    }
+}
@@ -1,9 +1,15 @@
 use crate::animation::{AnimationController, AnimationState, Direction, determine_animation_state};
 use crate::collision::{Collidable, CollisionLayer};
-use crate::combat::{AttackEvent, DamageEvent, PlayerState, calculate_damage_with_defense};
+use crate::buffs::ActiveEffects;
+use crate::combat::{AttackEvent, DamageEvent, PlayerState, calculate_damage_with_resistances};
+use crate::combo::ComboTracker;
+use crate::physics::{self, Knockback, MovementPhysics};
+use crate::progression::Progression;
 use crate::render::DepthSortable;
 use crate::save::{Saveable, SaveData, SaveError};
 use crate::stats::{Stats, DamageResult, ModifierEffect, StatType};
+use crate::status::StatusEffects;
+use crate::weapon::WeaponProfile;
 use sdl2::keyboard::Scancode;
 use sdl2::rect::Rect;
 use sdl2::render::Canvas;
@@ -11,13 +17,28 @@ use sdl2::video::Window;
 use serde::{Serialize, Deserialize};
 use std::time::Instant;
 
+/// Stamina spent per dodge roll
+const DODGE_STAMINA_COST: f32 = 3.0;
+/// Speed of a dodge roll (pixels per second), about three times running speed
+const DODGE_SPEED: f32 = 420.0;
+/// How long a dodge roll lasts (seconds)
+const DODGE_DURATION: f32 = 0.25;
+/// Invulnerable for this long from the start of a roll (seconds)
+const DODGE_IFRAMES: f32 = 0.2;
+
 pub struct Player<'a> {
-    pub x: i32,
-    pub y: i32,
+    /// Anchor (bottom-center) position; sub-pixel, rounded only when drawing
+    pub x: f32,
+    pub y: f32,
     pub width: u32,
     pub height: u32,
-    pub velocity_x: i32,
-    pub velocity_y: i32,
+    /// Pixels per second
+    pub velocity_x: f32,
+    pub velocity_y: f32,
+    /// Acceleration and friction (max speed comes from the MovementSpeed stat)
+    pub movement: MovementPhysics,
+    /// Slide from the last hit, on top of normal movement
+    pub knockback: Knockback,
     pub direction: Direction,
     pub is_attacking: bool,
     pub is_taking_damage: bool,  // Track if damage animation is playing
@@ -26,8 +47,14 @@ pub struct Player<'a> {
     // New comprehensive stats system
     pub stats: Stats,
 
-    // Active stat modifiers (buffs/debuffs from entities, items, etc.)
-    pub active_modifiers: Vec<ModifierEffect>,
+    // Timed buffs, permanent effects and pyramid auras
+    pub active_effects: ActiveEffects,
+
+    // Status effects (poison, slow, stun, ...); `modifiers()` adds their stat changes
+    pub status_effects: StatusEffects,
+
+    // Level, XP and stat points (allocated points are already in the base stats)
+    pub progression: Progression,
 
     // Player state (Alive/Dead)
     pub state: PlayerState,
@@ -40,6 +67,13 @@ pub struct Player<'a> {
     // Attack cooldown system
     last_attack_time: Instant,
 
+    // Combo chain position (recovery and window are measured from last_attack_time)
+    pub combo: ComboTracker,
+
+    // Dodge roll: seconds left in the roll (0 = not rolling) and its direction
+    dodge_timer: f32,
+    dodge_direction: (f32, f32),
+
     // Environmental collision box configuration (for walls, static objects)
     // This is separate from the damage hitbox to allow tight movement control
     // while maintaining fair combat. All values are in unscaled sprite pixels.
@@ -64,23 +98,30 @@ impl<'a> Player<'a> {
         stats.movement_speed = speed as f32;
 
         Player {
-            x,
-            y,
+            x: x as f32,
+            y: y as f32,
             width,
             height,
-            velocity_x: 0,
-            velocity_y: 0,
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+            movement: MovementPhysics::player(),
+            knockback: Knockback::default(),
             direction: Direction::South,
             is_attacking: false,
             is_taking_damage: false,  // Not taking damage initially
             animation_controller: AnimationController::new(),
             stats,
-            active_modifiers: Vec::new(),
+            active_effects: ActiveEffects::default(),
+            status_effects: StatusEffects::default(),
+            progression: Progression::new(),
             state: PlayerState::Alive,
             is_invulnerable: false,
             invulnerability_timer: Instant::now(),
             invulnerability_duration: 1.0, // 1 second of invulnerability after taking damage
             last_attack_time: Instant::now(),
+            combo: ComboTracker::default(),
+            dodge_timer: 0.0,
+            dodge_direction: (0.0, 0.0),
 
             // Environmental collision box (tight, at feet)
             // Centered horizontally, at base of sprite
@@ -103,46 +144,45 @@ impl<'a> Player<'a> {
         self.animation_controller = controller;
     }
 
-    pub fn update(&mut self, keyboard_state: &sdl2::keyboard::KeyboardState) {
-        self.velocity_x = 0;
-        self.velocity_y = 0;
-
-        // Get effective movement speed from stats with modifiers applied
-        let effective_speed = self.stats.effective_stat(StatType::MovementSpeed, &self.active_modifiers) as i32;
-
-        // Only allow movement if not attacking or taking damage
-        if !self.is_attacking && !self.is_taking_damage {
+    /// Reads movement input and moves the player
+    ///
+    /// # Arguments
+    /// * `keyboard_state` - Current keyboard state (WASD movement)
+    /// * `delta_time` - Time elapsed since last frame (in seconds)
+    pub fn update(&mut self, keyboard_state: &sdl2::keyboard::KeyboardState, delta_time: f32) {
+        // Input direction on each axis (-1, 0 or 1)
+        let mut input_x = 0;
+        let mut input_y = 0;
+
+        // Only allow movement if not attacking, taking damage, rolling or stunned
+        if !self.is_attacking && !self.is_taking_damage && !self.is_dodging() && !self.status_effects.is_stunned() {
             // Vertical movement
             if keyboard_state.is_scancode_pressed(Scancode::W) {
-                self.velocity_y -= effective_speed;
+                input_y -= 1;
             }
             if keyboard_state.is_scancode_pressed(Scancode::S) {
-                self.velocity_y += effective_speed;
+                input_y += 1;
             }
 
             // Horizontal movement
             if keyboard_state.is_scancode_pressed(Scancode::A) {
-                self.velocity_x -= effective_speed;
+                input_x -= 1;
             }
             if keyboard_state.is_scancode_pressed(Scancode::D) {
-                self.velocity_x += effective_speed;
+                input_x += 1;
             }
         }
 
-        // Normalize diagonal movement to maintain consistent speed
-        if self.velocity_x != 0 && self.velocity_y != 0 {
-            // For diagonal movement, scale by 1/√2 ≈ 0.707 to maintain same net speed
-            let diagonal_factor = 0.707; // 1.0 / sqrt(2.0)
-            self.velocity_x = (self.velocity_x as f32 * diagonal_factor).round() as i32;
-            self.velocity_y = (self.velocity_y as f32 * diagonal_factor).round() as i32;
-        }
+        self.apply_movement_input(input_x, input_y, delta_time);
+        self.dodge_timer = (self.dodge_timer - delta_time).max(0.0);
+        self.active_effects.update(delta_time);
 
-        self.x += self.velocity_x;
-        self.y += self.velocity_y;
+        let stamina_regen = self.stats.effective_stat(StatType::StaminaRegen, &self.modifiers());
+        self.stats.stamina.regenerate(stamina_regen, delta_time);
 
-        // Update direction based on movement (only when moving)
-        if self.velocity_x != 0 || self.velocity_y != 0 {
-            self.direction = Direction::from_velocity(self.velocity_x, self.velocity_y);
+        // Face the way the player is pushing (not the way they are sliding)
+        if input_x != 0 || input_y != 0 {
+            self.direction = Direction::from_velocity(input_x, input_y);
         }
 
         // Check if attack animation is finished
@@ -172,16 +212,39 @@ impl<'a> Player<'a> {
             "damage".to_string()
         } else if self.is_attacking {
             "attack".to_string()
+        } else if self.is_dodging() {
+            "running".to_string()
         } else {
-            // Only consider horizontal movement for running animation
-            // Vertical movement during attacks shouldn't trigger running animation
-            determine_animation_state(self.velocity_x, self.velocity_y, effective_speed)
+            // Run while movement keys are held; sliding to a stop plays idle
+            determine_animation_state(input_x, input_y, 1)
         };
 
         self.animation_controller.set_state(new_state);
         self.animation_controller.update();
     }
 
+    /// Accelerates toward the input direction (or slows under friction) and moves
+    ///
+    /// Diagonal input is normalized exactly, so every direction has the same top speed.
+    /// A dodge roll overrides input and moves at a fixed speed; once it ends,
+    /// friction slows the player back down.
+    fn apply_movement_input(&mut self, input_x: i32, input_y: i32, delta_time: f32) {
+        if self.is_dodging() {
+            self.velocity_x = self.dodge_direction.0 * DODGE_SPEED;
+            self.velocity_y = self.dodge_direction.1 * DODGE_SPEED;
+        } else {
+            let max_speed = self.stats.effective_stat(StatType::MovementSpeed, &self.modifiers())
+                * physics::SPEED_UNITS_PER_SECOND;
+            let direction = physics::normalize(input_x as f32, input_y as f32);
+            (self.velocity_x, self.velocity_y) =
+                self.movement.step((self.velocity_x, self.velocity_y), direction, max_speed, delta_time);
+        }
+
+        let (knockback_x, knockback_y) = self.knockback.step(delta_time);
+        self.x += self.velocity_x * delta_time + knockback_x;
+        self.y += self.velocity_y * delta_time + knockback_y;
+    }
+
     pub fn render(&self, canvas: &mut Canvas<Window>) -> Result<(), String> {
         const SPRITE_SCALE: u32 = 2;
         let scaled_width = self.width * SPRITE_SCALE;
@@ -190,8 +253,9 @@ impl<'a> Player<'a> {
         // Calculate render position from anchor point (bottom-center)
         // The anchor is where the player "stands" in the world
         // We render the sprite upward and centered from this point
-        let render_x = self.x - (scaled_width / 2) as i32;
-        let render_y = self.y - scaled_height as i32;
+        let (anchor_x, anchor_y) = self.position();
+        let render_x = anchor_x - (scaled_width / 2) as i32;
+        let render_y = anchor_y - scaled_height as i32;
 
         let dest_rect = Rect::new(render_x, render_y, scaled_width, scaled_height);
 
@@ -208,72 +272,133 @@ impl<'a> Player<'a> {
     }
 
 
+    /// Anchor position rounded to whole pixels (for drawing and tile lookups)
     pub fn position(&self) -> (i32, i32) {
-        (self.x, self.y)
+        (self.x.round() as i32, self.y.round() as i32)
     }
 
-    pub fn velocity(&self) -> (i32, i32) {
+    pub fn velocity(&self) -> (f32, f32) {
         (self.velocity_x, self.velocity_y)
     }
 
-    /// Checks if the player can attack (not attacking, alive, and cooldown ready)
+    /// Buffs plus status effect modifiers - everything `effective_stat` should see
+    pub fn modifiers(&self) -> Vec<ModifierEffect> {
+        let mut modifiers = self.active_effects.modifiers();
+        modifiers.extend(self.status_effects.modifiers());
+        modifiers
+    }
+
+    /// Checks if the player can attack (not attacking or rolling, alive, not stunned, and
+    /// recovered from the last swing)
     pub fn can_attack(&self) -> bool {
-        if !self.state.is_alive() || self.is_attacking {
+        if !self.state.is_alive() || self.is_attacking || self.is_dodging() || self.status_effects.is_stunned() {
             return false;
         }
 
-        // Attack cooldown based on attack_speed stat with modifiers applied
-        // attack_speed is attacks per second, so cooldown = 1.0 / attack_speed
-        let attack_cooldown = 1.0 / self.stats.effective_stat(StatType::AttackSpeed, &self.active_modifiers);
-        self.last_attack_time.elapsed().as_secs_f32() >= attack_cooldown
+        self.combo.is_recovered(self.last_attack_time.elapsed().as_secs_f32())
     }
 
-    /// Attempts to start an attack
+    /// Attempts to start an attack with the held weapon
     ///
-    /// Returns Some(AttackEvent) if attack was successful, None if on cooldown
-    pub fn start_attack(&mut self) -> Option<AttackEvent> {
-        if !self.can_attack() {
+    /// Attacking again within the weapon's combo window continues its combo
+    /// chain. Returns Some(AttackEvent) if attack was successful, None if
+    /// still recovering from the last one or short of the weapon's stamina cost.
+    pub fn start_attack(&mut self, weapon: &WeaponProfile) -> Option<AttackEvent> {
+        if !self.can_attack() || !self.stats.stamina.try_spend(weapon.stamina_cost) {
             return None;
         }
 
+        let modifiers = self.modifiers();
+        let elapsed = self.last_attack_time.elapsed().as_secs_f32();
+        let step = self.combo.next_step(elapsed, weapon.combo_window, weapon.combo_len());
+
+        // Recovery scales with the attack_speed stat (attacks per second) and the weapon
+        let attack_speed = self.stats.effective_stat(StatType::AttackSpeed, &modifiers);
+        self.combo.advance(step, weapon.step_recovery(step, attack_speed));
+
         self.is_attacking = true;
         self.last_attack_time = Instant::now();
 
         // Attack originates from player's visual center, not anchor (feet)
         // This ensures attacks extend outward from the body, not the ground
         const SPRITE_SCALE: u32 = 2;
-        let player_center_y = self.y - (self.height * SPRITE_SCALE / 2) as i32;
+        let (anchor_x, anchor_y) = self.position();
+        let player_center_y = anchor_y - (self.height * SPRITE_SCALE / 2) as i32;
 
-        Some(AttackEvent::new(
-            self.stats.effective_stat(StatType::AttackDamage, &self.active_modifiers),
-            (self.x, player_center_y),  // Use visual center, not anchor
+        Some(weapon.attack_event(
+            step,
+            self.stats.effective_stat(StatType::AttackDamage, &modifiers),
+            (anchor_x, player_center_y),  // Use visual center, not anchor
             self.direction,
-            32, // Attack range in pixels (balanced for close-range combat)
-        ))
+        ).with_roll(self.stats.damage_roll(&modifiers)))
+    }
+
+    /// Starts a dodge roll in the facing direction
+    ///
+    /// Costs stamina; the roll moves fast for `DODGE_DURATION` seconds and
+    /// ignores hits for the first `DODGE_IFRAMES`. Returns false (and spends
+    /// nothing) if the player can't roll right now or is short of stamina.
+    pub fn start_dodge(&mut self) -> bool {
+        if !self.state.is_alive() || self.is_dodging() || self.status_effects.is_stunned() {
+            return false;
+        }
+        if !self.stats.stamina.try_spend(DODGE_STAMINA_COST) {
+            return false;
+        }
+
+        // Rolling cancels the rest of an attack or hurt animation
+        self.is_attacking = false;
+        self.is_taking_damage = false;
+        self.dodge_direction = self.direction.unit_vector();
+        self.dodge_timer = DODGE_DURATION;
+        true
+    }
+
+    pub fn is_dodging(&self) -> bool {
+        self.dodge_timer > 0.0
+    }
+
+    /// Whether the current roll still ignores hits
+    pub fn has_dodge_iframes(&self) -> bool {
+        self.is_dodging() && DODGE_DURATION - self.dodge_timer < DODGE_IFRAMES
     }
 
     /// Applies a push force to the player (used for collision response).
     ///
     /// This is called when the player collides with something and needs to be
-    /// pushed away to prevent overlap.
-    pub fn apply_push(&mut self, push_x: i32, push_y: i32) {
+    /// pushed away to prevent overlap. Pushes are sub-pixel, so fractional
+    /// shares of an overlap are kept rather than rounded away.
+    pub fn apply_push(&mut self, push_x: f32, push_y: f32) {
         self.x += push_x;
         self.y += push_y;
     }
 
     /// Deals damage to the player using a DamageEvent
     ///
-    /// This applies defense calculations based on damage type and returns detailed results
+    /// This applies the resistance for the damage type and returns detailed results
     pub fn take_damage(&mut self, damage_event: DamageEvent) -> DamageResult {
-        if self.is_invulnerable || !self.state.is_alive() {
+        if self.is_invulnerable || self.has_dodge_iframes() || !self.state.is_alive() {
             return DamageResult::no_damage();
         }
 
-        // Calculate final damage with defense (including modifiers)
-        let effective_defense = self.stats.effective_stat(StatType::Defense, &self.active_modifiers);
-        let final_damage = calculate_damage_with_defense(&damage_event, effective_defense);
+        // Calculate final damage with resistances (including modifiers)
+        let modifiers = self.modifiers();
+        let resistances = self.stats.resistances(&modifiers);
+        let final_damage = calculate_damage_with_resistances(&damage_event, &resistances);
 
-        let result = self.stats.health.take_damage(final_damage);
+        let mut result = self.stats.health.take_damage(final_damage);
+        result.is_crit = damage_event.is_crit;
+
+        if let Some(impulse) = damage_event.knockback {
+            let resistance = self.stats.effective_stat(StatType::KnockbackResistance, &modifiers);
+            self.knockback.apply(impulse, resistance);
+        }
+        if let Some(effect) = damage_event.status_effect {
+            self.status_effects.apply(effect);
+        }
+
+        // Getting hit breaks the combo chain
+        self.combo.reset();
 
         // Activate invulnerability after taking damage
         self.is_invulnerable = true;
@@ -289,6 +414,24 @@ impl<'a> Player<'a> {
         result
     }
 
+    /// Deals one status effect tick (poison, burn) to the player
+    ///
+    /// Unlike a hit, a tick ignores invulnerability, doesn't grant any and
+    /// doesn't interrupt movement with the damage animation.
+    pub fn take_status_damage(&mut self, damage_event: DamageEvent) -> DamageResult {
+        if !self.state.is_alive() {
+            return DamageResult::no_damage();
+        }
+
+        let resistances = self.stats.resistances(&self.modifiers());
+        let final_damage = calculate_damage_with_resistances(&damage_event, &resistances);
+        let result = self.stats.health.take_damage(final_damage);
+        if result.is_fatal {
+            self.die();
+        }
+        result
+    }
+
     /// Handles player death
     fn die(&mut self) {
         self.state = PlayerState::Dead {
@@ -302,7 +445,7 @@ impl<'a> Player<'a> {
     /// - Restores full health
     /// - Resets player state to Alive
     /// - Clears combat state (attacking, taking damage)
-    /// - Resets invulnerability
+    /// - Resets invulnerability and clears status effects
     /// - Moves player to the respawn position
     /// - Stops player movement
     ///
@@ -325,16 +468,22 @@ impl<'a> Player<'a> {
         // Clear combat state
         self.is_attacking = false;
         self.is_taking_damage = false;
+        self.combo = ComboTracker::default();
+        self.dodge_timer = 0.0;
+        self.stats.stamina.refill();
 
         // Reset invulnerability
         self.is_invulnerable = false;
         self.invulnerability_timer = Instant::now();
+        self.status_effects.clear();
+        self.active_effects.clear();
 
         // Reset position
-        self.x = x;
-        self.y = y;
-        self.velocity_x = 0;
-        self.velocity_y = 0;
+        self.x = x as f32;
+        self.y = y as f32;
+        self.velocity_x = 0.0;
+        self.velocity_y = 0.0;
+        self.knockback = Knockback::default();
 
         println!("Player respawned at ({}, {}) with full health", x, y);
     }
@@ -419,9 +568,10 @@ impl<'a> Player<'a> {
         let scaled_width = self.damage_width * SPRITE_SCALE;
         let scaled_height = self.damage_height * SPRITE_SCALE;
 
+        let (anchor_x, anchor_y) = self.position();
         Rect::new(
-            self.x + offset_x,
-            self.y + offset_y,
+            anchor_x + offset_x,
+            anchor_y + offset_y,
             scaled_width,
             scaled_height,
         )
@@ -443,9 +593,10 @@ impl<'a> Collidable for Player<'a> {
         let scaled_width = self.collision_width * SPRITE_SCALE;
         let scaled_height = self.collision_height * SPRITE_SCALE;
 
+        let (anchor_x, anchor_y) = self.position();
         Rect::new(
-            self.x + offset_x,
-            self.y + offset_y,
+            anchor_x + offset_x,
+            anchor_y + offset_y,
             scaled_width,
             scaled_height,
         )
@@ -472,7 +623,7 @@ impl DepthSortable for Player<'_> {
         // Player's position is already at the anchor point (bottom-center)
         // This is where the player "touches the ground" in the game world
         // No calculation needed - the anchor is the depth!
-        self.y
+        self.position().1
     }
 
     fn render(&self, canvas: &mut Canvas<Window>) -> Result<(), String> {
@@ -489,10 +640,10 @@ impl DepthSortable for Player<'_> {
 impl Saveable for Player<'_> {
     fn to_save_data(&self) -> Result<SaveData, SaveError> {
         #[derive(Serialize)]
-        struct PlayerData {
+        struct PlayerData<'s> {
             // Position and movement
-            x: i32,
-            y: i32,
+            x: f32,
+            y: f32,
             direction: String,
 
             // Stats (health, movement speed, attack damage, etc.)
@@ -502,10 +653,14 @@ impl Saveable for Player<'_> {
             attack_damage: f32,
             attack_speed: f32,
             defense: f32,
+            magic_resistance: f32,
             max_health: f32,
 
             // State
             is_alive: bool,
+            status_effects: &'s StatusEffects,
+            active_effects: &'s ActiveEffects,
+            progression: &'s Progression,
 
             // Note: Collision/damage hitbox values are NOT saved
             // They are configuration constants defined in code, not player state
@@ -524,8 +679,12 @@ impl Saveable for Player<'_> {
             attack_damage: self.stats.attack_damage,
             attack_speed: self.stats.attack_speed,
             defense: self.stats.defense,
+            magic_resistance: self.stats.magic_resistance,
             max_health: self.stats.max_health,
             is_alive,
+            status_effects: &self.status_effects,
+            active_effects: &self.active_effects,
+            progression: &self.progression,
         };
 
         Ok(SaveData {
@@ -537,8 +696,9 @@ impl Saveable for Player<'_> {
     fn from_save_data(data: &SaveData) -> Result<Self, SaveError> {
         #[derive(Deserialize)]
         struct PlayerData {
-            x: i32,
-            y: i32,
+            // Older saves store whole pixels, which read fine as f32
+            x: f32,
+            y: f32,
             direction: String,
             health_current: f32,
             health_max: f32,
@@ -546,8 +706,16 @@ impl Saveable for Player<'_> {
             attack_damage: f32,
             attack_speed: f32,
             defense: f32,
+            #[serde(default)]
+            magic_resistance: f32,
             max_health: f32,
             is_alive: bool,
+            #[serde(default)]
+            status_effects: StatusEffects,
+            #[serde(default)]
+            active_effects: ActiveEffects,
+            #[serde(default)]
+            progression: Progression,
             // Note: Hitbox values not saved - using code defaults
         }
 
@@ -562,12 +730,14 @@ impl Saveable for Player<'_> {
 
         // Create player with position and initial speed
         let mut player = Player::new(
-            player_data.x,
-            player_data.y,
+            0,
+            0,
             32, // width
             32, // height
             player_data.movement_speed as i32,
         );
+        player.x = player_data.x;
+        player.y = player_data.y;
 
         // Restore stats
         player.stats.health = crate::stats::Health::new(player_data.health_max);
@@ -581,7 +751,11 @@ impl Saveable for Player<'_> {
         player.stats.attack_damage = player_data.attack_damage;
         player.stats.attack_speed = player_data.attack_speed;
         player.stats.defense = player_data.defense;
+        player.stats.magic_resistance = player_data.magic_resistance;
         player.stats.max_health = player_data.max_health;
+        player.status_effects = player_data.status_effects;
+        player.active_effects = player_data.active_effects;
+        player.progression = player_data.progression;
 
         // Restore direction
         player.direction = match player_data.direction.as_str() {
@@ -610,3 +784,58 @@ impl Saveable for Player<'_> {
         Ok(player)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::DamageSource;
+
+    fn player() -> Player<'static> {
+        Player::new(100, 100, 32, 32, 120)
+    }
+
+    #[test]
+    fn test_dodge_costs_stamina() {
+        let mut player = player();
+        let full = player.stats.stamina.current();
+
+        assert!(player.start_dodge());
+        assert!(player.is_dodging());
+        assert_eq!(player.stats.stamina.current(), full - DODGE_STAMINA_COST);
+
+        // Can't roll again mid-roll (and that costs nothing)
+        assert!(!player.start_dodge());
+        assert_eq!(player.stats.stamina.current(), full - DODGE_STAMINA_COST);
+    }
+
+    #[test]
+    fn test_dodge_fails_without_enough_stamina() {
+        let mut player = player();
+        let full = player.stats.stamina.current();
+        assert!(player.stats.stamina.try_spend(full - DODGE_STAMINA_COST + 1.0));
+        let left = player.stats.stamina.current();
+
+        assert!(!player.start_dodge());
+        assert!(!player.is_dodging());
+        assert_eq!(player.stats.stamina.current(), left);
+    }
+
+    #[test]
+    fn test_dodge_iframes_cover_only_the_start_of_the_roll() {
+        let mut player = player();
+        let hit = || DamageEvent::physical(2.0, DamageSource::Enemy);
+        assert!(player.start_dodge());
+
+        assert!(player.has_dodge_iframes());
+        assert_eq!(player.take_damage(hit()).damage_dealt, 0.0);
+
+        // Past the i-frame window the roll goes on, but hits land
+        player.dodge_timer = DODGE_DURATION - DODGE_IFRAMES - 0.01;
+        assert!(player.is_dodging());
+        assert!(!player.has_dodge_iframes());
+        assert_eq!(player.take_damage(hit()).damage_dealt, 2.0);
+
+        player.dodge_timer = 0.0;
+        assert!(!player.is_dodging() && !player.has_dodge_iframes());
+    }
+}
@@ -0,0 +1,245 @@
+//! Experience, levels and stat points
+//!
+//! Enemies award XP when they die (the `xp` field of their definition). Each
+//! level needs more XP than the last; leftover XP carries into the next level,
+//! so a big reward can grant several levels at once. Every level gained gives
+//! `POINTS_PER_LEVEL` stat points, which the player spends on the character
+//! screen to raise one of the `ALLOCATABLE_STATS`.
+//!
+//! Points raise the player's base stats directly (the same values the debug
+//! menu edits), so they are saved with the rest of the stats. The allocation
+//! counts are saved alongside only to show and cap what was spent.
+//!
+//! # Example
+//!
+//! ```rust
+//! let levels_gained = player.progression.add_xp(definition.xp);
+//! if levels_gained > 0 {
+//!     // show the level-up effect
+//! }
+//!
+//! player.progression.allocate(StatType::AttackDamage, &mut player.stats);
+//! ```
+
+use crate::stats::{StatType, Stats};
+use serde::{Deserialize, Serialize};
+
+/// Stat points granted per level gained
+pub const POINTS_PER_LEVEL: u32 = 1;
+
+/// XP needed to go from level 1 to 2; each level after needs `XP_GROWTH` times more
+const BASE_XP_TO_LEVEL: f32 = 10.0;
+const XP_GROWTH: f32 = 1.5;
+
+/// Points past this many in Defense are refused (60% damage reduction)
+const MAX_DEFENSE_POINTS: u32 = 20;
+
+/// Stats the player can spend points on, in character screen order
+pub const ALLOCATABLE_STATS: [StatType; 5] = [
+    StatType::MaxHealth,
+    StatType::AttackDamage,
+    StatType::AttackSpeed,
+    StatType::MovementSpeed,
+    StatType::Defense,
+];
+
+/// How much one point raises a stat, or None if points can't be spent on it
+pub fn gain_per_point(stat: StatType) -> Option<f32> {
+    match stat {
+        StatType::MaxHealth => Some(2.0),
+        StatType::AttackDamage => Some(0.5),
+        StatType::AttackSpeed => Some(0.25),
+        StatType::MovementSpeed => Some(0.2),
+        StatType::Defense => Some(0.03),
+        _ => None,
+    }
+}
+
+/// XP needed to advance from `level` to the next one
+pub fn xp_to_next_level(level: u32) -> u32 {
+    (BASE_XP_TO_LEVEL * XP_GROWTH.powi(level.saturating_sub(1) as i32)).round() as u32
+}
+
+/// Points spent on each allocatable stat
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatAllocations {
+    pub max_health: u32,
+    pub attack_damage: u32,
+    pub attack_speed: u32,
+    pub movement_speed: u32,
+    pub defense: u32,
+}
+
+impl StatAllocations {
+    /// Points spent on `stat` (0 for stats that can't be allocated)
+    pub fn get(&self, stat: StatType) -> u32 {
+        match stat {
+            StatType::MaxHealth => self.max_health,
+            StatType::AttackDamage => self.attack_damage,
+            StatType::AttackSpeed => self.attack_speed,
+            StatType::MovementSpeed => self.movement_speed,
+            StatType::Defense => self.defense,
+            _ => 0,
+        }
+    }
+
+    fn get_mut(&mut self, stat: StatType) -> Option<&mut u32> {
+        match stat {
+            StatType::MaxHealth => Some(&mut self.max_health),
+            StatType::AttackDamage => Some(&mut self.attack_damage),
+            StatType::AttackSpeed => Some(&mut self.attack_speed),
+            StatType::MovementSpeed => Some(&mut self.movement_speed),
+            StatType::Defense => Some(&mut self.defense),
+            _ => None,
+        }
+    }
+}
+
+/// The player's level, XP toward the next level and stat points
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Progression {
+    pub level: u32,
+    /// XP earned since reaching the current level
+    pub xp: u32,
+    pub unspent_points: u32,
+    pub allocations: StatAllocations,
+}
+
+impl Progression {
+    pub fn new() -> Self {
+        Progression {
+            level: 1,
+            xp: 0,
+            unspent_points: 0,
+            allocations: StatAllocations::default(),
+        }
+    }
+
+    /// XP needed to reach the next level from the start of this one
+    pub fn xp_to_next_level(&self) -> u32 {
+        xp_to_next_level(self.level)
+    }
+
+    /// Adds XP, levelling up as many times as it covers
+    ///
+    /// Returns the number of levels gained (0 if none).
+    pub fn add_xp(&mut self, amount: u32) -> u32 {
+        self.xp += amount;
+        let mut levels_gained = 0;
+        while self.xp >= self.xp_to_next_level() {
+            self.xp -= self.xp_to_next_level();
+            self.level += 1;
+            levels_gained += 1;
+        }
+        self.unspent_points += levels_gained * POINTS_PER_LEVEL;
+        levels_gained
+    }
+
+    /// Whether another point can go into `stat` right now
+    pub fn can_allocate(&self, stat: StatType) -> bool {
+        self.unspent_points > 0
+            && gain_per_point(stat).is_some()
+            && !(stat == StatType::Defense && self.allocations.defense >= MAX_DEFENSE_POINTS)
+    }
+
+    /// Spends one point on `stat`, raising its base value in `stats`
+    ///
+    /// Raising max health also heals by the same amount. Returns false (and
+    /// changes nothing) if the point can't be spent.
+    pub fn allocate(&mut self, stat: StatType, stats: &mut Stats) -> bool {
+        if !self.can_allocate(stat) {
+            return false;
+        }
+        let (Some(gain), Some(count)) = (gain_per_point(stat), self.allocations.get_mut(stat)) else {
+            return false;
+        };
+        *count += 1;
+        self.unspent_points -= 1;
+
+        match stat {
+            StatType::MaxHealth => {
+                stats.max_health += gain;
+                stats.health.set_max(stats.max_health);
+                stats.health.heal(gain);
+            }
+            StatType::AttackDamage => stats.attack_damage += gain,
+            StatType::AttackSpeed => stats.attack_speed += gain,
+            StatType::MovementSpeed => stats.movement_speed += gain,
+            StatType::Defense => stats.defense += gain,
+            _ => {}
+        }
+        true
+    }
+}
+
+impl Default for Progression {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xp_thresholds_grow_each_level() {
+        assert_eq!(xp_to_next_level(1), 10);
+        assert_eq!(xp_to_next_level(2), 15);
+        assert_eq!(xp_to_next_level(3), 23);
+        assert!(xp_to_next_level(10) > xp_to_next_level(9));
+    }
+
+    #[test]
+    fn test_add_xp_carries_over_and_grants_points() {
+        let mut progression = Progression::new();
+        assert_eq!(progression.add_xp(8), 0);
+        assert_eq!(progression.level, 1);
+
+        // 8 + 20 = 28: level 2 at 10, level 3 at 10 + 15, 3 left over
+        assert_eq!(progression.add_xp(20), 2);
+        assert_eq!(progression.level, 3);
+        assert_eq!(progression.xp, 3);
+        assert_eq!(progression.unspent_points, 2 * POINTS_PER_LEVEL);
+    }
+
+    #[test]
+    fn test_allocate_raises_base_stats() {
+        let mut progression = Progression::new();
+        let mut stats = Stats::new();
+        stats.health.take_damage(4.0);
+
+        // No points yet
+        assert!(!progression.allocate(StatType::MaxHealth, &mut stats));
+
+        progression.unspent_points = 2;
+        assert!(progression.allocate(StatType::MaxHealth, &mut stats));
+        assert_eq!(stats.max_health, 12.0);
+        assert_eq!(stats.health.max(), 12.0);
+        assert_eq!(stats.health.current(), 8.0);
+
+        assert!(progression.allocate(StatType::AttackDamage, &mut stats));
+        assert_eq!(stats.attack_damage, 3.5);
+        assert_eq!(progression.allocations.get(StatType::MaxHealth), 1);
+        assert_eq!(progression.unspent_points, 0);
+
+        // Only the five allocatable stats take points
+        progression.unspent_points = 1;
+        assert!(!progression.allocate(StatType::CritChance, &mut stats));
+        assert_eq!(progression.unspent_points, 1);
+    }
+
+    #[test]
+    fn test_defense_points_are_capped() {
+        let mut progression = Progression::new();
+        let mut stats = Stats::new();
+        progression.unspent_points = MAX_DEFENSE_POINTS + 1;
+        for _ in 0..MAX_DEFENSE_POINTS {
+            assert!(progression.allocate(StatType::Defense, &mut stats));
+        }
+        assert!(!progression.allocate(StatType::Defense, &mut stats));
+        assert!((stats.defense - 0.6).abs() < 0.001);
+    }
+}
@@ -0,0 +1,270 @@
+//! Projectiles - anything that flies in a straight line and hits on contact
+//!
+//! Enemy spit, thrown slime balls and (later) arrows are all projectiles. A
+//! projectile belongs to the layer that fired it (`owner`) and only hits the
+//! opposing side: player projectiles hit enemies, enemy projectiles hit the
+//! player. Each hit goes through a `DamageEvent` like any other attack.
+//!
+//! A projectile dies when it:
+//! - hits a target and has no pierce left
+//! - touches a `StaticCollidable` (walls, blocks, pyramids - a Static-layer trigger)
+//! - leaves the zone or outlives its lifetime
+//!
+//! Projectiles are depth-sorted with the other world entities and rendered
+//! with an `AnimationController`. Projectiles without sprites (enemy spit)
+//! fall back to a colored square.
+//!
+//! # Example
+//!
+//! ```rust
+//! let ball = Projectile::aimed((x, y), (target_x, target_y), 220.0, 2.0, CollisionLayer::Player)
+//!     .with_pierce(1)
+//!     .with_animation(controller, 12, 12);
+//! world.projectiles.push(ball);
+//! ```
+
+use crate::animation::AnimationController;
+use crate::collision::{Collidable, CollisionLayer};
+use crate::combat::{DamageEvent, DamageSource};
+use crate::physics;
+use crate::render::DepthSortable;
+use crate::status::StatusEffect;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+
+/// Default collision size in pixels (square)
+const DEFAULT_SIZE: u32 = 8;
+
+/// Default seconds before a projectile that hit nothing disappears
+const DEFAULT_LIFETIME: f32 = 3.0;
+
+pub struct Projectile<'a> {
+    /// Center position
+    pub x: f32,
+    pub y: f32,
+    pub velocity_x: f32,
+    pub velocity_y: f32,
+    /// Collision (and render) size in pixels
+    pub width: u32,
+    pub height: u32,
+    pub damage: f32,
+    /// Layer of whoever fired it (decides what it can hit)
+    pub owner: CollisionLayer,
+    /// Extra targets it can pass through before it's spent
+    pub pierce: u32,
+    /// Knockback speed (pixels per second) along the flight path; 0 for none
+    pub knockback: f32,
+    /// Left on each target it hits (e.g. poison spit)
+    pub status_effect: Option<StatusEffect>,
+    /// Seconds before it disappears on its own
+    pub lifetime: f32,
+    pub age: f32,
+    pub is_alive: bool,
+    /// Fallback color when there's no sprite
+    color: Color,
+    animation_controller: AnimationController<'a>,
+}
+
+impl<'a> Projectile<'a> {
+    /// Creates a projectile at `origin` flying toward `target`
+    pub fn aimed(origin: (f32, f32), target: (f32, f32), speed: f32, damage: f32, owner: CollisionLayer) -> Self {
+        let (dx, dy) = (target.0 - origin.0, target.1 - origin.1);
+        let length = (dx * dx + dy * dy).sqrt().max(f32::EPSILON);
+        Projectile {
+            x: origin.0,
+            y: origin.1,
+            velocity_x: dx / length * speed,
+            velocity_y: dy / length * speed,
+            width: DEFAULT_SIZE,
+            height: DEFAULT_SIZE,
+            damage,
+            owner,
+            pierce: 0,
+            knockback: 0.0,
+            status_effect: None,
+            lifetime: DEFAULT_LIFETIME,
+            age: 0.0,
+            is_alive: true,
+            color: Color::RGB(150, 60, 190),
+            animation_controller: AnimationController::new(),
+        }
+    }
+
+    /// Lets the projectile pass through `pierce` targets before the last hit
+    pub fn with_pierce(mut self, pierce: u32) -> Self {
+        self.pierce = pierce;
+        self
+    }
+
+    /// Knocks targets back along the direction it's flying
+    pub fn with_knockback(mut self, strength: f32) -> Self {
+        self.knockback = strength;
+        self
+    }
+
+    pub fn with_status_effect(mut self, effect: StatusEffect) -> Self {
+        self.status_effect = Some(effect);
+        self
+    }
+
+    pub fn with_lifetime(mut self, lifetime: f32) -> Self {
+        self.lifetime = lifetime;
+        self
+    }
+
+    /// Fallback color used when the projectile has no animation
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Renders with a sprite animation, resizing the projectile to match
+    pub fn with_animation(mut self, animation_controller: AnimationController<'a>, width: u32, height: u32) -> Self {
+        self.animation_controller = animation_controller;
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.animation_controller.update();
+        self.x += self.velocity_x * delta_time;
+        self.y += self.velocity_y * delta_time;
+        self.age += delta_time;
+        if self.age >= self.lifetime {
+            self.is_alive = false;
+        }
+    }
+
+    /// True if this projectile hurts things on the given layer
+    pub fn can_hit(&self, layer: CollisionLayer) -> bool {
+        self.is_alive
+            && match self.owner {
+                CollisionLayer::Player => layer == CollisionLayer::Enemy,
+                CollisionLayer::Enemy => layer == CollisionLayer::Player,
+                _ => matches!(layer, CollisionLayer::Player | CollisionLayer::Enemy),
+            }
+    }
+
+    /// Records a hit on a target, using up one pierce (or the projectile)
+    ///
+    /// Returns the damage to deal. Call only for hits that actually landed, so
+    /// a target that is still invulnerable doesn't use up the pierce.
+    pub fn register_hit(&mut self) -> DamageEvent {
+        if self.pierce == 0 {
+            self.is_alive = false;
+        } else {
+            self.pierce -= 1;
+        }
+        let source = match self.owner {
+            CollisionLayer::Player => DamageSource::Player,
+            CollisionLayer::Enemy => DamageSource::Enemy,
+            _ => DamageSource::Environment,
+        };
+        let mut damage = DamageEvent::physical(self.damage, source);
+        damage.status_effect = self.status_effect;
+        if self.knockback > 0.0 {
+            let (direction_x, direction_y) = physics::normalize(self.velocity_x, self.velocity_y);
+            damage.with_knockback((direction_x * self.knockback, direction_y * self.knockback))
+        } else {
+            damage
+        }
+    }
+
+    pub fn render(&self, canvas: &mut Canvas<Window>) -> Result<(), String> {
+        let bounds = Collidable::get_bounds(self);
+        if let Some(sprite_sheet) = self.animation_controller.get_current_sprite_sheet() {
+            return sprite_sheet.render_flipped(canvas, bounds, self.velocity_x < 0.0);
+        }
+
+        canvas.set_draw_color(self.color);
+        canvas.fill_rect(bounds)?;
+        canvas.set_draw_color(Color::RGB(self.color.r / 2, self.color.g / 3, self.color.b / 2));
+        canvas.draw_rect(bounds)
+    }
+}
+
+impl Collidable for Projectile<'_> {
+    fn get_bounds(&self) -> Rect {
+        Rect::new(
+            (self.x - self.width as f32 / 2.0) as i32,
+            (self.y - self.height as f32 / 2.0) as i32,
+            self.width,
+            self.height,
+        )
+    }
+
+    fn get_collision_layer(&self) -> CollisionLayer {
+        CollisionLayer::Projectile
+    }
+}
+
+/// Projectiles sort by the bottom of their bounds, so one flying past the
+/// player's feet draws in front and one passing behind their head draws behind
+impl DepthSortable for Projectile<'_> {
+    fn get_depth_y(&self) -> i32 {
+        (self.y + self.height as f32 / 2.0) as i32
+    }
+
+    fn render(&self, canvas: &mut Canvas<Window>) -> Result<(), String> {
+        Projectile::render(self, canvas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shot(owner: CollisionLayer) -> Projectile<'static> {
+        Projectile::aimed((0.0, 0.0), (30.0, 40.0), 100.0, 1.0, owner)
+    }
+
+    #[test]
+    fn test_flies_toward_target_and_expires() {
+        let mut projectile = shot(CollisionLayer::Enemy);
+        assert!((projectile.velocity_x - 60.0).abs() < 0.001);
+        assert!((projectile.velocity_y - 80.0).abs() < 0.001);
+
+        projectile.update(0.5);
+        assert!((projectile.x - 30.0).abs() < 0.001 && (projectile.y - 40.0).abs() < 0.001);
+        assert!(projectile.is_alive);
+
+        projectile.update(DEFAULT_LIFETIME);
+        assert!(!projectile.is_alive);
+    }
+
+    #[test]
+    fn test_only_hits_the_other_side() {
+        let enemy_shot = shot(CollisionLayer::Enemy);
+        assert!(enemy_shot.can_hit(CollisionLayer::Player));
+        assert!(!enemy_shot.can_hit(CollisionLayer::Enemy));
+        assert!(!enemy_shot.can_hit(CollisionLayer::Item));
+
+        let thrown = shot(CollisionLayer::Player);
+        assert!(thrown.can_hit(CollisionLayer::Enemy));
+        assert!(!thrown.can_hit(CollisionLayer::Player));
+    }
+
+    #[test]
+    fn test_pierce_allows_extra_hits() {
+        let mut projectile = shot(CollisionLayer::Player).with_pierce(1);
+
+        let damage = projectile.register_hit();
+        assert_eq!(damage.source, DamageSource::Player);
+        assert!(projectile.is_alive);
+
+        projectile.register_hit();
+        assert!(!projectile.is_alive);
+        assert!(!projectile.can_hit(CollisionLayer::Enemy));
+    }
+
+    #[test]
+    fn test_knockback_follows_flight_path() {
+        assert_eq!(shot(CollisionLayer::Enemy).register_hit().knockback, None);
+
+        let (x, y) = shot(CollisionLayer::Player).with_knockback(50.0).register_hit().knockback.unwrap();
+        assert!((x - 30.0).abs() < 0.001 && (y - 40.0).abs() < 0.001);
+    }
+}
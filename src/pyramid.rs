@@ -0,0 +1,434 @@
+//! Pyramid definitions and linked pyramid groups
+//!
+//! Every pyramid type (`EntityType`) has a definition in
+//! `assets/pyramids/<type>.json` with the pyramid's tuning and the buff it
+//! gives while awake:
+//!
+//! ```json
+//! {
+//!   "entity_type": "Crit",
+//!   "name": "Pyramid of Precision",
+//!   "hits_to_awaken": 9,
+//!   "awake_duration": 25.0,
+//!   "reverse_speed": 1.5,
+//!   "modifiers": [{ "stat_type": "CritChance", "modifier": { "Flat": 0.1 } }]
+//! }
+//! ```
+//!
+//! - `hits_to_awaken`: hits that fully wake it (default 7)
+//! - `awake_duration`: seconds it stays awake after the last hit (default 30)
+//! - `reverse_speed`: hits of progress lost per second once it is left alone,
+//!   and while it winds back down after being awake (default 1)
+//!
+//! Modifiers without a `source` are named after the pyramid.
+//!
+//! # Linked groups
+//!
+//! A zone (or map file) can link pyramids into groups. Each linked pyramid
+//! has an `order` from 1 up; waking all of a group's pyramids in that order
+//! earns the group's reward (XP and/or a timed buff) once. Waking one out of
+//! order starts the sequence over. Group progress is saved with the zone.
+//!
+//! ```json
+//! "pyramid_groups": [{
+//!   "id": "meadow_warriors", "name": "Warrior's Blessing",
+//!   "reward": { "xp": 60, "buff": { "duration": 120.0, "modifiers": [...] } }
+//! }],
+//! "pyramids": [{ "x": 160, "y": 120, "entity_type": "Attack",
+//!                "link": { "group": "meadow_warriors", "order": 1 } }]
+//! ```
+
+use crate::buffs::ActiveEffect;
+use crate::stats::ModifierEffect;
+use crate::the_entity::EntityType;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Directory the pyramid definitions are loaded from
+pub const PYRAMID_DIRECTORY: &str = "assets/pyramids";
+
+/// Tuning and buff for one pyramid type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PyramidDefinition {
+    pub entity_type: EntityType,
+    /// Shown as the source of its buff (e.g. "Pyramid of Attack")
+    pub name: String,
+    #[serde(default = "default_hits_to_awaken")]
+    pub hits_to_awaken: u32,
+    /// Seconds it stays awake after the last hit
+    #[serde(default = "default_awake_duration")]
+    pub awake_duration: f32,
+    /// Hits of progress lost per second when left alone
+    #[serde(default = "default_reverse_speed")]
+    pub reverse_speed: f32,
+    /// The buff while awake (Regeneration heals instead and needs none)
+    #[serde(default)]
+    pub modifiers: Vec<ModifierEffect>,
+}
+
+fn default_hits_to_awaken() -> u32 {
+    7
+}
+
+fn default_awake_duration() -> f32 {
+    30.0
+}
+
+fn default_reverse_speed() -> f32 {
+    1.0
+}
+
+impl PyramidDefinition {
+    /// Definition with the original tuning and no buff
+    pub fn new(entity_type: EntityType, name: &str) -> Self {
+        PyramidDefinition {
+            entity_type,
+            name: name.to_string(),
+            hits_to_awaken: default_hits_to_awaken(),
+            awake_duration: default_awake_duration(),
+            reverse_speed: default_reverse_speed(),
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Checks the values a JSON file can get wrong
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() {
+            return Err(format!("{:?} pyramid has no name", self.entity_type));
+        }
+        if self.hits_to_awaken == 0 {
+            return Err(format!("'{}': hits_to_awaken must be at least 1", self.name));
+        }
+        if self.awake_duration <= 0.0 || self.reverse_speed <= 0.0 {
+            return Err(format!("'{}': awake_duration and reverse_speed must be positive", self.name));
+        }
+        Ok(())
+    }
+
+    /// The buff an awake pyramid gives, keyed so each pyramid is its own aura
+    pub fn aura(&self, key: impl Into<String>) -> ActiveEffect {
+        ActiveEffect::aura(key, named_modifiers(&self.modifiers, &self.name))
+    }
+}
+
+/// Copies of `modifiers`, with `name` as the source of any that lack one
+fn named_modifiers(modifiers: &[ModifierEffect], name: &str) -> Vec<ModifierEffect> {
+    modifiers.iter()
+        .map(|modifier| {
+            let mut modifier = modifier.clone();
+            if modifier.source.is_empty() {
+                modifier.source = name.to_string();
+            }
+            modifier
+        })
+        .collect()
+}
+
+/// Every pyramid type's definition (exactly one per `EntityType`)
+pub struct PyramidRegistry {
+    /// In `EntityType::ALL` order
+    definitions: Vec<PyramidDefinition>,
+}
+
+impl PyramidRegistry {
+    /// Loads every `*.json` definition in a directory
+    ///
+    /// Fails on the first invalid file (naming it) or if a type has no definition.
+    pub fn load_from_directory(directory: &str) -> Result<Self, String> {
+        let mut paths: Vec<_> = fs::read_dir(directory)
+            .map_err(|e| format!("Failed to read pyramid directory '{}': {}", directory, e))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        let mut definitions = Vec::new();
+        for path in paths {
+            definitions.push(load_file(&path).map_err(|e| format!("{}: {}", path.display(), e))?);
+        }
+        Self::from_definitions(definitions)
+    }
+
+    /// Builds a registry, checking each definition and that every type has exactly one
+    pub fn from_definitions(definitions: Vec<PyramidDefinition>) -> Result<Self, String> {
+        for definition in &definitions {
+            definition.validate()?;
+        }
+
+        let mut ordered = Vec::new();
+        for entity_type in EntityType::ALL {
+            let mut matching = definitions.iter().filter(|definition| definition.entity_type == entity_type);
+            let definition = matching.next()
+                .ok_or_else(|| format!("No pyramid definition for {:?}", entity_type))?;
+            if matching.next().is_some() {
+                return Err(format!("More than one pyramid definition for {:?}", entity_type));
+            }
+            ordered.push(definition.clone());
+        }
+        Ok(PyramidRegistry { definitions: ordered })
+    }
+
+    pub fn get(&self, entity_type: EntityType) -> &PyramidDefinition {
+        &self.definitions[entity_type.index()]
+    }
+
+    /// Number of loaded definitions
+    pub fn len(&self) -> usize {
+        self.definitions.len()
+    }
+}
+
+fn load_file(path: &Path) -> Result<PyramidDefinition, String> {
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| e.to_string())
+}
+
+/// A pyramid's place in a linked group
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PyramidLink {
+    pub group: String,
+    /// Position in the waking order, from 1
+    pub order: u32,
+}
+
+/// What waking a group in order earns
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GroupReward {
+    #[serde(default)]
+    pub xp: u32,
+    #[serde(default)]
+    pub buff: Option<RewardBuff>,
+}
+
+/// A timed buff named after the group
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RewardBuff {
+    pub duration: f32,
+    pub modifiers: Vec<ModifierEffect>,
+}
+
+/// A linked group as placed in a zone or map file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PyramidGroupDefinition {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub reward: GroupReward,
+}
+
+impl PyramidGroupDefinition {
+    /// The reward buff as an effect keyed by the group (None if the reward has no buff)
+    pub fn reward_effect(&self) -> Option<ActiveEffect> {
+        let buff = self.reward.buff.as_ref()?;
+        let key = format!("pyramid_group_{}", self.id);
+        Some(ActiveEffect::timed(key, named_modifiers(&buff.modifiers, &self.name), buff.duration))
+    }
+}
+
+/// Checks that group IDs are unique and each group's links run 1, 2, ... with no gaps
+///
+/// Every group needs at least one linked pyramid, and every link a group.
+pub fn validate_groups<'l>(
+    groups: &[PyramidGroupDefinition],
+    mut links: impl Iterator<Item = &'l PyramidLink> + Clone,
+) -> Result<(), String> {
+    for (index, group) in groups.iter().enumerate() {
+        if group.id.is_empty() {
+            return Err(format!("pyramid group {} has no id", index));
+        }
+        if groups[..index].iter().any(|other| other.id == group.id) {
+            return Err(format!("duplicate pyramid group id '{}'", group.id));
+        }
+        if group.reward.buff.as_ref().is_some_and(|buff| buff.duration <= 0.0) {
+            return Err(format!("pyramid group '{}': reward buff duration must be positive", group.id));
+        }
+
+        let mut orders: Vec<u32> = links.clone().filter(|link| link.group == group.id).map(|link| link.order).collect();
+        orders.sort_unstable();
+        if orders.is_empty() || orders.iter().zip(1..).any(|(order, expected)| *order != expected) {
+            return Err(format!("pyramid group '{}': linked pyramids must be ordered 1 to {}", group.id, orders.len().max(1)));
+        }
+    }
+    if let Some(link) = links.find(|link| !groups.iter().any(|group| group.id == link.group)) {
+        return Err(format!("pyramid linked to unknown group '{}'", link.group));
+    }
+    Ok(())
+}
+
+/// How far a group's in-order waking has got
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct GroupProgress {
+    /// Pyramids woken in order so far in the current attempt
+    pub awakened_in_order: u32,
+    /// The reward has been earned (the group does nothing more)
+    pub completed: bool,
+}
+
+/// What one pyramid waking did to its group
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GroupStep {
+    /// The next pyramid in the order woke (how many are in order now)
+    InOrder(u32),
+    /// The last pyramid woke in order: the reward is earned
+    Completed,
+    /// One woke out of order and the sequence starts over
+    Broken,
+}
+
+/// A linked group and its progress (saved with the zone layout)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PyramidGroup {
+    pub definition: PyramidGroupDefinition,
+    #[serde(default)]
+    pub progress: GroupProgress,
+}
+
+impl PyramidGroup {
+    pub fn new(definition: PyramidGroupDefinition) -> Self {
+        PyramidGroup {
+            definition,
+            progress: GroupProgress::default(),
+        }
+    }
+
+    /// Records that the pyramid linked at `order` woke, out of `size` linked pyramids
+    ///
+    /// A pyramid waking out of order breaks the sequence, unless it is the
+    /// first in the order, which starts a new one. Returns None once the
+    /// group is completed.
+    pub fn record_awakening(&mut self, order: u32, size: u32) -> Option<GroupStep> {
+        let progress = &mut self.progress;
+        if progress.completed {
+            return None;
+        }
+
+        if order == progress.awakened_in_order + 1 {
+            progress.awakened_in_order = order;
+        } else if order == 1 {
+            progress.awakened_in_order = 1;
+        } else {
+            progress.awakened_in_order = 0;
+            return Some(GroupStep::Broken);
+        }
+
+        if progress.awakened_in_order >= size {
+            progress.completed = true;
+            Some(GroupStep::Completed)
+        } else {
+            Some(GroupStep::InOrder(progress.awakened_in_order))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::{StatModifier, StatType, Stats};
+
+    fn link(group: &str, order: u32) -> PyramidLink {
+        PyramidLink { group: group.to_string(), order }
+    }
+
+    fn group(id: &str) -> PyramidGroupDefinition {
+        PyramidGroupDefinition { id: id.to_string(), name: "Circle".to_string(), reward: GroupReward::default() }
+    }
+
+    #[test]
+    fn test_definition_defaults_and_modifier_names() {
+        let json = r#"{
+            "entity_type": "Crit",
+            "name": "Pyramid of Precision",
+            "modifiers": [{ "stat_type": "CritChance", "modifier": { "Flat": 0.1 } }]
+        }"#;
+        let definition: PyramidDefinition = serde_json::from_str(json).unwrap();
+        assert_eq!((definition.hits_to_awaken, definition.awake_duration, definition.reverse_speed), (7, 30.0, 1.0));
+        assert!(definition.validate().is_ok());
+
+        let aura = definition.aura("pyramid_3");
+        assert!(aura.aura);
+        assert_eq!(aura.name(), "Pyramid of Precision");
+        assert!((Stats::new().effective_stat(StatType::CritChance, &aura.modifiers) - 0.15).abs() < 0.001);
+
+        let mut broken = definition.clone();
+        broken.hits_to_awaken = 0;
+        assert!(broken.validate().is_err());
+    }
+
+    #[test]
+    fn test_registry_needs_one_definition_per_type() {
+        let all = || EntityType::ALL.iter().map(|entity_type| PyramidDefinition::new(*entity_type, "Pyramid")).collect::<Vec<_>>();
+        let registry = PyramidRegistry::from_definitions(all()).unwrap();
+        assert_eq!(registry.get(EntityType::XpGain).entity_type, EntityType::XpGain);
+
+        let mut missing = all();
+        missing.pop();
+        assert!(PyramidRegistry::from_definitions(missing).is_err());
+
+        let mut doubled = all();
+        doubled.push(PyramidDefinition::new(EntityType::Speed, "Another"));
+        assert!(PyramidRegistry::from_definitions(doubled).is_err());
+    }
+
+    #[test]
+    fn test_registry_loads_the_shipped_definitions() {
+        let registry = PyramidRegistry::load_from_directory(PYRAMID_DIRECTORY).unwrap();
+        assert_eq!(registry.len(), EntityType::ALL.len());
+        assert!(registry.get(EntityType::Regeneration).modifiers.is_empty());
+        for entity_type in EntityType::ALL.into_iter().filter(|entity_type| *entity_type != EntityType::Regeneration) {
+            assert!(!registry.get(entity_type).modifiers.is_empty(), "{:?} pyramid gives no buff", entity_type);
+        }
+    }
+
+    #[test]
+    fn test_groups_reward_waking_in_order_once() {
+        let mut circle = PyramidGroup::new(group("circle"));
+        assert_eq!(circle.record_awakening(1, 3), Some(GroupStep::InOrder(1)));
+        assert_eq!(circle.record_awakening(2, 3), Some(GroupStep::InOrder(2)));
+
+        // Out of order starts over; the first pyramid starts a fresh attempt
+        assert_eq!(circle.record_awakening(2, 3), Some(GroupStep::Broken));
+        assert_eq!(circle.progress.awakened_in_order, 0);
+        assert_eq!(circle.record_awakening(3, 3), Some(GroupStep::Broken));
+        assert_eq!(circle.record_awakening(1, 3), Some(GroupStep::InOrder(1)));
+        assert_eq!(circle.record_awakening(1, 3), Some(GroupStep::InOrder(1)));
+
+        assert_eq!(circle.record_awakening(2, 3), Some(GroupStep::InOrder(2)));
+        assert_eq!(circle.record_awakening(3, 3), Some(GroupStep::Completed));
+        assert_eq!(circle.record_awakening(1, 3), None);
+
+        // Progress survives a save
+        let json = serde_json::to_string(&circle).unwrap();
+        let restored: PyramidGroup = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, circle);
+    }
+
+    #[test]
+    fn test_validate_groups() {
+        let groups = [group("a"), group("b")];
+        let links = [link("a", 2), link("a", 1), link("b", 1)];
+        assert!(validate_groups(&groups, links.iter()).is_ok());
+
+        let gap = [link("a", 1), link("a", 3), link("b", 1)];
+        assert!(validate_groups(&groups, gap.iter()).is_err());
+
+        let unknown = [link("a", 1), link("b", 1), link("c", 1)];
+        assert!(validate_groups(&groups, unknown.iter()).unwrap_err().contains("unknown group 'c'"));
+
+        let empty = [link("a", 1)];
+        assert!(validate_groups(&groups, empty.iter()).is_err());
+    }
+
+    #[test]
+    fn test_reward_buff_is_named_after_the_group() {
+        let mut circle = group("circle");
+        assert!(circle.reward_effect().is_none());
+
+        circle.reward.buff = Some(RewardBuff {
+            duration: 60.0,
+            modifiers: vec![ModifierEffect::new(StatType::XpGain, 0.0, StatModifier::Percentage(0.5))],
+        });
+        let effect = circle.reward_effect().unwrap();
+        assert_eq!((effect.key.as_str(), effect.name(), effect.remaining), ("pyramid_group_circle", "Circle", Some(60.0)));
+    }
+}
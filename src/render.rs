@@ -15,17 +15,18 @@
 /// render_with_depth_sorting(
 ///     &mut canvas,
 ///     &player,
-///     &slimes,
+///     &enemies,
 ///     &static_objects,
 /// )?;
 /// ```
 ///
 /// See docs/systems/depth-sorting-render-system.md for detailed design documentation.
 use crate::player::Player;
-use crate::slime::Slime;
+use crate::enemy::Enemy;
 use crate::collision::StaticObject;
 use crate::the_entity::TheEntity;
 use crate::dropped_item::DroppedItem;
+use crate::projectile::Projectile;
 use sdl2::render::Canvas;
 use sdl2::video::Window;
 
@@ -69,10 +70,11 @@ pub trait DepthSortable {
 /// The compiler optimizes away the enum wrapper during compilation.
 pub enum Renderable<'a> {
     Player(&'a Player<'a>),
-    Slime(&'a Slime<'a>),
+    Enemy(&'a Enemy<'a>),
     StaticObject(&'a StaticObject),
     TheEntity(&'a TheEntity<'a>),
     DroppedItem(&'a DroppedItem<'a>),
+    Projectile(&'a Projectile<'a>),
 }
 
 impl<'a> Renderable<'a> {
@@ -82,7 +84,7 @@ impl<'a> Renderable<'a> {
     // fn get_depth_y(&self) -> i32 {
     //     match self {
     //         Renderable::Player(p) => p.get_depth_y(),
-    //         Renderable::Slime(s) => s.get_depth_y(),
+    //         Renderable::Enemy(e) => e.get_depth_y(),
     //         Renderable::StaticObject(obj) => obj.get_depth_y(),
     //         Renderable::TheEntity(e) => e.get_depth_y(),
     //     }
@@ -93,10 +95,11 @@ impl<'a> Renderable<'a> {
     fn render(&self, canvas: &mut Canvas<Window>) -> Result<(), String> {
         match self {
             Renderable::Player(p) => p.render(canvas),
-            Renderable::Slime(s) => s.render(canvas),
+            Renderable::Enemy(e) => e.render(canvas),
             Renderable::StaticObject(obj) => obj.render(canvas),
             Renderable::TheEntity(e) => e.render(canvas),
             Renderable::DroppedItem(item) => item.render(canvas),
+            Renderable::Projectile(projectile) => projectile.render(canvas),
         }
     }
 }
@@ -122,7 +125,7 @@ impl<'a> Renderable<'a> {
 ///
 /// - `canvas`: SDL2 canvas to render to
 /// - `player`: The player entity
-/// - `slimes`: Slice of slime enemies
+/// - `enemies`: Slice of enemies
 /// - `static_objects`: Slice of static world objects
 ///
 /// # Example
@@ -131,30 +134,31 @@ impl<'a> Renderable<'a> {
 /// render_with_depth_sorting(
 ///     &mut canvas,
 ///     &player,
-///     &slimes,
+///     &enemies,
 ///     &static_objects,
 /// )?;
 /// ```
 pub fn render_with_depth_sorting(
     canvas: &mut Canvas<Window>,
     player: &Player,
-    slimes: &[Slime],
+    enemies: &[Enemy],
     static_objects: &[StaticObject],
     entities: &[TheEntity],
     dropped_items: &[DroppedItem],
+    projectiles: &[Projectile],
 ) -> Result<(), String> {
     // Collect all renderables with their depth
     // Rust Learning: Vec::with_capacity() pre-allocates to avoid reallocation
     let mut renderables: Vec<(i32, Renderable)> = Vec::with_capacity(
-        1 + slimes.len() + static_objects.len() + entities.len() + dropped_items.len()
+        1 + enemies.len() + static_objects.len() + entities.len() + dropped_items.len() + projectiles.len()
     );
 
     // Add player
     renderables.push((player.get_depth_y(), Renderable::Player(player)));
 
-    // Add slimes
-    for slime in slimes {
-        renderables.push((slime.get_depth_y(), Renderable::Slime(slime)));
+    // Add enemies
+    for enemy in enemies {
+        renderables.push((enemy.get_depth_y(), Renderable::Enemy(enemy)));
     }
 
     // Add static objects
@@ -172,6 +176,11 @@ pub fn render_with_depth_sorting(
         renderables.push((item.get_depth_y(), Renderable::DroppedItem(item)));
     }
 
+    // Add projectiles
+    for projectile in projectiles {
+        renderables.push((projectile.get_depth_y(), Renderable::Projectile(projectile)));
+    }
+
     // Sort by Y-coordinate (painter's algorithm)
     // Entities with smaller Y render first (farther back in scene)
     // Rust Learning: sort_by_key() is a stable sort (maintains order of equal elements)
@@ -0,0 +1,127 @@
+//! Small deterministic random number generator
+//!
+//! The game doesn't pull in an external RNG crate. `SimpleRng` is a xorshift64*
+//! generator: fast, seedable, and more than good enough for loot rolls and
+//! gameplay variance.
+//!
+//! # Why seeded?
+//!
+//! Passing an explicit seed makes every roll reproducible, which keeps unit tests
+//! deterministic. The game itself seeds from the system clock at startup.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Seedable xorshift64* random number generator
+#[derive(Debug, Clone)]
+pub struct SimpleRng {
+    state: u64,
+}
+
+impl SimpleRng {
+    /// Creates a generator from a fixed seed
+    ///
+    /// A seed of 0 would lock xorshift at 0 forever, so it is replaced with a constant.
+    pub fn new(seed: u64) -> Self {
+        SimpleRng {
+            state: if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed },
+        }
+    }
+
+    /// Creates a generator seeded from the current system time
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        Self::new(nanos)
+    }
+
+    /// Returns the next raw 64-bit value
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a float in the range [0.0, 1.0)
+    pub fn next_f32(&mut self) -> f32 {
+        // Use the top 24 bits so every value is exactly representable as f32
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Returns an integer in the inclusive range [min, max]
+    pub fn range_u32(&mut self, min: u32, max: u32) -> u32 {
+        if max <= min {
+            return min;
+        }
+        let span = (max - min) as u64 + 1;
+        min + (self.next_u64() % span) as u32
+    }
+
+    /// Returns true with the given probability (0.0 = never, 1.0 = always)
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+}
+
+impl Default for SimpleRng {
+    fn default() -> Self {
+        Self::from_time()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = SimpleRng::new(42);
+        let mut b = SimpleRng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_zero_seed_does_not_stall() {
+        let mut rng = SimpleRng::new(0);
+        assert_ne!(rng.next_u64(), 0);
+    }
+
+    #[test]
+    fn test_next_f32_in_unit_range() {
+        let mut rng = SimpleRng::new(7);
+        for _ in 0..1000 {
+            let value = rng.next_f32();
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_range_is_inclusive() {
+        let mut rng = SimpleRng::new(3);
+        let mut seen_min = false;
+        let mut seen_max = false;
+        for _ in 0..1000 {
+            let value = rng.range_u32(1, 3);
+            assert!((1..=3).contains(&value));
+            seen_min |= value == 1;
+            seen_max |= value == 3;
+        }
+        assert!(seen_min && seen_max);
+        assert_eq!(rng.range_u32(5, 5), 5);
+    }
+
+    #[test]
+    fn test_chance_extremes() {
+        let mut rng = SimpleRng::new(11);
+        for _ in 0..100 {
+            assert!(rng.chance(1.0));
+            assert!(!rng.chance(0.0));
+        }
+    }
+}
@@ -24,7 +24,9 @@
 //!     timestamp: SystemTime::now(),
 //!     metadata: SaveMetadata { /* ... */ },
 //!     world_state: WorldSaveData { /* ... */ },
-//!     entities: vec![/* player, slimes, etc. */],
+//!     entities: vec![/* player, inventory, clock */],
+//!     active_zone: "meadow".to_string(),
+//!     zones: vec![/* per-zone tiles, slimes, items, etc. */],
 //! };
 //! save_manager.save_game(&save_file)?;
 //!
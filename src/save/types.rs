@@ -3,6 +3,10 @@
 //! This module defines all the data structures used for saving and loading game state.
 //! It uses Serde for serialization/deserialization to JSON format.
 
+use crate::collision::StaticObject;
+use crate::enemy::Spawner;
+use crate::map::{MapObject, SpawnPoint};
+use crate::pyramid::PyramidGroup;
 use serde::{Deserialize, Serialize};
 use std::time::SystemTime;
 
@@ -14,6 +18,12 @@ pub struct SaveFile {
     pub metadata: SaveMetadata,
     pub world_state: WorldSaveData,
     pub entities: Vec<EntitySaveData>,
+    /// ID of the zone the player is in (empty in saves from before zones existed)
+    #[serde(default)]
+    pub active_zone: String,
+    /// Every zone's tiles and entities (empty in saves from before zones existed)
+    #[serde(default)]
+    pub zones: Vec<ZoneSaveData>,
 }
 
 /// Metadata about the save
@@ -41,11 +51,59 @@ pub struct WorldSaveData {
     pub tiles: Vec<Vec<String>>,  // Serialized TileId as strings
 }
 
+/// Save data for a single zone (its own tile grid and entities)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ZoneSaveData {
+    pub zone_id: String,
+    pub world_state: WorldSaveData,
+    pub entities: Vec<EntitySaveData>,
+    /// Map-editor layout (None in saves from before the map editor: the zone
+    /// keeps the layout from its map file or built-in defaults)
+    #[serde(default)]
+    pub layout: Option<ZoneLayoutSaveData>,
+}
+
+/// The parts of a zone's layout that can change during play besides tiles
+/// (map editor edits including the player start, spawner timers and wave progress, linked pyramid group progress)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ZoneLayoutSaveData {
+    pub static_objects: Vec<MapObject>,
+    pub spawn_points: Vec<SpawnPoint>,
+    /// None in saves from before the player start was saved (the zone keeps its default)
+    #[serde(default)]
+    pub player_spawn: Option<SpawnPoint>,
+    /// None in saves from before spawners existed (the zone keeps its defaults)
+    #[serde(default)]
+    pub spawners: Option<Vec<Spawner>>,
+    /// None in saves from before linked pyramid groups (the zone keeps its defaults)
+    #[serde(default)]
+    pub pyramid_groups: Option<Vec<PyramidGroup>>,
+}
+
+impl ZoneLayoutSaveData {
+    /// Captures a zone's static objects, spawn points, player start, spawners and pyramid groups
+    pub fn capture(
+        static_objects: &[StaticObject],
+        spawn_points: &[SpawnPoint],
+        player_spawn: Option<SpawnPoint>,
+        spawners: &[Spawner],
+        pyramid_groups: &[PyramidGroup],
+    ) -> Self {
+        ZoneLayoutSaveData {
+            static_objects: static_objects.iter().map(MapObject::from_static_object).collect(),
+            spawn_points: spawn_points.to_vec(),
+            player_spawn,
+            spawners: Some(spawners.to_vec()),
+            pyramid_groups: Some(pyramid_groups.to_vec()),
+        }
+    }
+}
+
 /// Entity save data (polymorphic through entity_type)
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EntitySaveData {
     pub entity_id: u64,
-    pub entity_type: String,  // "player", "slime", "goblin", etc.
+    pub entity_type: String,  // "player", "enemy", "the_entity", etc.
     pub position: (i32, i32),
     pub data: String,  // JSON for entity-specific data
 }
@@ -95,4 +153,7 @@ pub struct SaveData {
 }
 
 /// Current save file version
-pub const CURRENT_SAVE_VERSION: u32 = 1;
+///
+/// - 1: Single world (world_state + entities)
+/// - 2: Multiple zones (zones + active_zone; version 1 saves load as the meadow)
+pub const CURRENT_SAVE_VERSION: u32 = 2;
@@ -0,0 +1,193 @@
+//! Spatial hash - a uniform grid broadphase for collision queries
+//!
+//! Objects are bucketed by every grid cell their bounds touch, so a query only
+//! looks at objects in the cells it overlaps instead of the whole collection.
+//! The hash stores plain indices into whatever collection it was built from
+//! (e.g. `enemies[index]`) and is meant to be rebuilt each tick, after things
+//! have moved.
+//!
+//! Queries return indices in ascending order without duplicates, and only for
+//! objects that really intersect (the same test as `collision::aabb_intersect`),
+//! so results match a brute-force scan exactly.
+//!
+//! # Example
+//!
+//! ```rust
+//! let mut hash = SpatialHash::new(64);
+//! hash.rebuild(enemies.iter().enumerate().map(|(i, enemy)| (i, enemy.get_bounds())));
+//! for index in hash.query_rect(&attack_hitbox) {
+//!     enemies[index].take_damage(damage);
+//! }
+//! ```
+
+use crate::collision;
+use sdl2::rect::Rect;
+use std::collections::HashMap;
+
+pub struct SpatialHash {
+    /// Cell edge length in world pixels
+    cell_size: i32,
+    /// Object indices touching each cell
+    cells: HashMap<(i32, i32), Vec<usize>>,
+    /// Bounds of every inserted object, by index
+    bounds: HashMap<usize, Rect>,
+}
+
+impl SpatialHash {
+    /// Creates an empty hash; `cell_size` should be around the size of a typical object
+    pub fn new(cell_size: i32) -> Self {
+        SpatialHash {
+            cell_size: cell_size.max(1),
+            cells: HashMap::new(),
+            bounds: HashMap::new(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bounds.clear();
+    }
+
+    /// Replaces the contents with the given (index, bounds) pairs
+    pub fn rebuild(&mut self, objects: impl IntoIterator<Item = (usize, Rect)>) {
+        self.clear();
+        for (index, bounds) in objects {
+            self.insert(index, bounds);
+        }
+    }
+
+    /// Adds an object (an index that is already present is moved to the new bounds)
+    pub fn insert(&mut self, index: usize, bounds: Rect) {
+        self.remove(index);
+        for cell in self.cells_touching(&bounds) {
+            self.cells.entry(cell).or_default().push(index);
+        }
+        self.bounds.insert(index, bounds);
+    }
+
+    /// Removes an object; returns false if it wasn't in the hash
+    pub fn remove(&mut self, index: usize) -> bool {
+        let Some(bounds) = self.bounds.remove(&index) else {
+            return false;
+        };
+        for cell in self.cells_touching(&bounds) {
+            if let Some(indices) = self.cells.get_mut(&cell) {
+                indices.retain(|&i| i != index);
+                if indices.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        }
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.bounds.len()
+    }
+
+    #[allow(dead_code)] // Reserved for callers that skip queries on empty collections
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    /// Indices of every object whose bounds intersect `area`
+    pub fn query_rect(&self, area: &Rect) -> Vec<usize> {
+        let mut found: Vec<usize> = self.cells_touching(area)
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .filter(|index| collision::aabb_intersect(&self.bounds[index], area))
+            .collect();
+        found.sort_unstable();
+        found.dedup();
+        found
+    }
+
+    /// Indices of every object whose bounds come within `radius` of `center`
+    pub fn query_radius(&self, center: (i32, i32), radius: f32) -> Vec<usize> {
+        let reach = radius.max(0.0).ceil() as i32;
+        let area = Rect::new(
+            center.0 - reach,
+            center.1 - reach,
+            (reach * 2 + 1) as u32,
+            (reach * 2 + 1) as u32,
+        );
+        let mut found = self.query_rect(&area);
+        found.retain(|index| distance_to_rect(center, &self.bounds[index]) <= radius);
+        found
+    }
+
+    /// Every cell a rect touches (right and bottom edges are exclusive, like `Rect`)
+    fn cells_touching(&self, bounds: &Rect) -> impl Iterator<Item = (i32, i32)> + use<> {
+        let first_x = bounds.x().div_euclid(self.cell_size);
+        let first_y = bounds.y().div_euclid(self.cell_size);
+        let last_x = (bounds.right() - 1).div_euclid(self.cell_size);
+        let last_y = (bounds.bottom() - 1).div_euclid(self.cell_size);
+        (first_y..=last_y).flat_map(move |y| (first_x..=last_x).map(move |x| (x, y)))
+    }
+}
+
+/// Distance from a point to the nearest point of a rect (0 inside it)
+fn distance_to_rect(point: (i32, i32), rect: &Rect) -> f32 {
+    let dx = (rect.x() - point.0).max(point.0 - (rect.right() - 1)).max(0) as f32;
+    let dy = (rect.y() - point.1).max(point.1 - (rect.bottom() - 1)).max(0) as f32;
+    (dx * dx + dy * dy).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::SimpleRng;
+
+    /// Thousands of randomly sized objects, some far outside the origin cell
+    fn random_rects(count: usize, seed: u64) -> Vec<Rect> {
+        let mut rng = SimpleRng::new(seed);
+        (0..count)
+            .map(|_| {
+                let x = rng.range_u32(0, 4000) as i32 - 1000;
+                let y = rng.range_u32(0, 3000) as i32 - 1000;
+                Rect::new(x, y, rng.range_u32(1, 120), rng.range_u32(1, 120))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_queries_match_brute_force() {
+        let rects = random_rects(3000, 7);
+        let mut hash = SpatialHash::new(64);
+        hash.rebuild(rects.iter().copied().enumerate());
+        assert_eq!(hash.len(), rects.len());
+
+        for area in random_rects(200, 11) {
+            let expected: Vec<usize> = (0..rects.len())
+                .filter(|&i| collision::aabb_intersect(&rects[i], &area))
+                .collect();
+            assert_eq!(hash.query_rect(&area), expected);
+        }
+
+        for area in random_rects(200, 13) {
+            let center = (area.x(), area.y());
+            let radius = area.width() as f32;
+            let expected: Vec<usize> = (0..rects.len())
+                .filter(|&i| distance_to_rect(center, &rects[i]) <= radius)
+                .collect();
+            assert_eq!(hash.query_radius(center, radius), expected);
+        }
+    }
+
+    #[test]
+    fn test_insert_moves_and_remove_forgets() {
+        let mut hash = SpatialHash::new(32);
+        hash.insert(4, Rect::new(0, 0, 16, 16));
+        assert_eq!(hash.query_rect(&Rect::new(8, 8, 4, 4)), vec![4]);
+
+        hash.insert(4, Rect::new(200, 200, 16, 16));
+        assert!(hash.query_rect(&Rect::new(8, 8, 4, 4)).is_empty());
+        assert_eq!(hash.query_radius((190, 205), 10.0), vec![4]);
+        assert!(hash.query_radius((190, 205), 9.0).is_empty());
+
+        assert!(hash.remove(4));
+        assert!(!hash.remove(4));
+        assert!(hash.is_empty());
+    }
+}
@@ -22,6 +22,7 @@
 //! - **Struct Methods**: Encapsulating behavior with data
 //! - **Option Types**: Handling cases like overkill damage
 
+use crate::combat::{DamageRoll, Resistances};
 use std::time::Duration;
 
 /// Represents a character's health points
@@ -108,6 +109,7 @@ impl Health {
             } else {
                 0.0
             },
+            is_crit: false,
         }
     }
 
@@ -144,6 +146,78 @@ impl Health {
     }
 }
 
+/// Seconds after spending stamina before it starts regenerating
+pub const STAMINA_REGEN_DELAY: f32 = 0.6;
+
+/// Stamina spent on dodge rolls (and some weapons' attacks)
+///
+/// Spending stamina pauses regeneration for `STAMINA_REGEN_DELAY` seconds, so
+/// chaining actions drains it faster than it comes back.
+///
+/// # Example
+///
+/// ```rust
+/// let mut stamina = Stamina::new(10.0);
+/// assert!(stamina.try_spend(4.0));
+/// stamina.regenerate(5.0, 1.0); // 0.6s of delay, then 0.4s at 5/s
+/// assert_eq!(stamina.current(), 8.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stamina {
+    current: f32,
+    max: f32,
+    /// Seconds left before regeneration resumes
+    regen_delay: f32,
+}
+
+impl Stamina {
+    /// Creates a full stamina pool
+    pub fn new(max: f32) -> Self {
+        Stamina { current: max, max, regen_delay: 0.0 }
+    }
+
+    pub fn current(&self) -> f32 {
+        self.current
+    }
+
+    /// Returns stamina as a percentage (0.0 to 1.0)
+    pub fn percentage(&self) -> f32 {
+        if self.max <= 0.0 {
+            0.0
+        } else {
+            self.current / self.max
+        }
+    }
+
+    /// Spends `amount` if there is enough, returning whether it was spent
+    ///
+    /// Spending nothing always succeeds and doesn't delay regeneration.
+    pub fn try_spend(&mut self, amount: f32) -> bool {
+        if amount <= 0.0 {
+            return true;
+        }
+        if self.current < amount {
+            return false;
+        }
+        self.current -= amount;
+        self.regen_delay = STAMINA_REGEN_DELAY;
+        true
+    }
+
+    /// Regenerates `rate` stamina per second, once the delay after spending is over
+    pub fn regenerate(&mut self, rate: f32, delta_time: f32) {
+        let delayed = delta_time.min(self.regen_delay);
+        self.regen_delay -= delayed;
+        self.current = (self.current + rate * (delta_time - delayed)).min(self.max);
+    }
+
+    /// Refills stamina completely (respawning)
+    pub fn refill(&mut self) {
+        self.current = self.max;
+        self.regen_delay = 0.0;
+    }
+}
+
 /// Result of a damage operation
 ///
 /// Provides detailed information about damage dealt, useful for:
@@ -153,13 +227,14 @@ impl Health {
 #[derive(Debug, Clone)]
 pub struct DamageResult {
     /// Actual damage dealt (may be less than requested if target had less health)
-    #[allow(dead_code)] // Reserved for damage number display
     pub damage_dealt: f32,
     /// Whether this damage killed the target
     pub is_fatal: bool,
     /// Excess damage beyond what was needed to kill (0.0 if not fatal)
     #[allow(dead_code)] // Reserved for damage number display
     pub overkill: f32,
+    /// The damage was a critical hit
+    pub is_crit: bool,
 }
 
 impl DamageResult {
@@ -169,6 +244,7 @@ impl DamageResult {
             damage_dealt: 0.0,
             is_fatal: false,
             overkill: 0.0,
+            is_crit: false,
         }
     }
 }
@@ -186,10 +262,24 @@ pub enum StatType {
     AttackDamage,
     /// Attacks per second
     AttackSpeed,
-    /// Damage reduction (0.0 = no reduction, 1.0 = invulnerable)
+    /// Physical damage reduction (0.0 = no reduction, 1.0 = invulnerable)
     Defense,
+    /// Magical damage reduction (0.0 = no reduction, 1.0 = invulnerable)
+    MagicResistance,
     /// Maximum health points
     MaxHealth,
+    /// Knockback reduction (0.0 = full knockback, 1.0 = immovable)
+    KnockbackResistance,
+    /// Chance of a critical hit (0.0 - 1.0)
+    CritChance,
+    /// Damage multiplier on a critical hit
+    CritMultiplier,
+    /// Stamina regenerated per second
+    StaminaRegen,
+    /// Extra reach in pixels for picking up dropped items
+    PickupRadius,
+    /// Multiplier on XP earned (1.0 = normal)
+    XpGain,
 }
 
 /// Types of stat modifications
@@ -198,7 +288,7 @@ pub enum StatType {
 /// 1. Override - replaces the value completely
 /// 2. Flat - adds/subtracts a fixed amount
 /// 3. Percentage - multiplies by a factor (0.5 = +50%)
-#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 #[allow(dead_code)] // Reserved for future stat modifier system
 pub enum StatModifier {
     /// Completely replace the stat value
@@ -212,18 +302,19 @@ pub enum StatModifier {
 /// A stat modification effect
 ///
 /// Represents a buff, debuff, or permanent stat change
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[allow(dead_code)] // Reserved for future stat modifier system
 pub struct ModifierEffect {
     /// Which stat this modifies
     pub stat_type: StatType,
     /// The type and value of modification
     pub modifier: StatModifier,
-    /// How long the effect lasts (None = permanent)
+    /// How long the effect lasts (None = permanent); `ActiveEffect::from_modifier` counts it down
     /// Note: Duration is not serializable, so we skip it
     #[serde(skip)]
     pub duration: Option<Duration>,
     /// What applied this effect (for debugging/UI)
+    #[serde(default)]
     pub source: String,
 }
 
@@ -251,7 +342,17 @@ pub struct Stats {
     pub attack_damage: f32,
     pub attack_speed: f32,
     pub defense: f32,
+    pub magic_resistance: f32,
     pub max_health: f32,
+    pub knockback_resistance: f32,
+    pub crit_chance: f32,
+    pub crit_multiplier: f32,
+    /// Attack damage varies by ± this fraction (0.1 = ±10%)
+    pub damage_variance: f32,
+    pub stamina: Stamina,
+    pub stamina_regen: f32,
+    pub pickup_radius: f32,
+    pub xp_gain: f32,
 }
 
 impl Stats {
@@ -265,7 +366,16 @@ impl Stats {
             attack_damage: 3.0,  // 3 damage per hit (slimes have 8 HP, so 3 hits to kill)
             attack_speed: 3.0,
             defense: 0.0,
+            magic_resistance: 0.0,
             max_health: 10.0,
+            knockback_resistance: 0.0,
+            crit_chance: 0.05,
+            crit_multiplier: 2.0,
+            damage_variance: 0.15,
+            stamina: Stamina::new(10.0),
+            stamina_regen: 4.0,  // A dodge (3 stamina) comes back in under a second, after the delay
+            pickup_radius: 0.0,
+            xp_gain: 1.0,
         }
     }
 
@@ -277,7 +387,14 @@ impl Stats {
             StatType::AttackDamage => self.attack_damage,
             StatType::AttackSpeed => self.attack_speed,
             StatType::Defense => self.defense,
+            StatType::MagicResistance => self.magic_resistance,
             StatType::MaxHealth => self.max_health,
+            StatType::KnockbackResistance => self.knockback_resistance,
+            StatType::CritChance => self.crit_chance,
+            StatType::CritMultiplier => self.crit_multiplier,
+            StatType::StaminaRegen => self.stamina_regen,
+            StatType::PickupRadius => self.pickup_radius,
+            StatType::XpGain => self.xp_gain,
         }
     }
 
@@ -344,6 +461,26 @@ impl Stats {
         // Apply formula: (base + flat) * percentage_multiplier
         (base_value + flat_bonus) * percentage_multiplier
     }
+
+    /// Effective resistance against each damage type, after modifiers
+    ///
+    /// Buffs and equipment raise these through Defense and MagicResistance
+    /// modifiers, like any other stat.
+    pub fn resistances(&self, modifiers: &[ModifierEffect]) -> Resistances {
+        Resistances {
+            physical: self.effective_stat(StatType::Defense, modifiers),
+            magical: self.effective_stat(StatType::MagicResistance, modifiers),
+        }
+    }
+
+    /// Variance and crit stats for this character's attacks, after modifiers
+    pub fn damage_roll(&self, modifiers: &[ModifierEffect]) -> DamageRoll {
+        DamageRoll {
+            variance: self.damage_variance,
+            crit_chance: self.effective_stat(StatType::CritChance, modifiers),
+            crit_multiplier: self.effective_stat(StatType::CritMultiplier, modifiers),
+        }
+    }
 }
 
 impl Default for Stats {
@@ -406,6 +543,31 @@ mod tests {
         assert_eq!(health.percentage(), 0.75);
     }
 
+    #[test]
+    fn test_stamina_spend_and_regenerate() {
+        let mut stamina = Stamina::new(10.0);
+        assert!(stamina.try_spend(4.0));
+        assert!(!stamina.try_spend(7.0));
+        assert_eq!(stamina.current(), 6.0);
+
+        // Nothing comes back during the delay, then `rate` per second
+        stamina.regenerate(5.0, STAMINA_REGEN_DELAY);
+        assert_eq!(stamina.current(), 6.0);
+        stamina.regenerate(5.0, 0.5);
+        assert_eq!(stamina.current(), 8.5);
+        stamina.regenerate(5.0, 10.0);
+        assert_eq!(stamina.current(), 10.0);
+
+        // Free actions don't pause regeneration
+        assert!(stamina.try_spend(6.0));
+        stamina.refill();
+        assert!(stamina.try_spend(0.0));
+        stamina.try_spend(1.0);
+        stamina.regenerate(5.0, STAMINA_REGEN_DELAY + 0.1);
+        assert!((stamina.current() - 9.5).abs() < 0.001);
+        assert_eq!(stamina.percentage(), stamina.current() / 10.0);
+    }
+
     #[test]
     fn test_stat_modifiers_flat() {
         let stats = Stats::new();
@@ -479,4 +641,29 @@ mod tests {
         let effective = stats.effective_stat(StatType::AttackDamage, &modifiers);
         assert_eq!(effective, 100.0);
     }
+
+    #[test]
+    fn test_resistances_from_buffs_and_equipment() {
+        let mut stats = Stats::new();
+        stats.defense = 0.1;
+        assert_eq!(stats.resistances(&[]), Resistances { physical: 0.1, magical: 0.0 });
+
+        // Equipment modifiers come from item data
+        let cloak: ModifierEffect = serde_json::from_str(
+            r#"{ "stat_type": "MagicResistance", "modifier": { "Flat": 0.25 }, "source": "Warded Cloak" }"#,
+        ).unwrap();
+        let modifiers = vec![
+            cloak,
+            ModifierEffect {
+                stat_type: StatType::Defense,
+                modifier: StatModifier::Flat(0.2),
+                duration: None,
+                source: "Pyramid of Defense".to_string(),
+            },
+        ];
+
+        let resistances = stats.resistances(&modifiers);
+        assert!((resistances.physical - 0.3).abs() < 0.001);
+        assert_eq!(resistances.magical, 0.25);
+    }
 }
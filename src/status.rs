@@ -0,0 +1,388 @@
+//! Status effects - lingering conditions left behind by hits
+//!
+//! A status effect is attached to the player or an enemy for a while after a
+//! hit lands (e.g. a spitter's poison, a thrown slime ball's slow). Each effect
+//! has a duration, an optional tick interval and a stack rule deciding what
+//! happens when the same kind is applied again:
+//! - `Refresh`: the duration restarts (keeping the stronger magnitude)
+//! - `StackIntensity`: another stack is added (up to `max_stacks`) and the duration restarts
+//! - `Ignore`: nothing happens until the current effect wears off
+//!
+//! What an effect does comes from its kind. Poison and burn deal a
+//! `DamageEvent` every tick; slow is a `MovementSpeed` stat modifier; stun
+//! stops its target from acting at all.
+//!
+//! Definitions (enemy attacks) describe effects with a `StatusEffectSpec`; the
+//! live `StatusEffect` adds the timers and is saved with its entity.
+//!
+//! # Example
+//!
+//! ```rust
+//! let mut status = StatusEffects::default();
+//! status.apply(StatusEffect::new(StatusKind::Poison, 0.5, 4.0));
+//!
+//! for (kind, damage) in status.update(delta_time) {
+//!     player.take_status_damage(damage);
+//! }
+//! let max_speed = stats.effective_stat(StatType::MovementSpeed, &status.modifiers());
+//! ```
+
+use crate::combat::{DamageEvent, DamageSource};
+use crate::stats::{ModifierEffect, StatModifier, StatType};
+use sdl2::pixels::Color;
+use serde::{Deserialize, Serialize};
+
+/// The kinds of status effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusKind {
+    /// True damage every second; stacks up
+    Poison,
+    /// Magical damage twice a second
+    Burn,
+    /// Lowers movement speed by `magnitude` (0.3 = 30% slower)
+    Slow,
+    /// Can't move, attack or think
+    Stun,
+}
+
+impl StatusKind {
+    /// HUD icon and damage number color
+    pub fn color(self) -> Color {
+        match self {
+            StatusKind::Poison => Color::RGB(120, 200, 60),
+            StatusKind::Burn => Color::RGB(240, 120, 30),
+            StatusKind::Slow => Color::RGB(90, 150, 240),
+            StatusKind::Stun => Color::RGB(240, 220, 80),
+        }
+    }
+}
+
+/// What re-applying an effect the target already has does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StackRule {
+    Refresh,
+    StackIntensity,
+    Ignore,
+}
+
+/// An effect as written in data files (e.g. an enemy attack's on-hit effect)
+///
+/// ```json
+/// "status_effect": { "kind": "poison", "magnitude": 0.5, "duration": 4.0 }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StatusEffectSpec {
+    pub kind: StatusKind,
+    /// Damage per tick (poison, burn) or speed fraction lost (slow); unused by stun
+    #[serde(default)]
+    pub magnitude: f32,
+    /// Seconds
+    pub duration: f32,
+}
+
+impl StatusEffectSpec {
+    pub fn to_effect(self) -> StatusEffect {
+        StatusEffect::new(self.kind, self.magnitude, self.duration)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.duration <= 0.0 {
+            return Err("status effect duration must be positive".to_string());
+        }
+        if self.magnitude < 0.0 || (self.kind == StatusKind::Slow && self.magnitude > 1.0) {
+            return Err("status effect magnitude must be non-negative (at most 1 for slow)".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// A status effect on a target
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    /// Strength of one stack (see `StatusEffectSpec::magnitude`)
+    pub magnitude: f32,
+    /// Seconds the effect lasts when (re)applied
+    pub duration: f32,
+    /// Seconds left
+    pub remaining: f32,
+    /// Seconds between damage ticks (0 = never ticks)
+    pub tick_interval: f32,
+    /// Seconds since the last tick
+    pub tick_timer: f32,
+    pub stacks: u32,
+    pub max_stacks: u32,
+    pub stack_rule: StackRule,
+    /// Tick damage not yet dealt to a target with whole-point health (below 1)
+    #[serde(default)]
+    pub damage_carry: f32,
+}
+
+impl StatusEffect {
+    /// A fresh single-stack effect with the kind's tick interval and stack rule
+    pub fn new(kind: StatusKind, magnitude: f32, duration: f32) -> Self {
+        let (tick_interval, stack_rule, max_stacks) = match kind {
+            StatusKind::Poison => (1.0, StackRule::StackIntensity, 5),
+            StatusKind::Burn => (0.5, StackRule::Refresh, 1),
+            StatusKind::Slow => (0.0, StackRule::Refresh, 1),
+            StatusKind::Stun => (0.0, StackRule::Ignore, 1),
+        };
+        StatusEffect {
+            kind,
+            magnitude,
+            duration,
+            remaining: duration,
+            tick_interval,
+            tick_timer: 0.0,
+            stacks: 1,
+            max_stacks,
+            stack_rule,
+            damage_carry: 0.0,
+        }
+    }
+
+    /// Damage dealt each tick, for the kinds that tick
+    pub fn tick_damage(&self) -> Option<DamageEvent> {
+        let amount = self.magnitude * self.stacks as f32;
+        match self.kind {
+            StatusKind::Poison => Some(DamageEvent::true_damage(amount, DamageSource::Environment)),
+            StatusKind::Burn => Some(DamageEvent::magical(amount, DamageSource::Environment)),
+            StatusKind::Slow | StatusKind::Stun => None,
+        }
+    }
+
+    /// Stat change while active, for the kinds that modify stats
+    pub fn modifier(&self) -> Option<ModifierEffect> {
+        match self.kind {
+            StatusKind::Slow => Some(ModifierEffect {
+                stat_type: StatType::MovementSpeed,
+                modifier: StatModifier::Percentage(-(self.magnitude * self.stacks as f32).min(1.0)),
+                duration: None,
+                source: "Slow".to_string(),
+            }),
+            StatusKind::Poison | StatusKind::Burn | StatusKind::Stun => None,
+        }
+    }
+
+    /// 1.0 when just applied, falling to 0.0 as it wears off
+    pub fn fraction_remaining(&self) -> f32 {
+        if self.duration <= 0.0 {
+            0.0
+        } else {
+            (self.remaining / self.duration).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// Every status effect on one target (at most one entry per kind)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    /// Applies an effect, following the stack rule of one already active
+    ///
+    /// Returns false if it was ignored.
+    pub fn apply(&mut self, effect: StatusEffect) -> bool {
+        let Some(current) = self.effects.iter_mut().find(|current| current.kind == effect.kind) else {
+            self.effects.push(effect);
+            return true;
+        };
+
+        match current.stack_rule {
+            StackRule::Ignore => return false,
+            StackRule::Refresh => {
+                current.magnitude = current.magnitude.max(effect.magnitude);
+            }
+            StackRule::StackIntensity => {
+                current.stacks = (current.stacks + effect.stacks).min(current.max_stacks.max(1));
+            }
+        }
+        // The tick timer carries on, so re-applying never delays the next tick
+        current.duration = current.duration.max(effect.duration);
+        current.remaining = current.duration;
+        true
+    }
+
+    /// Counts effects down, returning the damage of every tick that came due
+    ///
+    /// An effect still gets the tick that lands exactly as it runs out.
+    pub fn update(&mut self, delta_time: f32) -> Vec<(StatusKind, DamageEvent)> {
+        let mut ticks = Vec::new();
+        self.update_with(delta_time, |effect, damage| ticks.push((effect.kind, damage)));
+        ticks
+    }
+
+    /// Like `update`, for targets with whole-point health
+    ///
+    /// `resist` turns each tick into the damage actually taken. Fractions
+    /// carry over in the effect and come due as whole points once they add
+    /// up, so weak ticks still deal their damage over time (and no more).
+    pub fn update_whole(&mut self, delta_time: f32, resist: impl Fn(&DamageEvent) -> f32) -> Vec<(StatusKind, i32)> {
+        let mut ticks = Vec::new();
+        self.update_with(delta_time, |effect, damage| {
+            effect.damage_carry += resist(&damage);
+            // The epsilon keeps float drift (e.g. 0.4 * 5 = 1.9999) from losing a point
+            let whole = (effect.damage_carry + 1e-4).floor();
+            effect.damage_carry = (effect.damage_carry - whole).max(0.0);
+            if whole >= 1.0 {
+                ticks.push((effect.kind, whole as i32));
+            }
+        });
+        ticks
+    }
+
+    /// Counts effects down, handing every tick that came due to `on_tick`
+    fn update_with(&mut self, delta_time: f32, mut on_tick: impl FnMut(&mut StatusEffect, DamageEvent)) {
+        for effect in &mut self.effects {
+            if effect.tick_interval > 0.0 {
+                effect.tick_timer += delta_time.min(effect.remaining);
+                while effect.tick_timer >= effect.tick_interval {
+                    effect.tick_timer -= effect.tick_interval;
+                    if let Some(damage) = effect.tick_damage() {
+                        on_tick(effect, damage);
+                    }
+                }
+            }
+            effect.remaining -= delta_time;
+        }
+        self.effects.retain(|effect| effect.remaining > 0.0);
+    }
+
+    /// Stat modifiers from every active effect
+    pub fn modifiers(&self) -> Vec<ModifierEffect> {
+        self.effects.iter().filter_map(StatusEffect::modifier).collect()
+    }
+
+    /// Movement speed multiplier for targets without a stats block (1.0 = normal)
+    pub fn speed_multiplier(&self) -> f32 {
+        let slowdown: f32 = self.modifiers().iter()
+            .filter(|modifier| modifier.stat_type == StatType::MovementSpeed)
+            .map(|modifier| match modifier.modifier {
+                StatModifier::Percentage(value) => value,
+                StatModifier::Flat(_) | StatModifier::Override(_) => 0.0,
+            })
+            .sum();
+        (1.0 + slowdown).max(0.0)
+    }
+
+    pub fn is_stunned(&self) -> bool {
+        self.has(StatusKind::Stun)
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.effects.iter().any(|effect| effect.kind == kind)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.effects.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::DamageType;
+
+    #[test]
+    fn test_poison_ticks_every_second_until_it_runs_out() {
+        let mut status = StatusEffects::default();
+        status.apply(StatusEffect::new(StatusKind::Poison, 0.5, 3.0));
+
+        let mut ticks = Vec::new();
+        for _ in 0..16 {
+            ticks.extend(status.update(0.25));
+        }
+        assert_eq!(ticks.len(), 3);
+        let (kind, damage) = &ticks[0];
+        assert_eq!(*kind, StatusKind::Poison);
+        assert_eq!(damage.amount, 0.5);
+        assert_eq!(damage.damage_type, DamageType::True);
+        assert!(!status.has(StatusKind::Poison));
+    }
+
+    #[test]
+    fn test_whole_point_ticks_carry_fractions() {
+        let mut status = StatusEffects::default();
+        status.apply(StatusEffect::new(StatusKind::Poison, 0.4, 5.0));
+
+        // 0.4 a second comes due as whole points: 0, 0, 1, 0, 1
+        let mut dealt = Vec::new();
+        for _ in 0..5 {
+            dealt.push(status.update_whole(1.0, |damage| damage.amount).iter().map(|(_, amount)| *amount).sum::<i32>());
+        }
+        assert_eq!(dealt, vec![0, 0, 1, 0, 1]);
+
+        // Resistances scale the ticks before they add up
+        status.apply(StatusEffect::new(StatusKind::Burn, 3.0, 1.0));
+        let ticks = status.update_whole(1.0, |damage| damage.amount * 0.25);
+        assert_eq!(ticks, vec![(StatusKind::Burn, 1)]);
+    }
+
+    #[test]
+    fn test_stack_rules() {
+        let mut status = StatusEffects::default();
+
+        // Poison stacks intensity up to its cap and restarts the duration
+        status.apply(StatusEffect::new(StatusKind::Poison, 0.5, 4.0));
+        status.update(3.0);
+        for _ in 0..10 {
+            status.apply(StatusEffect::new(StatusKind::Poison, 0.5, 4.0));
+        }
+        let poison = status.iter().find(|effect| effect.kind == StatusKind::Poison).unwrap();
+        assert_eq!(poison.stacks, 5);
+        assert_eq!(poison.remaining, 4.0);
+        assert_eq!(poison.tick_damage().unwrap().amount, 2.5);
+
+        // Slow refreshes and keeps the stronger magnitude
+        status.apply(StatusEffect::new(StatusKind::Slow, 0.4, 2.0));
+        status.update(1.5);
+        assert!(status.apply(StatusEffect::new(StatusKind::Slow, 0.2, 2.0)));
+        let slow = status.iter().find(|effect| effect.kind == StatusKind::Slow).unwrap();
+        assert_eq!((slow.stacks, slow.magnitude, slow.remaining), (1, 0.4, 2.0));
+
+        // Stun can't be extended while it lasts
+        status.apply(StatusEffect::new(StatusKind::Stun, 0.0, 0.5));
+        status.update(0.25);
+        assert!(!status.apply(StatusEffect::new(StatusKind::Stun, 0.0, 5.0)));
+        status.update(0.5);
+        assert!(!status.is_stunned());
+    }
+
+    #[test]
+    fn test_slow_is_a_movement_speed_modifier() {
+        let mut status = StatusEffects::default();
+        assert_eq!(status.speed_multiplier(), 1.0);
+
+        status.apply(StatusEffect::new(StatusKind::Slow, 0.3, 2.0));
+        let stats = crate::stats::Stats::new();
+        let speed = stats.effective_stat(StatType::MovementSpeed, &status.modifiers());
+        assert!((speed - stats.movement_speed * 0.7).abs() < 0.001);
+        assert!((status.speed_multiplier() - 0.7).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_saves_round_trip_and_specs_validate() {
+        let mut status = StatusEffects::default();
+        status.apply(StatusEffect::new(StatusKind::Burn, 1.0, 3.0));
+        status.update(0.7);
+
+        let json = serde_json::to_string(&status).unwrap();
+        let restored: StatusEffects = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, status);
+
+        let spec: StatusEffectSpec = serde_json::from_str(r#"{ "kind": "stun", "duration": 0.5 }"#).unwrap();
+        assert!(spec.validate().is_ok());
+        assert_eq!(spec.to_effect().stack_rule, StackRule::Ignore);
+        let bad = StatusEffectSpec { kind: StatusKind::Slow, magnitude: 1.5, duration: 1.0 };
+        assert!(bad.validate().is_err());
+    }
+}
@@ -6,17 +6,21 @@
 ///
 /// # Core Mechanic: Progressive Awakening
 ///
-/// The entity requires **8 hits** to fully awaken:
-/// - Hit 1: Frame 1 → Frame 2 (starts awakening)
-/// - Hit 2: Frame 2 → Frame 3
-/// - ...
-/// - Hit 7: Frame 7 → Frame 8 (fully awake)
+/// Each pyramid type has a definition (see pyramid.rs) saying how many hits wake it
+/// (7 by default). The hits landed so far are spread over sprite frames 1-8:
+/// - No hits: Frame 1 (dormant)
+/// - Each hit: one step closer to Frame 8
+/// - The last hit: Frame 8 (fully awake)
 ///
-/// If the player stops hitting for **2 seconds**, the entity automatically reverses
-/// one frame every 2 seconds until it returns to dormant.
+/// If the player stops hitting for **1 second**, the entity starts losing progress at
+/// the definition's `reverse_speed` (hits per second) until it returns to dormant.
 ///
 /// Once fully awake, the entity displays a looping animation (frames 8-13) and will
-/// return to dormant after **30 seconds** of inactivity.
+/// wind back down after the definition's `awake_duration` (30 seconds by default)
+/// without a hit.
+///
+/// Pyramids can be linked into groups that reward waking them in order
+/// (see pyramid.rs); a linked pyramid reports each awakening through `take_awakened`.
 ///
 /// # State Machine
 ///
@@ -31,6 +35,7 @@
 /// - **Manual animation control**: Using pause() and set_frame() from SpriteSheet
 use crate::collision::StaticCollidable;
 use crate::collision::aabb_intersect;
+use crate::pyramid::{PyramidDefinition, PyramidLink};
 use crate::render::DepthSortable;
 use crate::save::{Saveable, SaveData, SaveError};
 use crate::sprite::SpriteSheet;
@@ -44,12 +49,49 @@ use std::time::Instant;
 const SPRITE_SCALE: u32 = 2;
 
 /// Type of buff provided by this entity when awake.
+///
+/// The buff itself comes from the type's definition in assets/pyramids/.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum EntityType {
     Attack,       // +1 attack damage
     Defense,      // +1 defense
     Speed,        // +1 movement speed
     Regeneration, // +2 HP every 5 seconds
+    Crit,         // +10% crit chance
+    PickupRadius, // Items are picked up from further away
+    XpGain,       // +25% XP
+}
+
+impl EntityType {
+    /// Every type, in declaration order
+    pub const ALL: [EntityType; 7] = [
+        EntityType::Attack,
+        EntityType::Defense,
+        EntityType::Speed,
+        EntityType::Regeneration,
+        EntityType::Crit,
+        EntityType::PickupRadius,
+        EntityType::XpGain,
+    ];
+
+    /// Position in `ALL`
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Sprite frame (1-8) showing `hits` of the `hits_to_awaken` needed
+///
+/// Any progress shows at least frame 2, and only a full awakening reaches frame 8.
+pub fn awakening_frame_for(hits: u32, hits_to_awaken: u32) -> usize {
+    if hits == 0 {
+        1
+    } else if hits >= hits_to_awaken {
+        8
+    } else {
+        // Hits 1 to hits_to_awaken - 1 spread over frames 2-7
+        2 + ((hits - 1) * 6 / (hits_to_awaken - 1)) as usize
+    }
 }
 
 /// State machine for The Entity's awakening lifecycle.
@@ -88,16 +130,19 @@ pub enum EntityState {
 ///
 /// **State Machine**:
 /// - `state`: Current lifecycle state
-/// - `awakening_frame`: Current frame during awakening (1-8)
-/// - `last_hit_time`: When the entity was last hit (for 2s timeout)
-/// - `reverse_timer`: Accumulator for automatic frame reversal
-/// - `inactivity_timer`: Time since last hit in awake state (for 30s timeout)
+/// - `hits`: Awakening progress, out of the definition's `hits_to_awaken`
+/// - `awakening_frame`: Current frame during awakening (1-8, follows `hits`)
+/// - `last_hit_time`: When the entity was last hit (for 1s timeout)
+/// - `reverse_timer`: Accumulator for automatic progress reversal
+/// - `inactivity_timer`: Time since last hit in awake state (for the awake duration)
 ///
 /// **Animation**:
 /// - `sprite_sheet`: Sprite sheet with manual frame control
 ///
 /// **Identification**:
 /// - `id`: Unique identifier (0-3 for the 4 spawned entities)
+/// - `definition`: Tuning and buff for its type
+/// - `link`: Its place in a linked group, if any
 ///
 /// # Rust Learning: Lifetimes
 ///
@@ -113,6 +158,7 @@ pub struct TheEntity<'a> {
 
     // State Machine
     pub state: EntityState,
+    pub hits: u32,
     pub awakening_frame: usize,
     last_hit_time: Instant,
     reverse_timer: f32,
@@ -126,6 +172,9 @@ pub struct TheEntity<'a> {
     // Identification
     pub id: usize,
     pub entity_type: EntityType,  // What buff this entity provides when awake
+    pub definition: PyramidDefinition,
+    pub link: Option<PyramidLink>,
+    awakened: bool,  // Woke since the last take_awakened()
 }
 
 impl<'a> TheEntity<'a> {
@@ -135,7 +184,7 @@ impl<'a> TheEntity<'a> {
     ///
     /// - `id`: Unique identifier for this entity (0-3)
     /// - `x, y`: World position in pixels (anchor at base)
-    /// - `entity_type`: What buff this entity provides when awake
+    /// - `definition`: Its type's tuning and buff
     /// - `sprite_sheet`: Pre-configured sprite sheet with 13 frames
     ///
     /// # Initial State
@@ -145,10 +194,10 @@ impl<'a> TheEntity<'a> {
     /// # Example
     ///
     /// ```rust
-    /// let entity = TheEntity::new(0, 320, 200, sprite_sheet);
+    /// let entity = TheEntity::new(0, 320, 200, registry.get(EntityType::Speed), sprite_sheet);
     /// assert_eq!(entity.state, EntityState::Dormant);
     /// ```
-    pub fn new(id: usize, x: i32, y: i32, entity_type: EntityType, sprite_sheet: SpriteSheet<'a>) -> Self {
+    pub fn new(id: usize, x: i32, y: i32, definition: &PyramidDefinition, sprite_sheet: SpriteSheet<'a>) -> Self {
         let mut entity = TheEntity {
             x,
             y,
@@ -156,6 +205,7 @@ impl<'a> TheEntity<'a> {
             height: 32,
             sprite_height: 32,
             state: EntityState::Dormant,
+            hits: 0,
             awakening_frame: 1,
             last_hit_time: Instant::now(),
             reverse_timer: 0.0,
@@ -164,7 +214,10 @@ impl<'a> TheEntity<'a> {
             awake_animation_frame: 8,  // Start at frame 8 (frame 9 in spec)
             awake_animation_timer: 0.0,
             id,
-            entity_type,
+            entity_type: definition.entity_type,
+            definition: definition.clone(),
+            link: None,
+            awakened: false,
         };
 
         // Initialize sprite to dormant state (frame 0 in sprite sheet = frame 1 in spec)
@@ -174,15 +227,15 @@ impl<'a> TheEntity<'a> {
 
     /// Handles a hit from the player.
     ///
-    /// This is the core mechanic for progressive awakening. Each hit advances
-    /// the entity one frame closer to being fully awake.
+    /// This is the core mechanic for progressive awakening. Each hit brings
+    /// the entity one hit closer to its definition's `hits_to_awaken`.
     ///
     /// # State-Dependent Behavior
     ///
-    /// - **Dormant**: Start awakening at frame 2
-    /// - **Awakening/ReversingToSleep**: Advance to next frame, switch to Awakening state
+    /// - **Dormant/Awakening/ReversingToSleep**: Add a hit, switch to Awakening state
+    ///   (or Awake on the last hit)
     /// - **Awake**: Reset inactivity timer (prevents timeout)
-    /// - **ReturningToDormant**: Interrupt return, restart awakening from current frame
+    /// - **ReturningToDormant**: Interrupt return, restart awakening from current progress
     ///
     /// # Design Pattern: State Machine with Match
     ///
@@ -191,32 +244,26 @@ impl<'a> TheEntity<'a> {
     /// will error if we forget a state!
     pub fn on_hit(&mut self) {
         match self.state {
-            EntityState::Dormant => {
-                // Start awakening at frame 2 (first visible change)
-                self.state = EntityState::Awakening;
-                self.awakening_frame = 2;
-                self.last_hit_time = Instant::now();
-                self.update_sprite_frame();
-            }
-            EntityState::Awakening | EntityState::ReversingToSleep => {
-                // Advance one frame toward full awakening
-                self.awakening_frame += 1;
+            EntityState::Dormant | EntityState::Awakening | EntityState::ReversingToSleep => {
+                // Advance one hit toward full awakening
+                self.hits += 1;
                 self.last_hit_time = Instant::now();
                 self.state = EntityState::Awakening;
                 self.reverse_timer = 0.0; // Reset reverse timer
 
-                if self.awakening_frame >= 8 {
+                if self.hits >= self.definition.hits_to_awaken {
                     // Fully awakened! Transition to awake state
-                    self.awakening_frame = 8; // Clamp to max
+                    self.hits = self.definition.hits_to_awaken; // Clamp to max
                     self.state = EntityState::Awake;
                     self.inactivity_timer = 0.0;
+                    self.awakened = true;
 
                     // Start the awake looping animation (frames 8-12, manually controlled)
                     self.awake_animation_frame = 8;  // Start at frame 8
                     self.awake_animation_timer = 0.0;
-                    self.update_sprite_frame();
                 }
 
+                self.awakening_frame = awakening_frame_for(self.hits, self.definition.hits_to_awaken);
                 self.update_sprite_frame();
             }
             EntityState::Awake => {
@@ -224,7 +271,7 @@ impl<'a> TheEntity<'a> {
                 self.inactivity_timer = 0.0;
             }
             EntityState::ReturningToDormant => {
-                // Interrupt the return, restart awakening from current frame
+                // Interrupt the return, restart awakening from current progress
                 self.state = EntityState::Awakening;
                 self.last_hit_time = Instant::now();
                 self.reverse_timer = 0.0;
@@ -243,10 +290,10 @@ impl<'a> TheEntity<'a> {
     ///
     /// # State-Dependent Behavior
     ///
-    /// - **Awakening**: Check for 2s timeout → transition to ReversingToSleep
-    /// - **ReversingToSleep**: Reverse 1 frame every 2 seconds → Dormant when frame = 1
-    /// - **Awake**: Check for 30s timeout → transition to ReturningToDormant
-    /// - **ReturningToDormant**: Reverse 1 frame per second → Dormant when frame = 1
+    /// - **Awakening**: Check for 1s timeout → transition to ReversingToSleep
+    /// - **ReversingToSleep**: Lose hits at `reverse_speed` → Dormant when none are left
+    /// - **Awake**: Check for `awake_duration` timeout → transition to ReturningToDormant
+    /// - **ReturningToDormant**: Lose hits at `reverse_speed` → Dormant when none are left
     /// - **Dormant**: No updates needed
     ///
     /// # Rust Learning: Pattern Matching
@@ -263,32 +310,27 @@ impl<'a> TheEntity<'a> {
                     self.sprite_sheet.pause(); // Pause auto-animation
                 }
             }
-            EntityState::ReversingToSleep => {
-                // Reverse 1 frame every 1 second (fast reverse when you stop hitting)
+            EntityState::ReversingToSleep | EntityState::ReturningToDormant => {
+                // Lose one hit of progress every 1/reverse_speed seconds
                 self.reverse_timer += delta_time;
-                if self.reverse_timer >= 1.0 {
-                    self.reverse_timer = 0.0;
-
-                    // Use saturating_sub to prevent underflow (won't panic if already at 0)
-                    self.awakening_frame = self.awakening_frame.saturating_sub(1);
-                    self.update_sprite_frame();
+                let step = 1.0 / self.definition.reverse_speed;
+                while self.reverse_timer >= step && self.hits > 0 {
+                    self.reverse_timer -= step;
+                    self.hits -= 1;
+                }
+                self.awakening_frame = awakening_frame_for(self.hits, self.definition.hits_to_awaken);
 
-                    if self.awakening_frame <= 1 {
-                        // Fully reversed - back to dormant
-                        self.state = EntityState::Dormant;
-                        self.awakening_frame = 1;
-                        self.update_sprite_frame();
-                    }
+                if self.hits == 0 {
+                    // Fully reversed - back to dormant
+                    self.state = EntityState::Dormant;
+                    self.reverse_timer = 0.0;
                 }
+                self.update_sprite_frame();
             }
             EntityState::Awake => {
-                // 30 second timeout
                 self.inactivity_timer += delta_time;
-                if self.inactivity_timer >= 30.0 {
-                    self.state = EntityState::ReturningToDormant;
-                    self.awakening_frame = 8; // Start from frame 8
-                    self.reverse_timer = 0.0;
-                    self.sprite_sheet.pause(); // Stop loop animation
+                if self.inactivity_timer >= self.definition.awake_duration {
+                    self.return_to_dormant();
                 } else {
                     // Manually cycle through awake frames 8-12 (0.2 seconds per frame)
                     self.awake_animation_timer += delta_time;
@@ -303,23 +345,6 @@ impl<'a> TheEntity<'a> {
                     }
                 }
             }
-            EntityState::ReturningToDormant => {
-                // Reverse faster: 1 frame per second
-                self.reverse_timer += delta_time;
-                if self.reverse_timer >= 1.0 {
-                    self.reverse_timer = 0.0;
-
-                    self.awakening_frame = self.awakening_frame.saturating_sub(1);
-                    self.update_sprite_frame();
-
-                    if self.awakening_frame <= 1 {
-                        // Fully returned to dormant
-                        self.state = EntityState::Dormant;
-                        self.awakening_frame = 1;
-                        self.update_sprite_frame();
-                    }
-                }
-            }
             EntityState::Dormant => {
                 // No updates needed in dormant state
             }
@@ -404,6 +429,46 @@ impl<'a> TheEntity<'a> {
             None
         }
     }
+
+    /// Starts an awake entity winding back down to dormant.
+    ///
+    /// This is what the awake duration timeout does; summoning the boss
+    /// also uses it to drain the pyramids. Does nothing in other states.
+    pub fn return_to_dormant(&mut self) {
+        if self.state == EntityState::Awake {
+            self.state = EntityState::ReturningToDormant;
+            self.hits = self.definition.hits_to_awaken; // Start from frame 8
+            self.awakening_frame = 8;
+            self.reverse_timer = 0.0;
+            self.sprite_sheet.pause(); // Stop loop animation
+            self.update_sprite_frame();
+        }
+    }
+
+    /// Restores saved progress and shows the matching frame
+    ///
+    /// Saves from before hit counts were stored only have the frame, so the
+    /// hits are worked out from it when `hits` is None.
+    pub fn restore_progress(&mut self, state: EntityState, awakening_frame: usize, hits: Option<u32>, inactivity_timer: f32) {
+        let hits_to_awaken = self.definition.hits_to_awaken;
+        self.state = state;
+        self.hits = match (state, hits) {
+            (EntityState::Dormant, _) => 0,
+            (EntityState::Awake, _) => hits_to_awaken,
+            (_, Some(hits)) => hits.min(hits_to_awaken),
+            (_, None) => (1..=hits_to_awaken)
+                .find(|hits| awakening_frame_for(*hits, hits_to_awaken) >= awakening_frame)
+                .unwrap_or(hits_to_awaken),
+        };
+        self.awakening_frame = awakening_frame_for(self.hits, hits_to_awaken);
+        self.inactivity_timer = inactivity_timer;
+        self.update_sprite_frame();
+    }
+
+    /// True once after each time it fully wakes (linked groups count these)
+    pub fn take_awakened(&mut self) -> bool {
+        std::mem::take(&mut self.awakened)
+    }
 }
 
 /// Implementation of StaticCollidable for collision detection.
@@ -498,7 +563,9 @@ impl DepthSortable for TheEntity<'_> {
 /// - `x, y`: World position
 /// - `state`: Current EntityState enum value
 /// - `awakening_frame`: Current frame in awakening sequence (1-8)
+/// - `hits`: Awakening progress (the frame follows from it)
 /// - `inactivity_timer`: Time accumulated for timeout checks
+/// - `link`: Its place in a linked group, if any
 ///
 /// # Loading Process
 ///
@@ -514,8 +581,10 @@ impl Saveable for TheEntity<'_> {
             y: i32,
             state: EntityState,
             awakening_frame: usize,
+            hits: u32,
             inactivity_timer: f32,
             entity_type: EntityType,
+            link: Option<PyramidLink>,
         }
 
         let data = EntitySaveData {
@@ -524,8 +593,10 @@ impl Saveable for TheEntity<'_> {
             y: self.y,
             state: self.state,
             awakening_frame: self.awakening_frame,
+            hits: self.hits,
             inactivity_timer: self.inactivity_timer,
             entity_type: self.entity_type,
+            link: self.link.clone(),
         };
 
         Ok(SaveData {
@@ -578,6 +649,29 @@ mod tests {
         assert_eq!(fully_awake_frame, 8); // Spec requirement
     }
 
+    #[test]
+    fn test_awakening_frames_follow_hits() {
+        // The original seven hits step through every frame
+        let frames: Vec<usize> = (0..=7).map(|hits| awakening_frame_for(hits, 7)).collect();
+        assert_eq!(frames, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+
+        // Tougher pyramids only show frame 8 on the last hit
+        assert_eq!(awakening_frame_for(1, 20), 2);
+        assert_eq!(awakening_frame_for(19, 20), 7);
+        assert_eq!(awakening_frame_for(20, 20), 8);
+
+        // A single hit wakes a one-hit pyramid
+        assert_eq!(awakening_frame_for(1, 1), 8);
+        assert_eq!(awakening_frame_for(3, 2), 8);
+    }
+
+    #[test]
+    fn test_entity_types_index_all() {
+        for (index, entity_type) in EntityType::ALL.iter().enumerate() {
+            assert_eq!(entity_type.index(), index);
+        }
+    }
+
     #[test]
     fn test_collision_bounds_calculation() {
         // Test the math for collision bounds
@@ -309,6 +309,18 @@ pub fn get_render_tile_neighbors(
 // ==============================================================================
 
 impl TileId {
+    /// Every tile type, in the order the map editor cycles through them
+    pub fn all() -> [TileId; 2] {
+        [TileId::Grass, TileId::Dirt]
+    }
+
+    /// Whether enemies can path across this tile (see pathfinding.rs)
+    pub fn is_walkable(&self) -> bool {
+        match self {
+            TileId::Grass | TileId::Dirt => true,
+        }
+    }
+
     /// Convert TileId to string for serialization
     pub fn to_string(&self) -> String {
         match self {
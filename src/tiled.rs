@@ -0,0 +1,583 @@
+//! Tiled map import (JSON `.tmj` format)
+//!
+//! Converts maps made in the [Tiled](https://www.mapeditor.org/) editor into the
+//! game's own `MapFile`, which `Zone::apply_map` then turns into tiles, pyramids,
+//! static objects and spawn points.
+//!
+//! Only the JSON format is supported. In Tiled, use *File > Export As* with the
+//! "JSON map files (*.tmj)" type and keep the tile layer format at CSV.
+//!
+//! # Tile Layers
+//!
+//! Every visible tile layer is read in order; later layers override earlier ones
+//! wherever they have a tile. Each Tiled tile is mapped to a `TileId` by:
+//! 1. An explicit `TileMapping` entry for (tileset name, local tile ID), or
+//! 2. A string custom property named `tile` on the tile in the tileset
+//!    (e.g. `tile = "dirt"`)
+//!
+//! # Object Layers
+//!
+//! Objects are recognised by their class (`type` in Tiled before 1.9):
+//!
+//! | Class          | Becomes                 | Required properties       |
+//! |----------------|-------------------------|---------------------------|
+//! | `pyramid`      | `TheEntity` pyramid     | `entity_type` (e.g. "Speed") |
+//! | `solid`        | Visible `StaticObject`  | -                         |
+//! | `slime_spawn`  | Slime spawn point       | -                         |
+//! | `player_spawn` | New-game player start   | - (at most one per map)   |
+//!
+//! Point objects use their position; rectangles use their top-left corner for
+//! pyramids and solids and their center for spawns.
+//!
+//! Maps drawn with a tile size other than 32px are scaled to the game's grid.
+//!
+//! # Errors
+//!
+//! Problems are collected rather than stopping at the first one, so a level
+//! designer sees everything that needs fixing in a single error message.
+
+use crate::map::{MapFile, MapObject, MapPyramid, SpawnPoint};
+use crate::the_entity::EntityType;
+use crate::tile::TileId;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Size of a game tile in pixels (Tiled coordinates are scaled to this)
+const TILE_SIZE: f32 = 32.0;
+
+/// Tiled stores flip/rotation flags in the top bits of each GID
+const GID_FLAG_MASK: u32 = 0xF000_0000;
+
+/// Explicit tileset tile -> TileId mapping
+///
+/// Keys are (tileset name, local tile ID). External tilesets are named after
+/// their file stem (`tilesets/terrain.tsj` -> "terrain").
+#[derive(Debug, Clone, Default)]
+pub struct TileMapping {
+    entries: HashMap<(String, u32), TileId>,
+}
+
+impl TileMapping {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mapping for the game's terrain tileset: tile 0 is grass, tile 1 is dirt
+    pub fn standard() -> Self {
+        Self::new()
+            .with_tile("terrain", 0, TileId::Grass)
+            .with_tile("terrain", 1, TileId::Dirt)
+    }
+
+    /// Builder-style: map a tile of a tileset to a TileId
+    pub fn with_tile(mut self, tileset: &str, local_id: u32, tile: TileId) -> Self {
+        self.entries.insert((tileset.to_string(), local_id), tile);
+        self
+    }
+
+    fn get(&self, tileset: &str, local_id: u32) -> Option<TileId> {
+        self.entries.get(&(tileset.to_string(), local_id)).copied()
+    }
+}
+
+// ==============================================================================
+// Tiled JSON structures (only the fields the importer needs)
+// ==============================================================================
+
+#[derive(Debug, Deserialize)]
+struct TiledMap {
+    width: usize,
+    height: usize,
+    tilewidth: u32,
+    tileheight: u32,
+    #[serde(default)]
+    orientation: String,
+    #[serde(default)]
+    infinite: bool,
+    #[serde(default)]
+    layers: Vec<TiledLayer>,
+    #[serde(default)]
+    tilesets: Vec<TiledTileset>,
+    #[serde(default)]
+    properties: Vec<TiledProperty>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TiledLayer {
+    #[serde(rename = "type")]
+    layer_type: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default = "default_visible")]
+    visible: bool,
+    /// CSV tile data is a JSON array; base64 data is a string (unsupported)
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+    #[serde(default)]
+    objects: Vec<TiledObject>,
+    /// Child layers of a group layer
+    #[serde(default)]
+    layers: Vec<TiledLayer>,
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+struct TiledObject {
+    id: u32,
+    /// Object class before Tiled 1.9
+    #[serde(default, rename = "type")]
+    object_type: String,
+    /// Object class since Tiled 1.9
+    #[serde(default)]
+    class: String,
+    x: f32,
+    y: f32,
+    #[serde(default)]
+    width: f32,
+    #[serde(default)]
+    height: f32,
+    #[serde(default)]
+    point: bool,
+    #[serde(default)]
+    properties: Vec<TiledProperty>,
+}
+
+impl TiledObject {
+    fn class(&self) -> &str {
+        if self.class.is_empty() { &self.object_type } else { &self.class }
+    }
+
+    fn string_property(&self, name: &str) -> Option<&str> {
+        find_string_property(&self.properties, name)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TiledTileset {
+    firstgid: u32,
+    #[serde(default)]
+    name: String,
+    /// Path of an external tileset (.tsj/.tsx); its tiles aren't embedded
+    #[serde(default)]
+    source: Option<String>,
+    #[serde(default)]
+    tiles: Vec<TiledTile>,
+}
+
+impl TiledTileset {
+    /// Embedded tilesets use their name, external ones their file stem
+    fn display_name(&self) -> String {
+        match &self.source {
+            Some(source) if self.name.is_empty() => Path::new(source)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            _ => self.name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TiledTile {
+    id: u32,
+    #[serde(default)]
+    properties: Vec<TiledProperty>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TiledProperty {
+    name: String,
+    value: serde_json::Value,
+}
+
+fn find_string_property<'p>(properties: &'p [TiledProperty], name: &str) -> Option<&'p str> {
+    properties.iter()
+        .find(|property| property.name == name)
+        .and_then(|property| property.value.as_str())
+}
+
+// ==============================================================================
+// Import
+// ==============================================================================
+
+/// Reads a Tiled JSON map from disk and converts it
+///
+/// The map's name is its `name` custom property, or the file stem.
+pub fn load_tiled_map(path: impl AsRef<Path>, mapping: &TileMapping) -> Result<MapFile, String> {
+    let path = path.as_ref();
+    if path.extension().is_some_and(|ext| ext == "tmx") {
+        return Err(format!("{}: TMX (XML) maps are not supported, export the map as JSON (.tmj)", path.display()));
+    }
+
+    let json = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read Tiled map {}: {}", path.display(), e))?;
+    let default_name = path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    import_tiled_map(&json, &default_name, mapping)
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Converts Tiled JSON into a MapFile
+pub fn import_tiled_map(json: &str, default_name: &str, mapping: &TileMapping) -> Result<MapFile, String> {
+    let tiled: TiledMap = serde_json::from_str(json)
+        .map_err(|e| format!("not a valid Tiled JSON map: {}", e))?;
+
+    // Structural problems make the rest meaningless, so they fail immediately
+    if !tiled.orientation.is_empty() && tiled.orientation != "orthogonal" {
+        return Err(format!("only orthogonal maps are supported (found '{}')", tiled.orientation));
+    }
+    if tiled.infinite {
+        return Err("infinite maps are not supported, disable 'Infinite' in the map properties".to_string());
+    }
+    if tiled.tilewidth == 0 || tiled.tilewidth != tiled.tileheight {
+        return Err(format!("tiles must be square (found {}x{})", tiled.tilewidth, tiled.tileheight));
+    }
+    if tiled.width == 0 || tiled.height == 0 {
+        return Err("map must be at least 1x1 tiles".to_string());
+    }
+
+    let name = find_string_property(&tiled.properties, "name").unwrap_or(default_name);
+    let mut map_file = MapFile::new(name, tiled.width, tiled.height, TileId::Grass);
+    let mut errors = Vec::new();
+
+    let mut layers = Vec::new();
+    collect_visible_layers(&tiled.layers, &mut layers);
+
+    // Tile layers
+    let mut grid: Vec<Option<TileId>> = vec![None; tiled.width * tiled.height];
+    let mut unknown_tiles: Vec<UnknownTile> = Vec::new();
+    let mut tile_layer_count = 0;
+
+    for layer in layers.iter().filter(|layer| layer.layer_type == "tilelayer") {
+        tile_layer_count += 1;
+        let data = match layer_tile_data(layer) {
+            Ok(data) => data,
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
+        if data.len() != tiled.width * tiled.height {
+            errors.push(format!(
+                "layer '{}' has {} tiles, expected {} ({}x{})",
+                layer.name, data.len(), tiled.width * tiled.height, tiled.width, tiled.height
+            ));
+            continue;
+        }
+
+        for (index, raw_gid) in data.into_iter().enumerate() {
+            let gid = raw_gid & !GID_FLAG_MASK;
+            if gid == 0 {
+                continue; // Empty cell, lower layers show through
+            }
+            match resolve_gid(gid, &tiled.tilesets, mapping) {
+                Ok(tile) => grid[index] = Some(tile),
+                Err(reason) => {
+                    // Report each unknown GID once, at its first position
+                    if let Some(unknown) = unknown_tiles.iter_mut().find(|unknown| unknown.gid == gid) {
+                        unknown.count += 1;
+                    } else {
+                        unknown_tiles.push(UnknownTile {
+                            gid,
+                            reason,
+                            layer: layer.name.clone(),
+                            x: index % tiled.width,
+                            y: index / tiled.width,
+                            count: 1,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    for unknown in unknown_tiles {
+        let more = if unknown.count > 1 { format!(" (and {} more)", unknown.count - 1) } else { String::new() };
+        errors.push(format!(
+            "layer '{}': unknown tile ID {} at ({}, {}){}: {}",
+            unknown.layer, unknown.gid, unknown.x, unknown.y, more, unknown.reason
+        ));
+    }
+
+    if tile_layer_count == 0 {
+        errors.push("map has no visible tile layers".to_string());
+    } else if let Some(index) = grid.iter().position(|tile| tile.is_none()) {
+        let empty_count = grid.iter().filter(|tile| tile.is_none()).count();
+        // Only report holes when every tile resolved; otherwise they're just the unknown tiles again
+        if errors.is_empty() {
+            errors.push(format!(
+                "{} cell(s) have no tile in any layer (first at ({}, {}))",
+                empty_count, index % tiled.width, index / tiled.width
+            ));
+        }
+    }
+
+    if errors.is_empty() {
+        map_file.tiles = grid.chunks(tiled.width)
+            .map(|row| row.iter().map(|tile| tile.unwrap_or(TileId::Grass).to_string()).collect())
+            .collect();
+    }
+
+    // Object layers
+    let scale = TILE_SIZE / tiled.tilewidth as f32;
+    for layer in layers.iter().filter(|layer| layer.layer_type == "objectgroup") {
+        for object in &layer.objects {
+            if let Err(e) = import_object(object, scale, &mut map_file) {
+                errors.push(format!("layer '{}', object {}: {}", layer.name, object.id, e));
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        let mut message = format!("Tiled map '{}' has {} problem(s):", name, errors.len());
+        for error in errors {
+            message.push_str("\n  - ");
+            message.push_str(&error);
+        }
+        return Err(message);
+    }
+
+    map_file.validate()?;
+    Ok(map_file)
+}
+
+/// First occurrence of a tile that couldn't be mapped to a TileId
+struct UnknownTile {
+    gid: u32,
+    reason: String,
+    layer: String,
+    x: usize,
+    y: usize,
+    count: usize,
+}
+
+/// Flattens group layers, skipping hidden layers (and everything inside hidden groups)
+fn collect_visible_layers<'l>(layers: &'l [TiledLayer], out: &mut Vec<&'l TiledLayer>) {
+    for layer in layers.iter().filter(|layer| layer.visible) {
+        if layer.layer_type == "group" {
+            collect_visible_layers(&layer.layers, out);
+        } else {
+            out.push(layer);
+        }
+    }
+}
+
+/// Reads a tile layer's CSV data
+fn layer_tile_data(layer: &TiledLayer) -> Result<Vec<u32>, String> {
+    match &layer.data {
+        Some(serde_json::Value::Array(values)) => values.iter()
+            .map(|value| value.as_u64().map(|gid| gid as u32)
+                .ok_or_else(|| format!("layer '{}' has a non-numeric tile ({})", layer.name, value)))
+            .collect(),
+        Some(serde_json::Value::String(_)) => Err(format!(
+            "layer '{}' uses base64 tile data, set the tile layer format to CSV", layer.name
+        )),
+        _ => Err(format!("layer '{}' has no tile data", layer.name)),
+    }
+}
+
+/// Maps a global tile ID to a TileId through its tileset
+fn resolve_gid(gid: u32, tilesets: &[TiledTileset], mapping: &TileMapping) -> Result<TileId, String> {
+    // The owning tileset is the one with the highest firstgid <= gid
+    let tileset = tilesets.iter()
+        .filter(|tileset| tileset.firstgid <= gid)
+        .max_by_key(|tileset| tileset.firstgid)
+        .ok_or_else(|| "no tileset contains it".to_string())?;
+
+    let local_id = gid - tileset.firstgid;
+    let tileset_name = tileset.display_name();
+
+    if let Some(tile) = mapping.get(&tileset_name, local_id) {
+        return Ok(tile);
+    }
+
+    let tile_name = tileset.tiles.iter()
+        .find(|tile| tile.id == local_id)
+        .and_then(|tile| find_string_property(&tile.properties, "tile"));
+
+    match tile_name {
+        Some(tile_name) => TileId::from_string(tile_name).ok_or_else(|| format!(
+            "tile {} of tileset '{}' has unknown 'tile' property \"{}\"", local_id, tileset_name, tile_name
+        )),
+        None => Err(format!(
+            "tile {} of tileset '{}' has no 'tile' property and no mapping", local_id, tileset_name
+        )),
+    }
+}
+
+/// Converts one object into a pyramid, solid, spawn point or player spawn
+fn import_object(object: &TiledObject, scale: f32, map_file: &mut MapFile) -> Result<(), String> {
+    let x = (object.x * scale).round() as i32;
+    let y = (object.y * scale).round() as i32;
+    let width = (object.width * scale).round() as i32;
+    let height = (object.height * scale).round() as i32;
+
+    // Spawns are placed at a point: the object itself or the rectangle's center
+    let spawn_point = if object.point {
+        SpawnPoint::new(x, y)
+    } else {
+        SpawnPoint::new(x + width / 2, y + height / 2)
+    };
+
+    match object.class() {
+        "pyramid" => {
+            let type_name = object.string_property("entity_type")
+                .ok_or("pyramid is missing the 'entity_type' property")?;
+            let entity_type = parse_entity_type(type_name).ok_or_else(|| format!(
+                "unknown entity_type \"{}\" (expected Attack, Defense, Speed, Regeneration, Crit, PickupRadius or XpGain)",
+                type_name
+            ))?;
+            map_file.pyramids.push(MapPyramid { x, y, entity_type, link: None });
+        }
+        "solid" => {
+            if object.point || width <= 0 || height <= 0 {
+                return Err("solid objects must be rectangles with a size".to_string());
+            }
+            map_file.static_objects.push(MapObject { x, y, width: width as u32, height: height as u32 });
+        }
+        "slime_spawn" => map_file.spawn_points.push(spawn_point),
+        "player_spawn" => {
+            if map_file.player_spawn.is_some() {
+                return Err("map has more than one player_spawn".to_string());
+            }
+            map_file.player_spawn = Some(spawn_point);
+        }
+        "" => return Err("object has no class (expected pyramid, solid, slime_spawn or player_spawn)".to_string()),
+        other => return Err(format!(
+            "unknown class \"{}\" (expected pyramid, solid, slime_spawn or player_spawn)", other
+        )),
+    }
+
+    Ok(())
+}
+
+/// Parses an EntityType name, ignoring case
+fn parse_entity_type(name: &str) -> Option<EntityType> {
+    match name.to_lowercase().as_str() {
+        "attack" => Some(EntityType::Attack),
+        "defense" => Some(EntityType::Defense),
+        "speed" => Some(EntityType::Speed),
+        "regeneration" => Some(EntityType::Regeneration),
+        "crit" => Some(EntityType::Crit),
+        "pickupradius" => Some(EntityType::PickupRadius),
+        "xpgain" => Some(EntityType::XpGain),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 3x2 map using an embedded tileset with `tile` properties
+    fn sample_map(objects: &str) -> String {
+        format!(r#"{{
+            "width": 3, "height": 2, "tilewidth": 16, "tileheight": 16,
+            "orientation": "orthogonal", "infinite": false,
+            "properties": [{{ "name": "name", "type": "string", "value": "Sample" }}],
+            "tilesets": [{{
+                "firstgid": 1, "name": "ground",
+                "tiles": [
+                    {{ "id": 0, "properties": [{{ "name": "tile", "type": "string", "value": "grass" }}] }},
+                    {{ "id": 1, "properties": [{{ "name": "tile", "type": "string", "value": "dirt" }}] }}
+                ]
+            }}],
+            "layers": [
+                {{ "type": "tilelayer", "name": "Ground", "width": 3, "height": 2, "data": [1, 1, 1, 1, 1, 1] }},
+                {{ "type": "tilelayer", "name": "Paths", "width": 3, "height": 2, "data": [0, 2, 0, 0, 2, 0] }},
+                {{ "type": "objectgroup", "name": "Objects", "objects": [{}] }}
+            ]
+        }}"#, objects)
+    }
+
+    #[test]
+    fn test_tile_layers_and_properties() {
+        let map = import_tiled_map(&sample_map(""), "fallback", &TileMapping::new()).unwrap();
+        assert_eq!(map.name, "Sample");
+        assert_eq!(map.tiles, vec![
+            vec!["grass", "dirt", "grass"],
+            vec!["grass", "dirt", "grass"],
+        ]);
+    }
+
+    #[test]
+    fn test_explicit_mapping_overrides_properties() {
+        let mapping = TileMapping::new().with_tile("ground", 0, TileId::Dirt);
+        let map = import_tiled_map(&sample_map(""), "fallback", &mapping).unwrap();
+        assert_eq!(map.tiles[0], vec!["dirt", "dirt", "dirt"]);
+    }
+
+    #[test]
+    fn test_objects_are_scaled_to_game_tiles() {
+        let objects = r#"
+            { "id": 1, "class": "pyramid", "x": 16, "y": 8, "width": 32, "height": 32,
+              "properties": [{ "name": "entity_type", "type": "string", "value": "speed" }] },
+            { "id": 2, "type": "solid", "x": 0, "y": 0, "width": 16, "height": 16 },
+            { "id": 3, "class": "slime_spawn", "x": 40, "y": 10, "point": true },
+            { "id": 4, "class": "player_spawn", "x": 0, "y": 0, "width": 16, "height": 16 }
+        "#;
+        let map = import_tiled_map(&sample_map(objects), "fallback", &TileMapping::new()).unwrap();
+
+        // 16px Tiled tiles -> 32px game tiles doubles every coordinate
+        assert_eq!(map.pyramids, vec![MapPyramid { x: 32, y: 16, entity_type: EntityType::Speed, link: None }]);
+        assert_eq!(map.static_objects, vec![MapObject { x: 0, y: 0, width: 32, height: 32 }]);
+        assert_eq!(map.spawn_points, vec![SpawnPoint::new(80, 20)]);
+        assert_eq!(map.player_spawn, Some(SpawnPoint::new(16, 16)));
+    }
+
+    #[test]
+    fn test_unknown_tile_is_reported_once_with_position() {
+        let json = sample_map("").replace("[0, 2, 0, 0, 2, 0]", "[0, 7, 0, 0, 7, 0]");
+        let error = import_tiled_map(&json, "fallback", &TileMapping::new()).unwrap_err();
+        assert!(error.contains("1 problem(s)"), "{}", error);
+        assert!(error.contains("layer 'Paths': unknown tile ID 7 at (1, 0) (and 1 more)"), "{}", error);
+        assert!(error.contains("tile 6 of tileset 'ground'"), "{}", error);
+    }
+
+    #[test]
+    fn test_object_errors_are_collected() {
+        let objects = r#"
+            { "id": 5, "class": "pyramid", "x": 0, "y": 0, "width": 32, "height": 32 },
+            { "id": 6, "class": "tree", "x": 0, "y": 0 },
+            { "id": 7, "class": "pyramid", "x": 0, "y": 0,
+              "properties": [{ "name": "entity_type", "type": "string", "value": "Luck" }] }
+        "#;
+        let error = import_tiled_map(&sample_map(objects), "fallback", &TileMapping::new()).unwrap_err();
+        assert!(error.contains("3 problem(s)"), "{}", error);
+        assert!(error.contains("object 5: pyramid is missing the 'entity_type' property"), "{}", error);
+        assert!(error.contains("object 6: unknown class \"tree\""), "{}", error);
+        assert!(error.contains("object 7: unknown entity_type \"Luck\""), "{}", error);
+    }
+
+    #[test]
+    fn test_empty_cells_and_unsupported_maps() {
+        let json = sample_map("").replace("[1, 1, 1, 1, 1, 1]", "[1, 1, 1, 1, 0, 0]");
+        let error = import_tiled_map(&json, "fallback", &TileMapping::new()).unwrap_err();
+        assert!(error.contains("1 cell(s) have no tile in any layer (first at (2, 1))"), "{}", error);
+
+        let json = sample_map("").replace(r#""infinite": false"#, r#""infinite": true"#);
+        assert!(import_tiled_map(&json, "fallback", &TileMapping::new()).unwrap_err().contains("infinite"));
+
+        let json = sample_map("").replace("[1, 1, 1, 1, 1, 1]", r#""AQAAAA==""#);
+        assert!(import_tiled_map(&json, "fallback", &TileMapping::new()).unwrap_err().contains("base64"));
+    }
+
+    #[test]
+    fn test_standard_mapping_with_external_tileset() {
+        let json = r#"{
+            "width": 2, "height": 1, "tilewidth": 32, "tileheight": 32,
+            "tilesets": [{ "firstgid": 1, "source": "tilesets/terrain.tsj" }],
+            "layers": [{ "type": "tilelayer", "name": "Ground", "data": [1, 2] }]
+        }"#;
+        let map = import_tiled_map(json, "cave", &TileMapping::standard()).unwrap();
+        assert_eq!(map.name, "cave");
+        assert_eq!(map.tiles, vec![vec!["grass", "dirt"]]);
+    }
+}
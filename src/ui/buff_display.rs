@@ -4,6 +4,14 @@
 //! are currently active on the player. It renders in a fixed position on screen
 //! (typically top-left corner).
 //!
+//! Pyramid auras come first, one icon per buff type (stats without a sprite
+//! get a plain gold square). Timed buffs follow, each with its own icon and a
+//! clockwise sweep darkening the time already used.
+//!
+//! Status effects (poison, burn, slow, stun) follow the buff icons. They have
+//! no sprites yet, so each is drawn as a square in the effect's color with a
+//! bar showing the time left and a pip per extra stack.
+//!
 //! # Example
 //!
 //! ```rust
@@ -13,13 +21,16 @@
 //! let texture_creator = canvas.texture_creator();
 //! let buff_display = BuffDisplay::new(&texture_creator).unwrap();
 //!
-//! // Render each frame with current active modifiers
-//! buff_display.render(&mut canvas, &player.active_modifiers, player.has_regen)?;
+//! // Render each frame with the player's current effects
+//! buff_display.render(&mut canvas, &player.active_effects, player.has_regen, &player.status_effects)?;
 //! ```
 
-use crate::stats::{ModifierEffect, StatType};
+use crate::buffs::{ActiveEffect, ActiveEffects};
+use crate::stats::StatType;
+use crate::status::{StatusEffect, StatusEffects};
 use sdl2::image::LoadTexture;
-use sdl2::rect::Rect;
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
 use sdl2::render::{Canvas, Texture, TextureCreator};
 use sdl2::video::{Window, WindowContext};
 
@@ -59,6 +70,18 @@ enum BuffType {
     Regen,
 }
 
+impl BuffType {
+    /// The icon for an effect, from the stat its first modifier changes
+    fn for_effect(effect: &ActiveEffect) -> Option<Self> {
+        match effect.modifiers.first()?.stat_type {
+            StatType::AttackDamage => Some(BuffType::Attack),
+            StatType::Defense | StatType::MagicResistance => Some(BuffType::Defense),
+            StatType::MovementSpeed => Some(BuffType::Speed),
+            _ => None,
+        }
+    }
+}
+
 /// Buff display component for showing active buffs
 ///
 /// This is a screen-space UI component that renders in a fixed position on screen.
@@ -88,49 +111,141 @@ impl<'a> BuffDisplay<'a> {
     //     Ok(BuffDisplay { style, texture })
     // }
 
-    /// Renders the buff display with current active modifiers and regeneration status.
+    /// Renders the buff display with the player's effects and regeneration status.
     ///
     /// # Parameters
     ///
     /// - `canvas`: SDL2 canvas to render to
-    /// - `active_modifiers`: Current active stat modifiers from player
+    /// - `active_effects`: The player's auras and timed buffs
     /// - `has_regen`: Whether regeneration is active
+    /// - `status_effects`: The player's status effects, drawn after the buffs
     pub fn render(
         &self,
         canvas: &mut Canvas<Window>,
-        active_modifiers: &[ModifierEffect],
+        active_effects: &ActiveEffects,
         has_regen: bool,
+        status_effects: &StatusEffects,
     ) -> Result<(), String> {
-        let mut active_buffs = Vec::new();
-
-        for modifier in active_modifiers {
-            match modifier.stat_type {
-                StatType::AttackDamage => active_buffs.push(BuffType::Attack),
-                StatType::Defense => active_buffs.push(BuffType::Defense),
-                StatType::MovementSpeed => active_buffs.push(BuffType::Speed),
-                _ => {}
-            }
-        }
+        let mut aura_buffs: Vec<BuffType> = active_effects.iter()
+            .filter(|effect| effect.aura)
+            .filter_map(BuffType::for_effect)
+            .collect();
 
         if has_regen {
-            active_buffs.push(BuffType::Regen);
-        }
-
-        if active_buffs.is_empty() {
-            return Ok(());
+            aura_buffs.push(BuffType::Regen);
         }
 
         // Sort and remove duplicates to ensure a consistent render order
-        active_buffs.sort_unstable();
-        active_buffs.dedup();
+        aura_buffs.sort_unstable();
+        aura_buffs.dedup();
 
-        // Render each active buff icon
+        // Render each active aura icon
         let mut current_x = self.style.x;
-        for buff_type in active_buffs {
+        for buff_type in aura_buffs {
             self.render_icon(canvas, current_x, self.style.y, buff_type)?;
             current_x += self.style.icon_size as i32 + self.style.icon_spacing;
         }
 
+        // Auras for stats without a sprite (crit, pickup radius, XP), one per stat
+        let mut plain_auras: Vec<&ActiveEffect> = Vec::new();
+        for effect in active_effects.iter().filter(|effect| effect.aura && BuffType::for_effect(effect).is_none()) {
+            let stat = effect.modifiers.first().map(|modifier| modifier.stat_type);
+            if stat.is_some() && !plain_auras.iter().any(|shown| shown.modifiers.first().map(|modifier| modifier.stat_type) == stat) {
+                plain_auras.push(effect);
+            }
+        }
+        for effect in plain_auras {
+            self.render_effect_icon(canvas, current_x, self.style.y, effect)?;
+            current_x += self.style.icon_size as i32 + self.style.icon_spacing;
+        }
+
+        // Timed and permanent buffs, in the order they were applied
+        for effect in active_effects.iter().filter(|effect| !effect.aura) {
+            self.render_effect_icon(canvas, current_x, self.style.y, effect)?;
+            current_x += self.style.icon_size as i32 + self.style.icon_spacing;
+        }
+
+        // Status effects, in a consistent order
+        let mut effects: Vec<&StatusEffect> = status_effects.iter().collect();
+        effects.sort_by_key(|effect| effect.kind);
+        for effect in effects {
+            self.render_status_icon(canvas, current_x, self.style.y, effect)?;
+            current_x += self.style.icon_size as i32 + self.style.icon_spacing;
+        }
+
+        Ok(())
+    }
+
+    /// Draws a status effect icon: colored square, time-left bar and stack pips
+    fn render_status_icon(
+        &self,
+        canvas: &mut Canvas<Window>,
+        x: i32,
+        y: i32,
+        effect: &StatusEffect,
+    ) -> Result<(), String> {
+        let size = self.style.icon_size;
+        let color = effect.kind.color();
+        let dark = Color::RGB(color.r / 3, color.g / 3, color.b / 3);
+
+        let icon = Rect::new(x, y, size, size);
+        canvas.set_draw_color(dark);
+        canvas.fill_rect(icon)?;
+        canvas.set_draw_color(color);
+        canvas.fill_rect(Rect::new(x + 4, y + 4, size - 8, size - 12))?;
+        canvas.draw_rect(icon)?;
+
+        // Time left shrinks from the full width
+        let bar_width = ((size - 4) as f32 * effect.fraction_remaining()).round() as u32;
+        if bar_width > 0 {
+            canvas.set_draw_color(Color::RGB(240, 240, 240));
+            canvas.fill_rect(Rect::new(x + 2, y + size as i32 - 5, bar_width, 3))?;
+        }
+
+        // One pip per stack beyond the first
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        for stack in 1..effect.stacks.min(5) as i32 {
+            canvas.fill_rect(Rect::new(x + 2 + (stack - 1) * 5, y + 2, 3, 3))?;
+        }
+
+        Ok(())
+    }
+
+    /// Draws a buff's icon with a sweep over the time used and a pip per extra stack
+    ///
+    /// Buffs for stats without a sprite get a plain gold square.
+    fn render_effect_icon(
+        &self,
+        canvas: &mut Canvas<Window>,
+        x: i32,
+        y: i32,
+        effect: &ActiveEffect,
+    ) -> Result<(), String> {
+        let size = self.style.icon_size;
+        match BuffType::for_effect(effect) {
+            Some(buff_type) => self.render_icon(canvas, x, y, buff_type)?,
+            None => {
+                canvas.set_draw_color(Color::RGB(200, 170, 60));
+                canvas.fill_rect(Rect::new(x + 4, y + 4, size - 8, size - 8))?;
+            }
+        }
+
+        if let Some(fraction_remaining) = effect.fraction_remaining() {
+            let points: Vec<Point> = sweep_points(size, 1.0 - fraction_remaining)
+                .into_iter()
+                .map(|(dx, dy)| Point::new(x + dx, y + dy))
+                .collect();
+            canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
+            canvas.set_draw_color(Color::RGBA(0, 0, 0, 150));
+            canvas.draw_points(points.as_slice())?;
+            canvas.set_blend_mode(sdl2::render::BlendMode::None);
+        }
+
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        for stack in 1..effect.stacks.min(5) as i32 {
+            canvas.fill_rect(Rect::new(x + 2 + (stack - 1) * 5, y + 2, 3, 3))?;
+        }
+
         Ok(())
     }
 
@@ -154,4 +269,42 @@ impl<'a> BuffDisplay<'a> {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Pixels (relative to the icon's corner) covered by a clockwise sweep from
+/// 12 o'clock over `fraction` of a `size`-pixel square icon
+fn sweep_points(size: u32, fraction: f32) -> Vec<(i32, i32)> {
+    if fraction <= 0.0 {
+        return Vec::new();
+    }
+    let center = size as f32 / 2.0;
+    let mut points = Vec::new();
+    for py in 0..size as i32 {
+        for px in 0..size as i32 {
+            let (dx, dy) = (px as f32 + 0.5 - center, py as f32 + 0.5 - center);
+            // Angle clockwise from straight up, as a fraction of a full turn
+            let turn = dx.atan2(-dy).rem_euclid(std::f32::consts::TAU) / std::f32::consts::TAU;
+            if turn < fraction {
+                points.push((px, py));
+            }
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sweep_covers_the_elapsed_fraction_clockwise() {
+        assert!(sweep_points(32, 0.0).is_empty());
+        assert_eq!(sweep_points(32, 1.0).len(), 32 * 32);
+
+        // A quarter sweep is the top-right quadrant
+        let quarter = sweep_points(32, 0.25);
+        assert_eq!(quarter.len(), 16 * 16);
+        assert!(quarter.contains(&(20, 4)));
+        assert!(!quarter.contains(&(4, 20)));
+    }
+}
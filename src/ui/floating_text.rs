@@ -33,6 +33,9 @@ pub struct FloatingTextStyle {
     /// We use procedural rendering, so this defines character size
     pub font_size: u32,
 
+    /// Character size for emphasized text (critical hits)
+    pub large_font_size: u32,
+
     /// Horizontal spacing between characters
     pub char_spacing: i32,
 
@@ -47,6 +50,7 @@ impl Default for FloatingTextStyle {
     fn default() -> Self {
         FloatingTextStyle {
             font_size: 12,
+            large_font_size: 18,
             char_spacing: 2,
             outline_offset: 1,
             outline_color: Color::RGB(0, 0, 0),
@@ -135,6 +139,33 @@ impl FloatingText {
         text: &str,
         color: Color,
         alpha: u8,
+    ) -> Result<(), String> {
+        self.render_sized(canvas, (x, y), text, color, alpha, self.style.font_size)
+    }
+
+    /// Renders floating text at the large font size (e.g. critical hit numbers)
+    ///
+    /// Same parameters as `render`.
+    pub fn render_large(
+        &self,
+        canvas: &mut Canvas<Window>,
+        x: i32,
+        y: i32,
+        text: &str,
+        color: Color,
+        alpha: u8,
+    ) -> Result<(), String> {
+        self.render_sized(canvas, (x, y), text, color, alpha, self.style.large_font_size)
+    }
+
+    fn render_sized(
+        &self,
+        canvas: &mut Canvas<Window>,
+        (x, y): (i32, i32),
+        text: &str,
+        color: Color,
+        alpha: u8,
+        font_size: u32,
     ) -> Result<(), String> {
         // Apply alpha to color
         let text_color = Color::RGBA(color.r, color.g, color.b, alpha);
@@ -146,13 +177,13 @@ impl FloatingText {
         );
 
         // Calculate total text width for centering
-        let total_width = (text.len() as i32 * (self.style.font_size as i32 + self.style.char_spacing))
+        let total_width = (text.len() as i32 * (font_size as i32 + self.style.char_spacing))
             - self.style.char_spacing;
         let start_x = x - (total_width / 2);
 
         // Render each character
         for (i, ch) in text.chars().enumerate() {
-            let char_x = start_x + (i as i32 * (self.style.font_size as i32 + self.style.char_spacing));
+            let char_x = start_x + (i as i32 * (font_size as i32 + self.style.char_spacing));
 
             // Draw outline first (shadow effect)
             self.render_char(
@@ -160,11 +191,12 @@ impl FloatingText {
                 ch,
                 char_x + self.style.outline_offset,
                 y + self.style.outline_offset,
+                font_size,
                 outline_color,
             )?;
 
             // Draw main character
-            self.render_char(canvas, ch, char_x, y, text_color)?;
+            self.render_char(canvas, ch, char_x, y, font_size, text_color)?;
         }
 
         Ok(())
@@ -173,18 +205,19 @@ impl FloatingText {
     /// Renders a single character using procedural graphics
     ///
     /// This is a simple bitmap-style renderer for common characters.
-    /// Supports: 0-9, +, -, !, and some letters
+    /// Supports: 0-9, +, -, !, ., and some letters
     fn render_char(
         &self,
         canvas: &mut Canvas<Window>,
         ch: char,
         x: i32,
         y: i32,
+        font_size: u32,
         color: Color,
     ) -> Result<(), String> {
         canvas.set_draw_color(color);
 
-        let size = self.style.font_size as i32;
+        let size = font_size as i32;
         let half = size / 2;
         let third = size / 3;
 
@@ -266,6 +299,10 @@ impl FloatingText {
                 // Horizontal bar
                 canvas.fill_rect(Rect::new(x, y + half - 1, size as u32, 2))?;
             }
+            '.' => {
+                // Dot on the baseline
+                canvas.fill_rect(Rect::new(x + half - 1, y + size - 2, 2, 2))?;
+            }
             '!' => {
                 // Vertical line with dot at bottom
                 canvas.fill_rect(Rect::new(x + half - 1, y, 2, (size - third) as u32))?;
@@ -279,6 +316,44 @@ impl FloatingText {
                 canvas.fill_rect(Rect::new(x, y, size as u32, 2))?;
                 canvas.fill_rect(Rect::new(x, y + half, size as u32, 2))?;
             }
+            'E' => {
+                canvas.fill_rect(Rect::new(x, y, 2, size as u32))?;
+                canvas.fill_rect(Rect::new(x, y, size as u32, 2))?;
+                canvas.fill_rect(Rect::new(x, y + half - 1, (size - third) as u32, 2))?;
+                canvas.fill_rect(Rect::new(x, y + size - 2, size as u32, 2))?;
+            }
+            'L' => {
+                canvas.fill_rect(Rect::new(x, y, 2, size as u32))?;
+                canvas.fill_rect(Rect::new(x, y + size - 2, size as u32, 2))?;
+            }
+            'P' => {
+                // Left side with a closed top half
+                canvas.fill_rect(Rect::new(x, y, 2, size as u32))?;
+                canvas.fill_rect(Rect::new(x, y, size as u32, 2))?;
+                canvas.fill_rect(Rect::new(x, y + half, size as u32, 2))?;
+                canvas.fill_rect(Rect::new(x + size - 2, y, 2, (half + 2) as u32))?;
+            }
+            'U' => {
+                canvas.fill_rect(Rect::new(x, y, 2, size as u32))?;
+                canvas.fill_rect(Rect::new(x + size - 2, y, 2, size as u32))?;
+                canvas.fill_rect(Rect::new(x, y + size - 2, size as u32, 2))?;
+            }
+            'V' => {
+                // Sides down two thirds, stepping in to a point
+                canvas.fill_rect(Rect::new(x, y, 2, (size - third) as u32))?;
+                canvas.fill_rect(Rect::new(x + size - 2, y, 2, (size - third) as u32))?;
+                canvas.fill_rect(Rect::new(x + 2, y + size - third, 2, (third / 2) as u32))?;
+                canvas.fill_rect(Rect::new(x + size - 4, y + size - third, 2, (third / 2) as u32))?;
+                canvas.fill_rect(Rect::new(x + half - 1, y + size - 2, 2, 2))?;
+            }
+            'X' => {
+                // Two diagonals drawn as steps
+                for step in (0..size).step_by(2) {
+                    canvas.fill_rect(Rect::new(x + step, y + step, 2, 2))?;
+                    canvas.fill_rect(Rect::new(x + size - 2 - step, y + step, 2, 2))?;
+                }
+            }
+            ' ' => {}
 
             // Default: small rectangle for unknown chars
             _ => {
@@ -290,6 +365,18 @@ impl FloatingText {
     }
 }
 
+/// Formats an amount for display: whole amounts as integers, anything else to one decimal
+///
+/// Rounding to one place keeps float noise (0.35000002) out of the numbers.
+pub fn format_amount(amount: f32) -> String {
+    let tenths = (amount * 10.0).round() / 10.0;
+    if tenths.fract() == 0.0 {
+        format!("{}", tenths as i32)
+    } else {
+        format!("{:.1}", tenths)
+    }
+}
+
 impl Default for FloatingText {
     fn default() -> Self {
         Self::new()
@@ -304,6 +391,7 @@ mod tests {
     fn test_default_floating_text() {
         let text = FloatingText::new();
         assert_eq!(text.style.font_size, 12);
+        assert!(text.style.large_font_size > text.style.font_size);
     }
 
     #[test]
@@ -315,4 +403,12 @@ mod tests {
         let text = FloatingText::with_style(style);
         assert_eq!(text.style.font_size, 20);
     }
+
+    #[test]
+    fn test_format_amount() {
+        assert_eq!(format_amount(3.0), "3");
+        assert_eq!(format_amount(0.35000002), "0.4");
+        assert_eq!(format_amount(0.375), "0.4");
+        assert_eq!(format_amount(1.96), "2");
+    }
 }
@@ -0,0 +1,491 @@
+//! Weapon profiles - how an attack looks and lands for each held weapon
+//!
+//! Every attack is built from the weapon in the selected hotbar slot: its
+//! hitbox shape, range, damage multiplier (on the AttackDamage stat), attack
+//! speed multiplier (on the AttackSpeed stat), knockback, stamina cost and the
+//! effect sprite drawn in front of the player. Bare fists are the fallback when the held
+//! item isn't a weapon.
+//!
+//! A weapon can also define a combo chain of up to three steps, each with its
+//! own effect animation, damage, hitbox and recovery (see `combo.rs`). Without
+//! one, every attack is the profile's single attack.
+//!
+//! Profiles live in assets/weapons/<id>.json and are loaded into the
+//! `WeaponRegistry` at startup. Items pick a profile by ID: tools through
+//! `ToolType::weapon_id` (the hoe, a sword), MainHand equipment by their own
+//! item ID.
+//!
+//! # Example (assets/weapons/hoe.json)
+//!
+//! ```json
+//! {
+//!   "id": "hoe",
+//!   "name": "Hoe",
+//!   "shape": { "type": "arc", "degrees": 100.0 },
+//!   "range": 36,
+//!   "damage_multiplier": 1.25,
+//!   "attack_speed_multiplier": 0.8,
+//!   "knockback": 260.0,
+//!   "stamina_cost": 1.0,
+//!   "effect": {
+//!     "sprite_path": "assets/sprites/new_player/punch_effect.png",
+//!     "animation_config": "assets/config/punch_effect.json",
+//!     "animation": "punch",
+//!     "offset": 24
+//!   },
+//!   "combo_window": 0.35,
+//!   "combo": [
+//!     { "recovery": 1.0 },
+//!     { "animation": "punch_heavy", "damage_multiplier": 1.5, "shape": { "type": "strip", "width": 14 }, "range": 52, "knockback": 320.0, "recovery": 2.0 }
+//!   ]
+//! }
+//! ```
+
+use crate::animation::{AnimationConfig, AnimationController, Direction};
+use crate::combat::{AttackEvent, HitboxShape};
+use crate::combo::MAX_COMBO_STEPS;
+use crate::item::definition::ItemDefinition;
+use crate::item::ItemProperties;
+use crate::item::properties::EquipmentSlot;
+use sdl2::render::Texture;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// The profile used when the held item isn't a weapon
+pub const FISTS_ID: &str = "fists";
+
+/// Everything about how a weapon attacks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeaponProfile {
+    /// Unique identifier (items refer to profiles by this)
+    pub id: String,
+
+    /// Display name
+    pub name: String,
+
+    /// Area the attack covers in front of the player
+    pub shape: HitboxShape,
+
+    /// How far the attack reaches, in pixels
+    pub range: i32,
+
+    /// Multiplies the AttackDamage stat
+    #[serde(default = "default_multiplier")]
+    pub damage_multiplier: f32,
+
+    /// Multiplies the AttackSpeed stat (attacks per second)
+    #[serde(default = "default_multiplier")]
+    pub attack_speed_multiplier: f32,
+
+    /// Knockback speed in the attack's direction (pixels per second)
+    #[serde(default)]
+    pub knockback: f32,
+
+    /// Stamina spent per attack (0 for free attacks)
+    #[serde(default)]
+    pub stamina_cost: f32,
+
+    /// Sprite played in front of the player
+    pub effect: AttackEffectSprite,
+
+    /// Seconds after a step's recovery in which attacking continues the combo
+    #[serde(default = "default_combo_window")]
+    pub combo_window: f32,
+
+    /// Combo chain, first step first; empty for a single repeated attack
+    #[serde(default)]
+    pub combo: Vec<ComboStep>,
+}
+
+/// One attack in a weapon's combo chain
+///
+/// Anything left out uses the profile's own value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComboStep {
+    /// Effect animation (defaults to the profile's effect animation)
+    #[serde(default)]
+    pub animation: Option<String>,
+
+    /// Multiplies the profile's damage
+    #[serde(default = "default_multiplier")]
+    pub damage_multiplier: f32,
+
+    /// Hitbox shape (defaults to the profile's shape)
+    #[serde(default)]
+    pub shape: Option<HitboxShape>,
+
+    /// Reach in pixels (defaults to the profile's range)
+    #[serde(default)]
+    pub range: Option<i32>,
+
+    /// Knockback speed in pixels per second (defaults to the profile's knockback)
+    #[serde(default)]
+    pub knockback: Option<f32>,
+
+    /// Multiplies the weapon's attack cooldown before anything else can be done
+    #[serde(default = "default_multiplier")]
+    pub recovery: f32,
+}
+
+/// The effect animation a weapon plays when it attacks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttackEffectSprite {
+    /// Sprite sheet texture
+    pub sprite_path: String,
+
+    /// AnimationConfig JSON describing the sprite sheet's frames
+    pub animation_config: String,
+
+    /// Which animation in the config plays
+    pub animation: String,
+
+    /// Frame size in unscaled pixels
+    #[serde(default = "default_frame_size")]
+    pub frame_size: u32,
+
+    /// How far in front of the player's center the effect is drawn (pixels)
+    #[serde(default = "default_effect_offset")]
+    pub offset: i32,
+}
+
+fn default_multiplier() -> f32 {
+    1.0
+}
+
+fn default_frame_size() -> u32 {
+    32
+}
+
+fn default_effect_offset() -> i32 {
+    20
+}
+
+fn default_combo_window() -> f32 {
+    0.4
+}
+
+fn validate_shape(id: &str, shape: HitboxShape) -> Result<(), String> {
+    match shape {
+        HitboxShape::Strip { width } if width <= 0 => {
+            Err(format!("'{}': strip width must be positive", id))
+        }
+        HitboxShape::Arc { degrees } if !(degrees > 0.0 && degrees <= 360.0) => {
+            Err(format!("'{}': arc degrees must be between 0 and 360", id))
+        }
+        _ => Ok(()),
+    }
+}
+
+impl WeaponProfile {
+    /// Checks the values make sense (called when registering)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() {
+            return Err("weapon id must not be empty".to_string());
+        }
+        if self.range <= 0 {
+            return Err(format!("'{}': range must be positive", self.id));
+        }
+        if self.damage_multiplier < 0.0 || self.attack_speed_multiplier <= 0.0 || self.knockback < 0.0 || self.stamina_cost < 0.0 {
+            return Err(format!(
+                "'{}': damage_multiplier, knockback and stamina_cost must not be negative, attack_speed_multiplier must be positive",
+                self.id
+            ));
+        }
+        if self.combo.len() > MAX_COMBO_STEPS || self.combo_window < 0.0 {
+            return Err(format!(
+                "'{}': combo can have at most {} steps and combo_window must not be negative",
+                self.id, MAX_COMBO_STEPS
+            ));
+        }
+        for step in &self.combo {
+            if step.damage_multiplier < 0.0 || step.recovery <= 0.0 || step.range.is_some_and(|range| range <= 0) || step.knockback.is_some_and(|knockback| knockback < 0.0) {
+                return Err(format!(
+                    "'{}': combo steps need a positive range and recovery, and no negative damage_multiplier or knockback",
+                    self.id
+                ));
+            }
+            if let Some(shape) = step.shape {
+                validate_shape(&self.id, shape)?;
+            }
+        }
+        validate_shape(&self.id, self.shape)
+    }
+
+    /// Number of attacks in the combo chain (1 for a weapon without one)
+    pub fn combo_len(&self) -> usize {
+        self.combo.len().max(1)
+    }
+
+    /// Builds combo `step`'s attack from the player's stats
+    ///
+    /// `attack_damage` is the effective AttackDamage stat; the caller adds the
+    /// damage roll and any status effect.
+    pub fn attack_event(&self, step: usize, attack_damage: f32, position: (i32, i32), direction: Direction) -> AttackEvent {
+        let combo_step = self.combo.get(step);
+        let damage = attack_damage * self.damage_multiplier * combo_step.map_or(1.0, |s| s.damage_multiplier);
+        let range = combo_step.and_then(|s| s.range).unwrap_or(self.range);
+
+        AttackEvent::new(damage, position, direction, range)
+            .with_shape(combo_step.and_then(|s| s.shape).unwrap_or(self.shape))
+            .with_knockback(combo_step.and_then(|s| s.knockback).unwrap_or(self.knockback))
+            .with_combo_step(step)
+    }
+
+    /// Effect animation for combo `step`
+    pub fn step_animation(&self, step: usize) -> &str {
+        self.combo.get(step)
+            .and_then(|s| s.animation.as_deref())
+            .unwrap_or(&self.effect.animation)
+    }
+
+    /// Seconds of recovery after combo `step` for an AttackSpeed stat
+    pub fn step_recovery(&self, step: usize, attack_speed: f32) -> f32 {
+        self.attack_cooldown(attack_speed) * self.combo.get(step).map_or(1.0, |s| s.recovery)
+    }
+
+    /// Seconds between attacks for an AttackSpeed stat (attacks per second)
+    pub fn attack_cooldown(&self, attack_speed: f32) -> f32 {
+        1.0 / (attack_speed * self.attack_speed_multiplier)
+    }
+}
+
+/// Central registry of weapon profiles (like EnemyRegistry for enemies)
+pub struct WeaponRegistry {
+    profiles: HashMap<String, WeaponProfile>,
+    /// Effect animation configs keyed by profile ID
+    effect_configs: HashMap<String, AnimationConfig>,
+}
+
+impl WeaponRegistry {
+    pub fn new() -> Self {
+        WeaponRegistry {
+            profiles: HashMap::new(),
+            effect_configs: HashMap::new(),
+        }
+    }
+
+    /// Loads every `*.json` profile in a directory
+    ///
+    /// Fails on the first invalid file (naming it), or if there is no fists profile.
+    pub fn load_from_directory(directory: &str) -> Result<Self, String> {
+        let mut paths: Vec<_> = fs::read_dir(directory)
+            .map_err(|e| format!("Failed to read weapon directory '{}': {}", directory, e))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        let mut registry = Self::new();
+        for path in paths {
+            registry.load_file(&path)
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+
+        if !registry.profiles.contains_key(FISTS_ID) {
+            return Err(format!("No '{}' weapon profile found in '{}'", FISTS_ID, directory));
+        }
+        Ok(registry)
+    }
+
+    fn load_file(&mut self, path: &Path) -> Result<(), String> {
+        let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let profile: WeaponProfile = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+        let effect_config = AnimationConfig::load_from_file(&profile.effect.animation_config)
+            .map_err(|e| format!("failed to load '{}': {}", profile.effect.animation_config, e))?;
+        self.register(profile, effect_config)
+    }
+
+    /// Registers a profile with its effect animation config
+    ///
+    /// Returns error if the profile is invalid, its ID is taken, or the config
+    /// is missing one of its effect animations.
+    pub fn register(&mut self, profile: WeaponProfile, effect_config: AnimationConfig) -> Result<(), String> {
+        profile.validate()?;
+        if self.profiles.contains_key(&profile.id) {
+            return Err(format!("Weapon '{}' already registered", profile.id));
+        }
+        if let Some(animation) = (0..profile.combo_len())
+            .map(|step| profile.step_animation(step))
+            .find(|animation| !effect_config.has_state(animation))
+        {
+            return Err(format!(
+                "'{}': animation '{}' not found in {}",
+                profile.id, animation, profile.effect.animation_config
+            ));
+        }
+
+        self.effect_configs.insert(profile.id.clone(), effect_config);
+        self.profiles.insert(profile.id.clone(), profile);
+        Ok(())
+    }
+
+    pub fn get(&self, id: &str) -> Option<&WeaponProfile> {
+        self.profiles.get(id)
+    }
+
+    /// All registered profiles (unordered)
+    pub fn all(&self) -> impl Iterator<Item = &WeaponProfile> {
+        self.profiles.values()
+    }
+
+    /// The bare-handed profile
+    pub fn fists(&self) -> &WeaponProfile {
+        &self.profiles[FISTS_ID]
+    }
+
+    /// The profile for a held item, falling back to fists
+    ///
+    /// Tools use `ToolType::weapon_id`; MainHand equipment uses its item ID.
+    pub fn for_item(&self, item: Option<&ItemDefinition>) -> &WeaponProfile {
+        let weapon_id = item.and_then(|item| match &item.properties {
+            ItemProperties::Tool { tool_type, .. } => tool_type.weapon_id(),
+            ItemProperties::Equipment { slot: EquipmentSlot::MainHand, .. } => Some(item.id.as_str()),
+            _ => None,
+        });
+        weapon_id.and_then(|id| self.get(id)).unwrap_or_else(|| self.fists())
+    }
+
+    /// Builds the effect animation for a weapon's combo `step`, already playing
+    ///
+    /// `textures` maps profile IDs to their effect sprite sheets.
+    pub fn create_effect_controller<'a>(
+        &self,
+        profile: &WeaponProfile,
+        step: usize,
+        textures: &'a HashMap<String, Texture<'a>>,
+    ) -> Result<AnimationController<'a>, String> {
+        let texture = textures.get(&profile.id)
+            .ok_or_else(|| format!("Missing effect texture for weapon '{}'", profile.id))?;
+        let config = self.effect_configs.get(&profile.id)
+            .ok_or_else(|| format!("Unknown weapon '{}'", profile.id))?;
+        let animation = profile.step_animation(step);
+        let mut controller = config.create_controller(texture, &[animation])?;
+        controller.set_state(animation.to_string());
+        Ok(controller)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::ToolType;
+
+    fn effect_config() -> AnimationConfig {
+        serde_json::from_str(include_str!("../assets/config/punch_effect.json")).unwrap()
+    }
+
+    fn profile(json: &str) -> WeaponProfile {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn bundled_registry() -> WeaponRegistry {
+        let mut registry = WeaponRegistry::new();
+        for json in [
+            include_str!("../assets/weapons/fists.json"),
+            include_str!("../assets/weapons/hoe.json"),
+            include_str!("../assets/weapons/sword.json"),
+        ] {
+            registry.register(profile(json), effect_config()).unwrap();
+        }
+        registry
+    }
+
+    fn tool(id: &str, tool_type: ToolType) -> ItemDefinition {
+        ItemDefinition::new(id, id, "", "", 1, ItemProperties::Tool { tool_type, durability: 1, mining_speed: 1.0 })
+    }
+
+    #[test]
+    fn test_items_pick_their_profile() {
+        let registry = bundled_registry();
+
+        assert_eq!(registry.for_item(None).id, FISTS_ID);
+        assert_eq!(registry.for_item(Some(&tool("hoe", ToolType::Hoe))).id, "hoe");
+        assert_eq!(registry.for_item(Some(&tool("sword", ToolType::Sword))).id, "sword");
+        assert_eq!(registry.for_item(Some(&tool("watering_can", ToolType::WateringCan))).id, FISTS_ID);
+
+        let spear = ItemDefinition::new("spear", "Spear", "", "", 1, ItemProperties::Equipment {
+            slot: EquipmentSlot::MainHand,
+            modifiers: Vec::new(),
+        });
+        assert_eq!(registry.for_item(Some(&spear)).id, FISTS_ID); // No spear profile yet
+    }
+
+    #[test]
+    fn test_profile_builds_attack() {
+        let registry = bundled_registry();
+        let fists = registry.fists();
+        assert_eq!(fists.shape, HitboxShape::Square);
+
+        let sword = registry.get("sword").unwrap();
+        let attack = sword.attack_event(0, 2.0, (10, 20), Direction::East);
+        assert_eq!(attack.damage, 2.0 * sword.damage_multiplier);
+        assert_eq!(attack.range, sword.range);
+        assert_eq!(attack.shape, sword.shape);
+        assert_eq!(attack.knockback, sword.knockback);
+
+        let slow = profile(r#"{ "id": "maul", "name": "Maul", "shape": { "type": "square" }, "range": 40,
+            "attack_speed_multiplier": 0.5,
+            "effect": { "sprite_path": "", "animation_config": "", "animation": "punch" } }"#);
+        assert_eq!(slow.damage_multiplier, 1.0);
+        assert_eq!(slow.attack_cooldown(2.0), 1.0);
+    }
+
+    #[test]
+    fn test_register_rejects_bad_profiles() {
+        let mut registry = bundled_registry();
+        assert!(registry.register(profile(include_str!("../assets/weapons/hoe.json")), effect_config()).is_err());
+
+        let mut bad = registry.fists().clone();
+        bad.id = "bad".to_string();
+        bad.shape = HitboxShape::Arc { degrees: 0.0 };
+        assert!(registry.register(bad.clone(), effect_config()).is_err());
+
+        bad.shape = HitboxShape::Strip { width: 8 };
+        bad.attack_speed_multiplier = 0.0;
+        assert!(registry.register(bad.clone(), effect_config()).is_err());
+
+        bad.attack_speed_multiplier = 1.0;
+        bad.effect.animation = "slash".to_string();
+        assert!(registry.register(bad.clone(), effect_config()).is_err());
+
+        bad.effect.animation = "punch".to_string();
+        assert!(registry.register(bad, effect_config()).is_ok());
+    }
+
+    #[test]
+    fn test_combo_steps_override_the_profile() {
+        let registry = bundled_registry();
+        let fists = registry.fists();
+        assert_eq!(fists.combo_len(), 3);
+
+        let jab = fists.attack_event(0, 2.0, (0, 0), Direction::South);
+        let finisher = fists.attack_event(2, 2.0, (0, 0), Direction::South);
+        assert_eq!(jab.combo_step, 0);
+        assert_eq!(finisher.combo_step, 2);
+        assert!(finisher.damage > jab.damage);
+        assert_ne!(finisher.shape, jab.shape);
+        assert_eq!(fists.step_animation(0), fists.effect.animation);
+        assert_ne!(fists.step_animation(2), fists.effect.animation);
+        assert!(fists.step_recovery(2, 2.0) > fists.step_recovery(0, 2.0));
+
+        // Without a combo every attack is the profile's own
+        let mut single = fists.clone();
+        single.combo.clear();
+        assert_eq!(single.combo_len(), 1);
+        assert_eq!(single.attack_event(0, 2.0, (0, 0), Direction::South).damage, 2.0);
+        assert_eq!(single.step_recovery(0, 2.0), single.attack_cooldown(2.0));
+    }
+
+    #[test]
+    fn test_combo_limits_are_validated() {
+        let mut long = bundled_registry().fists().clone();
+        long.combo.push(long.combo[0].clone());
+        assert!(long.validate().is_err());
+
+        let mut bad_step = bundled_registry().fists().clone();
+        bad_step.combo[1].recovery = 0.0;
+        assert!(bad_step.validate().is_err());
+    }
+}